@@ -129,6 +129,14 @@ pub trait Cycle: Copy + Clone + Default + Send + Sync + 'static {
     /// commitments and reasoning about them inside of PCD.)
     type HostCurve: CurveAffine<ScalarExt = Self::CircuitField, Base = Self::ScalarField>;
 
+    /// The length in bytes of the [`PrimeField::Repr`](ff::PrimeField::Repr)
+    /// of the [`CircuitField`](Cycle::CircuitField).
+    const CIRCUIT_FIELD_BYTES: usize;
+
+    /// The length in bytes of the [`PrimeField::Repr`](ff::PrimeField::Repr)
+    /// of the [`ScalarField`](Cycle::ScalarField).
+    const SCALAR_FIELD_BYTES: usize;
+
     /// Fixed generators for the [`NestedCurve`](Cycle::NestedCurve).
     type NestedGenerators: FixedGenerators<Self::NestedCurve>;
 
@@ -167,6 +175,13 @@ pub trait Cycle: Copy + Clone + Default + Send + Sync + 'static {
 
 /// Contains various fixed generators for elliptic curves, all of which have
 /// unknown discrete logarithm relationships with each other.
+///
+/// No precomputed form of the generators (such as per-generator windowed
+/// NAF tables) is kept. Commitments are computed with the bucketed MSM in
+/// [`mul`], whose cost is dominated by bucket additions that scale with the
+/// number of scalars; fixed-base tables would multiply the memory held for
+/// [`g`](FixedGenerators::g) without reducing that work at the sizes used
+/// for polynomial commitments.
 pub trait FixedGenerators<C: CurveAffine>: Send + Sync + 'static {
     /// The main generators used to commit to vectors (like the coefficients of
     /// polynomials).
@@ -175,6 +190,12 @@ pub trait FixedGenerators<C: CurveAffine>: Send + Sync + 'static {
     /// Generator used as a blinding factor or randomization.
     fn h(&self) -> &C;
 
+    /// Returns the number of main generators, which bounds the length of the
+    /// vectors that can be committed to.
+    fn num_generators(&self) -> usize {
+        self.g().len()
+    }
+
     /// Compute a commitment to a single value.
     fn short_commit(&self, value: C::ScalarExt, blind: C::ScalarExt) -> C {
         (self.g()[0] * value + *self.h() * blind).into()
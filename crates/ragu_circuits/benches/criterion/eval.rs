@@ -0,0 +1,23 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use ff::Field;
+use ragu_circuits::polynomials::{ProductionRank, Rank, sparse};
+use ragu_pasta::Fp;
+use rand::{SeedableRng, rngs::StdRng};
+
+/// Evaluates a polynomial with every coefficient populated, so that `eval` is
+/// a single Horner pass over `ProductionRank::num_coeffs()` coefficients.
+fn eval_bench(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let coeffs = (0..ProductionRank::num_coeffs())
+        .map(|_| Fp::random(&mut rng))
+        .collect();
+    let poly = sparse::Polynomial::<Fp, ProductionRank>::from_coeffs(coeffs);
+    let x = Fp::random(&mut rng);
+
+    c.bench_function("eval_full_rank", |b| {
+        b.iter(|| poly.eval(std::hint::black_box(x)));
+    });
+}
+
+criterion_group!(benches, eval_bench);
+criterion_main!(benches);
@@ -1,5 +1,7 @@
 //! Streaming Horner's method evaluation via the Buffer trait.
 
+use core::borrow::Borrow;
+
 use ragu_core::{Result, drivers::Driver};
 use ragu_primitives::{Element, GadgetExt, io::Buffer};
 
@@ -36,6 +38,31 @@ impl<'a, 'dr, D: Driver<'dr>> Horner<'a, 'dr, D> {
         }
     }
 
+    /// Creates a new buffer that evaluates a polynomial at `point`, with
+    /// `terms` already written in order.
+    pub fn from_terms<E: Borrow<Element<'dr, D>>>(
+        dr: &mut D,
+        point: &'a Element<'dr, D>,
+        terms: impl IntoIterator<Item = E>,
+    ) -> Result<Self> {
+        let mut horner = Self::new(point);
+        horner.extend(dr, terms)?;
+        Ok(horner)
+    }
+
+    /// Writes each of `terms` in order, exactly as repeated calls to
+    /// [`write`](Buffer::write) would.
+    pub fn extend<E: Borrow<Element<'dr, D>>>(
+        &mut self,
+        dr: &mut D,
+        terms: impl IntoIterator<Item = E>,
+    ) -> Result<()> {
+        for term in terms {
+            self.write(dr, term.borrow())?;
+        }
+        Ok(())
+    }
+
     /// Finishes the evaluation, returning the accumulated result.
     ///
     /// Returns zero if no elements were written.
@@ -60,3 +87,51 @@ impl<'a, 'dr, D: Driver<'dr>> Buffer<'dr, D> for Horner<'a, 'dr, D> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ff::Field;
+    use ragu_core::{drivers::emulator::Emulator, maybe::Maybe};
+    use ragu_pasta::Fp;
+
+    use super::*;
+
+    #[test]
+    fn extend_matches_write() -> Result<()> {
+        let point = Fp::from(7u64);
+        let coeffs = [3u64, 1, 4, 1, 5].map(Fp::from);
+
+        let (written, extended, from_terms) =
+            Emulator::emulate_wireless((point, coeffs), |dr, witness| {
+                let (point, coeffs) = witness.cast();
+                let point = Element::alloc(dr, point)?;
+                let terms = (0..coeffs.snag().len())
+                    .map(|i| Element::alloc(dr, coeffs.as_ref().map(|c| c[i])))
+                    .collect::<Result<alloc::vec::Vec<_>>>()?;
+
+                let mut written = Horner::new(&point);
+                for term in &terms {
+                    written.write(dr, term)?;
+                }
+
+                let mut extended = Horner::new(&point);
+                extended.extend(dr, &terms[..2])?;
+                extended.extend(dr, &terms[2..])?;
+
+                let from_terms = Horner::from_terms(dr, &point, &terms)?;
+
+                Ok((
+                    *written.finish(dr).value().take(),
+                    *extended.finish(dr).value().take(),
+                    *from_terms.finish(dr).value().take(),
+                ))
+            })?;
+
+        let expected = coeffs.iter().fold(Fp::ZERO, |acc, c| acc * point + c);
+        assert_eq!(written, expected);
+        assert_eq!(extended, expected);
+        assert_eq!(from_terms, expected);
+
+        Ok(())
+    }
+}
@@ -32,7 +32,7 @@ pub mod staging;
 mod trace;
 mod trivial;
 
-pub use metrics::{RoutineFingerprint, RoutineIdentity, SegmentRecord};
+pub use metrics::{DEFAULT_MAX_ROUTINE_DEPTH, RoutineFingerprint, RoutineIdentity, SegmentRecord};
 pub use trace::Trace;
 
 #[cfg(test)]
@@ -197,6 +197,20 @@ pub(crate) trait CircuitObject<F: Field, R: Rank>: Send + Sync {
     fn sy(&self, y: F, floor_plan: &[floor_planner::ConstraintSegment])
     -> sparse::Polynomial<F, R>;
 
+    /// Computes $s(x, Y)$ once, returning a closure that evaluates it at any
+    /// $y$ in agreement with [`sxy`](Self::sxy).
+    ///
+    /// This amortizes the cost of evaluating $s(x, y)$ at a fixed $x$ for
+    /// many values of $y$.
+    fn sx_cached(
+        &self,
+        x: F,
+        floor_plan: &[floor_planner::ConstraintSegment],
+    ) -> Box<dyn Fn(F) -> F + Send + Sync> {
+        let sx = self.sx(x, floor_plan);
+        Box::new(move |y| sx.eval(y))
+    }
+
     /// Returns constraint counts as `(gates, constraints)`, where gates is
     /// the number of multiplication gates and constraints is the number of
     /// [`enforce_zero`](ragu_core::drivers::Driver::enforce_zero) calls.
@@ -216,6 +230,40 @@ pub(crate) trait CircuitObject<F: Field, R: Rank>: Send + Sync {
     fn is_mask(&self) -> bool {
         false
     }
+
+    /// Tabulates $s(x, y)$ over the cross product of `xs` and `ys`, so that
+    /// `grid[i][j]` is $s(x_i, y_j)$.
+    #[cfg(test)]
+    fn sample_grid(
+        &self,
+        xs: &[F],
+        ys: &[F],
+        floor_plan: &[floor_planner::ConstraintSegment],
+    ) -> alloc::vec::Vec<alloc::vec::Vec<F>> {
+        xs.iter()
+            .map(|&x| ys.iter().map(|&y| self.sxy(x, y, floor_plan)).collect())
+            .collect()
+    }
+
+    /// Returns `true` if the restrictions $s(x, Y)$ and $s(X, y)$ agree with
+    /// $s(x, y)$ at every point of the cross product of `xs` and `ys`.
+    #[cfg(test)]
+    fn check_grid(
+        &self,
+        xs: &[F],
+        ys: &[F],
+        floor_plan: &[floor_planner::ConstraintSegment],
+    ) -> bool {
+        let grid = self.sample_grid(xs, ys, floor_plan);
+        let sys: alloc::vec::Vec<_> = ys.iter().map(|&y| self.sy(y, floor_plan)).collect();
+        xs.iter().zip(&grid).all(|(&x, row)| {
+            let sx = self.sx(x, floor_plan);
+            ys.iter()
+                .zip(&sys)
+                .zip(row)
+                .all(|((&y, sy), &sxy)| sx.eval(y) == sxy && sy.eval(x) == sxy)
+        })
+    }
 }
 
 /// Wraps a circuit into a boxed [`CircuitObject`] that can evaluate the
@@ -228,7 +276,21 @@ where
     C: Circuit<F> + 'a,
     R: Rank,
 {
-    let metrics = metrics::eval(&circuit)?;
+    into_circuit_object_with_max_routine_depth(circuit, DEFAULT_MAX_ROUTINE_DEPTH)
+}
+
+/// Like [`into_circuit_object`], but rejects circuits whose routines nest more
+/// than `max_routine_depth` levels deep.
+pub(crate) fn into_circuit_object_with_max_routine_depth<'a, F, C, R>(
+    circuit: C,
+    max_routine_depth: usize,
+) -> Result<Box<dyn CircuitObject<F, R> + 'a>>
+where
+    F: FromUniformBytes<64>,
+    C: Circuit<F> + 'a,
+    R: Rank,
+{
+    let metrics = metrics::eval(&circuit, max_routine_depth)?;
 
     // Reserve the last coefficient slot (Y^{4n-1}) for the registry key
     // constraint, which is injected at the registry level.
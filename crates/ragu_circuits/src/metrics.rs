@@ -39,7 +39,7 @@ use core::any::TypeId;
 use ff::{FromUniformBytes, PrimeField};
 use ragu_arithmetic::Coeff;
 use ragu_core::{
-    Result,
+    Error, Result,
     convert::WireMap,
     drivers::{Driver, DriverTypes, emulator::Emulator},
     gadgets::{Bound, GadgetKind as _},
@@ -210,6 +210,12 @@ pub struct CircuitMetrics {
     pub(crate) segments: Vec<SegmentRecord>,
 }
 
+/// Default maximum nesting depth of routines within a circuit.
+///
+/// Legitimate circuits nest routines only a handful of levels deep; this is
+/// far beyond that while still well within the stack of a test thread.
+pub const DEFAULT_MAX_ROUTINE_DEPTH: usize = 256;
+
 /// Per-routine state that is saved and restored across routine boundaries.
 ///
 /// Contains both the constraint counting record index and the identity
@@ -243,6 +249,9 @@ struct CounterScope<F> {
 
     /// Horner accumulator for the fingerprint evaluation result.
     result: F,
+
+    /// Number of routines enclosing this scope; `0` for the root segment.
+    depth: usize,
 }
 
 /// A [`Driver`] that simultaneously counts constraints and computes routine
@@ -264,6 +273,10 @@ struct Counter<F> {
     num_gates: usize,
     segments: Vec<SegmentRecord>,
 
+    /// Maximum routine nesting depth before [`Error::RoutineRecursionLimit`]
+    /// is returned. Set through [`eval`].
+    max_routine_depth: usize,
+
     /// Base for the $a$-wire geometric sequence.
     x0: F,
 
@@ -316,7 +329,7 @@ impl<F: FromUniformBytes<64>> Counter<F> {
         WireEval::Value(v)
     }
 
-    fn new() -> Self {
+    fn new(max_routine_depth: usize) -> Self {
         let base_state = blake2b_simd::Params::new()
             .personal(b"ragu_counter____")
             .to_state();
@@ -343,6 +356,7 @@ impl<F: FromUniformBytes<64>> Counter<F> {
                 current_d: x3,
                 remap_current: x_remap,
                 result: h,
+                depth: 0,
             },
             num_constraints: 0,
             num_gates: 0,
@@ -351,6 +365,7 @@ impl<F: FromUniformBytes<64>> Counter<F> {
                 num_constraints: 0,
                 identity: RoutineIdentity::Root,
             }],
+            max_routine_depth,
             x0,
             x1,
             x2,
@@ -435,6 +450,15 @@ impl<'dr, F: FromUniformBytes<64>> Driver<'dr> for Counter<F> {
         routine: Ro,
         input: Bound<'dr, Self, Ro::Input>,
     ) -> Result<Bound<'dr, Self, Ro::Output>> {
+        // Every circuit is counted before any other driver synthesizes it, so
+        // runaway recursion is caught here rather than overflowing the stack.
+        let depth = self.scope.depth + 1;
+        if depth > self.max_routine_depth {
+            return Err(Error::RoutineRecursionLimit {
+                limit: self.max_routine_depth,
+            });
+        }
+
         // Push new segment with placeholder identity.
         self.segments.push(SegmentRecord {
             num_gates: 0,
@@ -455,6 +479,7 @@ impl<'dr, F: FromUniformBytes<64>> Driver<'dr> for Counter<F> {
                 current_d: self.x3,
                 remap_current: self.x_remap,
                 result: self.h,
+                depth,
             },
         );
 
@@ -507,15 +532,22 @@ impl<F: FromUniformBytes<64>> WireMap<F> for Counter<F> {
 }
 
 /// Evaluates the constraint topology of a circuit.
-pub fn eval<F: FromUniformBytes<64>, C: Circuit<F>>(circuit: &C) -> Result<CircuitMetrics> {
-    eval_raw(&super::raw::CircuitAdapterRef(circuit))
+///
+/// Fails with [`Error::RoutineRecursionLimit`] once routines nest more than
+/// `max_routine_depth` levels deep; see [`DEFAULT_MAX_ROUTINE_DEPTH`].
+pub fn eval<F: FromUniformBytes<64>, C: Circuit<F>>(
+    circuit: &C,
+    max_routine_depth: usize,
+) -> Result<CircuitMetrics> {
+    eval_raw(&super::raw::CircuitAdapterRef(circuit), max_routine_depth)
 }
 
 /// Evaluates the constraint topology of a [`RawCircuit`].
 pub(crate) fn eval_raw<F: FromUniformBytes<64>, RC: RawCircuit<F>>(
     circuit: &RC,
+    max_routine_depth: usize,
 ) -> Result<CircuitMetrics> {
-    let mut collector = Counter::<F>::new();
+    let mut collector = Counter::<F>::new(max_routine_depth);
 
     let result = super::raw::orchestrate(&mut collector, circuit, Empty)?;
 
@@ -600,7 +632,7 @@ pub(crate) mod tests {
         D: Driver<'dr, F = F>,
         Ro: Routine<F>,
     {
-        let mut counter = Counter::<F>::new();
+        let mut counter = Counter::<F>::new(DEFAULT_MAX_ROUTINE_DEPTH);
 
         // Remap input wires into Counter via the dedicated `x_remap`
         // sequence, mirroring Counter::routine. No gates allocated, no
@@ -687,6 +719,107 @@ pub(crate) mod tests {
 
     #[test]
     fn dangling_alloc_in_routine() {
-        super::eval::<Fp, _>(&DanglingAllocCircuit).expect("metrics eval should succeed");
+        super::eval::<Fp, _>(&DanglingAllocCircuit, DEFAULT_MAX_ROUTINE_DEPTH)
+            .expect("metrics eval should succeed");
+    }
+
+    /// A routine that nests itself `remaining` levels deep.
+    #[derive(Clone)]
+    struct RecursiveRoutine {
+        remaining: usize,
+    }
+
+    impl Routine<Fp> for RecursiveRoutine {
+        type Input = ();
+        type Output = ();
+        type Aux<'dr> = ();
+
+        fn execute<'dr, D: Driver<'dr, F = Fp>>(
+            &self,
+            dr: &mut D,
+            _input: Bound<'dr, D, Self::Input>,
+            _aux: DriverValue<D, Self::Aux<'dr>>,
+        ) -> Result<Bound<'dr, D, Self::Output>> {
+            if self.remaining > 0 {
+                dr.routine(
+                    RecursiveRoutine {
+                        remaining: self.remaining - 1,
+                    },
+                    (),
+                )?;
+            }
+            Ok(())
+        }
+
+        fn predict<'dr, D: Driver<'dr, F = Fp>>(
+            &self,
+            _dr: &mut D,
+            _input: &Bound<'dr, D, Self::Input>,
+        ) -> Result<Prediction<Bound<'dr, D, Self::Output>, DriverValue<D, Self::Aux<'dr>>>>
+        {
+            Ok(Prediction::Unknown(D::unit()))
+        }
+    }
+
+    /// A circuit whose single routine nests itself `.0` more levels deep.
+    pub(crate) struct RecursiveCircuit(pub(crate) usize);
+
+    impl Circuit<Fp> for RecursiveCircuit {
+        type Instance<'source> = ();
+        type Witness<'source> = ();
+        type Output = ();
+        type Aux<'source> = ();
+
+        fn instance<'dr, 'source: 'dr, D: Driver<'dr, F = Fp>>(
+            &self,
+            _dr: &mut D,
+            _instance: DriverValue<D, Self::Instance<'source>>,
+        ) -> Result<Bound<'dr, D, Self::Output>> {
+            Ok(())
+        }
+
+        fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = Fp>>(
+            &self,
+            dr: &mut D,
+            _witness: DriverValue<D, Self::Witness<'source>>,
+        ) -> Result<WithAux<Bound<'dr, D, Self::Output>, DriverValue<D, Self::Aux<'source>>>>
+        {
+            dr.routine(RecursiveRoutine { remaining: self.0 }, ())?;
+            Ok(WithAux::new((), D::unit()))
+        }
+    }
+
+    #[test]
+    fn routine_recursion_limit() {
+        // The outermost routine sits at depth 1, so `remaining` nested calls
+        // reach depth `remaining + 1`.
+        let metrics = super::eval::<Fp, _>(
+            &RecursiveCircuit(DEFAULT_MAX_ROUTINE_DEPTH - 1),
+            DEFAULT_MAX_ROUTINE_DEPTH,
+        )
+        .expect("recursion up to the limit should succeed");
+        assert_eq!(metrics.segments.len(), DEFAULT_MAX_ROUTINE_DEPTH + 1);
+
+        assert!(matches!(
+            super::eval::<Fp, _>(
+                &RecursiveCircuit(DEFAULT_MAX_ROUTINE_DEPTH),
+                DEFAULT_MAX_ROUTINE_DEPTH
+            ),
+            Err(Error::RoutineRecursionLimit {
+                limit: DEFAULT_MAX_ROUTINE_DEPTH
+            })
+        ));
+    }
+
+    #[test]
+    fn routine_recursion_custom_limit() {
+        let metrics = super::eval::<Fp, _>(&RecursiveCircuit(3), 4)
+            .expect("recursion up to the limit should succeed");
+        assert_eq!(metrics.segments.len(), 5);
+
+        assert!(matches!(
+            super::eval::<Fp, _>(&RecursiveCircuit(4), 4),
+            Err(Error::RoutineRecursionLimit { limit: 4 })
+        ));
     }
 }
@@ -119,6 +119,28 @@ pub type ProductionRank = R<13>;
 /// $2^5 = 32$ gates.
 pub type TestRank = R<7>;
 
+/// Returns the smallest rank $k$ such that `R<k>` supports at least
+/// `gate_count` gates, i.e. such that $n = 2^{k - 2} \geq$ `gate_count`.
+///
+/// | $k$  | $n$  |
+/// |------|------|
+/// | $7$  | $32$ ([`TestRank`]) |
+/// | $8$  | $64$ |
+/// | $9$  | $128$ |
+/// | $10$ | $256$ |
+/// | $11$ | $512$ |
+/// | $12$ | $1024$ |
+/// | $13$ | $2048$ ([`ProductionRank`]) |
+/// | $14$ | $4096$ |
+/// | $15$ | $8192$ |
+/// | $16$ | $16384$ |
+///
+/// The result may exceed the ranks for which [`Rank`] is implemented.
+pub const fn min_rank_for(gate_count: usize) -> u32 {
+    let gate_count = if gate_count == 0 { 1 } else { gate_count };
+    gate_count.next_power_of_two().trailing_zeros() + 2
+}
+
 /// Macro to implement [`Rank`] for various `R<N>`.
 macro_rules! impl_rank_for_R {
     ($($n:literal),*) => {
@@ -131,7 +153,17 @@ macro_rules! impl_rank_for_R {
     };
 }
 
-impl_rank_for_R! {7, 13}
+impl_rank_for_R! {7, 13, 14}
+
+#[test]
+fn test_min_rank_for() {
+    assert_eq!(min_rank_for(0), 2);
+    assert_eq!(min_rank_for(1), 2);
+    assert_eq!(min_rank_for(TestRank::n()), 7);
+    assert_eq!(min_rank_for(TestRank::n() + 1), 8);
+    assert_eq!(min_rank_for(ProductionRank::n()), 13);
+    assert_eq!(min_rank_for(ProductionRank::n() + 1), 14);
+}
 
 #[test]
 fn test_tz() {
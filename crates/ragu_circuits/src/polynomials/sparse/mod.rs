@@ -19,6 +19,8 @@
 //! - [`Polynomial::from_coeffs`]: compress a dense coefficient vector, stripping
 //!   leading and trailing zeros; short interior zero gaps are kept inline
 //!   within blocks.
+//! - [`Polynomial::from_wires`]: a trace polynomial from equal-length
+//!   per-gate wire vectors, without going through a [`View`].
 //! - [`View`]: a builder that maps four gate-indexed wire buffers to degree
 //!   positions, producing a polynomial via [`View::build`]. Zero elements within
 //!   a wire buffer are **preserved** in the resulting blocks — push only
@@ -26,19 +28,21 @@
 //!   to compress a pre-built dense vector.
 //!
 //! Once constructed, the polynomial supports algebraic operations ([`scale`],
-//! [`add_assign`], [`sub_assign`], [`negate`], [`eval`], [`revdot`],
-//! [`dilate`], [`fold`], [`commit`]) but cannot be deconstructed back into wire
-//! buffers.
+//! [`add_assign`], [`sub_assign`], [`negate`], [`mul`], [`eval`], [`revdot`],
+//! [`dilate`], [`fold`], [`commit`], [`commit_batch`]) but cannot be
+//! deconstructed back into wire buffers.
 //!
 //! [`scale`]: Polynomial::scale
 //! [`add_assign`]: Polynomial::add_assign
 //! [`sub_assign`]: Polynomial::sub_assign
 //! [`negate`]: Polynomial::negate
+//! [`mul`]: Polynomial::mul
 //! [`eval`]: Polynomial::eval
 //! [`revdot`]: Polynomial::revdot
 //! [`dilate`]: Polynomial::dilate
 //! [`fold`]: Polynomial::fold
 //! [`commit`]: Polynomial::commit
+//! [`commit_batch`]: Polynomial::commit_batch
 
 pub(crate) mod view;
 pub use view::View;
@@ -46,11 +50,12 @@ pub use view::View;
 #[cfg(test)]
 mod tests;
 
-use alloc::vec::Vec;
+use alloc::{format, string::String, vec::Vec};
 use core::{borrow::Borrow, marker::PhantomData};
 
 use ff::Field;
 use ragu_arithmetic::CurveAffine;
+use ragu_core::{Error, Result};
 use rand::CryptoRng;
 
 use super::Rank;
@@ -66,25 +71,37 @@ pub struct Polynomial<T, R: Rank> {
 }
 
 impl<T, R: Rank> Polynomial<T, R> {
-    /// Panics if the block list violates any structural invariant: blocks must
-    /// be sorted by start index, non-empty, non-overlapping, and each block
-    /// must fit within `[0, R::num_coeffs())`. Adjacent blocks are permitted.
-    fn assert_invariants(&self) {
+    /// Checks the structural invariants of the block list: blocks must be
+    /// sorted by start index, non-empty, non-overlapping, and each block must
+    /// fit within `[0, R::num_coeffs())`. Adjacent blocks are permitted.
+    fn check_invariants(&self) -> core::result::Result<(), String> {
         let mut prev_end: usize = 0;
         for (i, (start, data)) in self.blocks.iter().enumerate() {
-            assert!(!data.is_empty(), "block {i} is empty");
-            assert!(
-                *start + data.len() <= R::num_coeffs(),
-                "block {i} exceeds capacity"
-            );
-            if i > 0 {
-                assert!(
-                    *start >= prev_end,
+            if data.is_empty() {
+                return Err(format!("block {i} is empty"));
+            }
+            if start
+                .checked_add(data.len())
+                .is_none_or(|end| end > R::num_coeffs())
+            {
+                return Err(format!("block {i} exceeds capacity"));
+            }
+            if i > 0 && *start < prev_end {
+                return Err(format!(
                     "block {i} overlaps previous (start={start}, prev_end={prev_end})"
-                );
+                ));
             }
             prev_end = *start + data.len();
         }
+        Ok(())
+    }
+
+    /// Panics if the block list violates any structural invariant; see
+    /// [`check_invariants`](Self::check_invariants).
+    fn assert_invariants(&self) {
+        if let Err(msg) = self.check_invariants() {
+            panic!("{msg}");
+        }
     }
 
     /// Creates a polynomial from pre-built blocks. The caller must ensure
@@ -153,6 +170,16 @@ impl<T, R: Rank> Default for Polynomial<T, R> {
     }
 }
 
+/// Two polynomials are equal if they have the same coefficients, regardless
+/// of how those coefficients are split into blocks.
+impl<F: Field, R: Rank> PartialEq for Polynomial<F, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter_coeffs().eq(other.iter_coeffs())
+    }
+}
+
+impl<F: Field, R: Rank> Eq for Polynomial<F, R> {}
+
 impl<T, R: Rank> Polynomial<T, R> {
     /// Creates a new empty (zero) polynomial.
     pub fn new() -> Self {
@@ -161,6 +188,29 @@ impl<T, R: Rank> Polynomial<T, R> {
             _marker: PhantomData,
         }
     }
+
+    /// Returns the blocks of this polynomial as `(start_index, values)` pairs,
+    /// sorted by start index.
+    pub fn blocks(&self) -> impl ExactSizeIterator<Item = (usize, &[T])> {
+        self.blocks
+            .iter()
+            .map(|(start, data)| (*start, data.as_slice()))
+    }
+
+    /// Creates a polynomial from `(start_index, values)` blocks, such as those
+    /// returned by [`blocks`](Self::blocks).
+    ///
+    /// Returns [`Error::MalformedEncoding`] if the blocks are not sorted,
+    /// non-empty, non-overlapping and within capacity.
+    pub fn try_from_blocks(blocks: Vec<(usize, Vec<T>)>) -> Result<Self> {
+        let poly = Self {
+            blocks,
+            _marker: PhantomData,
+        };
+        poly.check_invariants()
+            .map_err(|msg| Error::MalformedEncoding(msg.into()))?;
+        Ok(poly)
+    }
 }
 
 impl<F: Field, R: Rank> Polynomial<F, R> {
@@ -182,12 +232,71 @@ impl<F: Field, R: Rank> Polynomial<F, R> {
         Self::from_blocks(blocks)
     }
 
+    /// Creates a trace polynomial directly from per-gate wire values, as if
+    /// each were pushed onto the corresponding buffer of a [`View::trace`].
+    ///
+    /// All four wires must have one entry per gate. No relation between the
+    /// wires (such as `a[i] * b[i] == c[i]`) is required.
+    ///
+    /// Returns [`Error::VectorLengthMismatch`] if the wires differ in length,
+    /// or [`Error::GateBoundExceeded`] if they exceed `R::n()` gates.
+    pub fn from_wires(a: Vec<F>, b: Vec<F>, c: Vec<F>, d: Vec<F>) -> Result<Self> {
+        for wire in [&b, &c, &d] {
+            if wire.len() != a.len() {
+                return Err(Error::VectorLengthMismatch {
+                    expected: a.len(),
+                    actual: wire.len(),
+                });
+            }
+        }
+        if a.len() > R::n() {
+            return Err(Error::GateBoundExceeded { limit: R::n() });
+        }
+
+        let mut view = View::trace();
+        view.a = a;
+        view.b = b;
+        view.c = c;
+        view.d = d;
+        Ok(view.build())
+    }
+
+    /// Assembles a circuit [`Trace`](crate::Trace) into a trace polynomial,
+    /// without a [`Registry`](crate::registry::Registry).
+    ///
+    /// Segments are placed one after another in synthesis order, as by
+    /// [`floor_plan`](crate::floor_planner::floor_plan), and `d[0]` is left
+    /// zero rather than blinded. This is intended for tests that inspect the
+    /// polynomial of a single circuit; proofs should use
+    /// [`Registry::assemble`](crate::registry::Registry::assemble).
+    ///
+    /// Returns [`Error::GateBoundExceeded`] if the trace exceeds `R::n()`
+    /// gates.
+    pub fn from_trace(trace: &crate::Trace<F>) -> Result<Self> {
+        trace.assemble_trivial()
+    }
+
     /// Creates a polynomial with random coefficients filling all `4n` slots.
     pub fn random<RNG: CryptoRng>(rng: &mut RNG) -> Self {
         assert!(R::num_coeffs() > 0, "num_coeffs must be positive");
         let coeffs: Vec<F> = (0..R::num_coeffs()).map(|_| F::random(&mut *rng)).collect();
         Self::from_blocks(alloc::vec![(0, coeffs)])
     }
+
+    /// Creates a random trace polynomial over all `n` gates that satisfies
+    /// every multiplication gate: the `a` and `b` wires are sampled and the
+    /// `c` wires are set to `a * b`. The `d` wires are left zero.
+    pub fn random_trace<RNG: CryptoRng>(rng: &mut RNG) -> Self {
+        let mut view = View::trace();
+        for _ in 0..R::n() {
+            let a = F::random(&mut *rng);
+            let b = F::random(&mut *rng);
+            view.a.push(a);
+            view.b.push(b);
+            view.c.push(a * b);
+        }
+        view.build()
+    }
 }
 
 impl<T, R: Rank> Polynomial<T, R> {
@@ -214,10 +323,50 @@ impl<F: Field, R: Rank> Polynomial<F, R> {
         }
     }
 
+    /// Returns the number of coefficients yielded by
+    /// [`iter_coeffs`](Self::iter_coeffs), which is always `R::num_coeffs()`.
+    pub fn len_coeffs(&self) -> usize {
+        R::num_coeffs()
+    }
+
+    /// Returns the coefficient of degree `i`, or `None` if `i` is not less
+    /// than [`len_coeffs`](Self::len_coeffs).
+    ///
+    /// The containing block is found by binary search, so this does not
+    /// iterate over the coefficients.
+    pub fn coeff_at(&self, i: usize) -> Option<F> {
+        if i >= self.len_coeffs() {
+            return None;
+        }
+
+        // Index of the first block starting after `i`; only the block before
+        // it can contain `i`.
+        let next = self.blocks.partition_point(|(start, _)| *start <= i);
+        let coeff = next
+            .checked_sub(1)
+            .and_then(|b| {
+                let (start, data) = &self.blocks[b];
+                data.get(i - start).copied()
+            })
+            .unwrap_or(F::ZERO);
+
+        Some(coeff)
+    }
+
     /// Merges another polynomial into this one using the given binary
     /// operation, pruning all-zero blocks from the result.
-    fn combine_assign(&mut self, other: &Self, mut op: impl FnMut(&mut F, &F)) {
+    ///
+    /// Every stored coefficient of `self` is first transformed by `map_lhs`,
+    /// which must map nonzero values to nonzero values so that blocks passed
+    /// through unchanged remain valid.
+    fn combine_assign(
+        &mut self,
+        other: &Self,
+        mut map_lhs: impl FnMut(&mut F),
+        mut op: impl FnMut(&mut F, &F),
+    ) {
         if other.blocks.is_empty() {
+            self.apply_all(map_lhs);
             return;
         }
         if self.blocks.is_empty() {
@@ -274,6 +423,7 @@ impl<F: Field, R: Rank> Polynomial<F, R> {
             // unchanged, avoiding the dense intermediate buffer.
             if ri == ri_start {
                 for block in &mut lhs[li_start..li] {
+                    block.1.iter_mut().for_each(&mut map_lhs);
                     out.push((block.0, core::mem::take(&mut block.1)));
                 }
                 continue;
@@ -287,12 +437,16 @@ impl<F: Field, R: Rank> Polynomial<F, R> {
                 && lhs[li_start].0 == cluster_start
                 && lhs[li_start].1.len() == cluster_len
             {
-                core::mem::take(&mut lhs[li_start].1)
+                let mut data = core::mem::take(&mut lhs[li_start].1);
+                data.iter_mut().for_each(&mut map_lhs);
+                data
             } else {
                 let mut data = alloc::vec![F::ZERO; cluster_len];
                 for (ls, ld) in &lhs[li_start..li] {
                     let off = ls - cluster_start;
-                    data[off..off + ld.len()].copy_from_slice(ld);
+                    let dst = &mut data[off..off + ld.len()];
+                    dst.copy_from_slice(ld);
+                    dst.iter_mut().for_each(&mut map_lhs);
                 }
                 data
             };
@@ -327,12 +481,26 @@ impl<F: Field, R: Rank> Polynomial<F, R> {
 
     /// Adds the coefficients of `other` to `self`.
     pub fn add_assign(&mut self, other: &Self) {
-        self.combine_assign(other, |a, b| *a += *b);
+        self.combine_assign(other, |_| {}, |a, b| *a += *b);
     }
 
     /// Subtracts the coefficients of `other` from `self`.
     pub fn sub_assign(&mut self, other: &Self) {
-        self.combine_assign(other, |a, b| *a -= *b);
+        self.combine_assign(other, |_| {}, |a, b| *a -= *b);
+    }
+
+    /// Replaces `self` with `self * scale + other`.
+    ///
+    /// This is equivalent to [`scale`](Self::scale) followed by
+    /// [`add_assign`](Self::add_assign), but makes a single pass over the
+    /// coefficients.
+    pub fn add_assign_scaled(&mut self, scale: F, other: &Self) {
+        if bool::from(scale.is_zero()) {
+            self.blocks.clear();
+            self.add_assign(other);
+        } else {
+            self.combine_assign(other, |a| *a *= scale, |a, b| *a += *b);
+        }
     }
 
     /// Negates all coefficients.
@@ -340,6 +508,42 @@ impl<F: Field, R: Rank> Polynomial<F, R> {
         self.apply_all(|x| *x = -*x);
     }
 
+    /// Computes the product of this polynomial and `other` by schoolbook
+    /// multiplication over their stored coefficients.
+    ///
+    /// This is quadratic and intended for debugging and tests, such as
+    /// materializing the product whose coefficients a
+    /// [`revdot`](Self::revdot) relation constrains.
+    ///
+    /// Returns [`Error::DegreeBoundExceeded`] if the product does not fit in
+    /// `R::num_coeffs()` coefficients.
+    pub fn mul(&self, other: &Self) -> Result<Self> {
+        let end = |p: &Self| p.blocks.last().map(|(start, data)| start + data.len());
+        let (Some(a_end), Some(b_end)) = (end(self), end(other)) else {
+            return Ok(Self::new());
+        };
+
+        let len = a_end + b_end - 1;
+        if len > R::num_coeffs() {
+            return Err(Error::DegreeBoundExceeded {
+                limit: R::num_coeffs() - 1,
+            });
+        }
+
+        let mut coeffs = alloc::vec![F::ZERO; len];
+        for (a_start, a_data) in &self.blocks {
+            for (i, a) in a_data.iter().enumerate() {
+                for (b_start, b_data) in &other.blocks {
+                    for (j, b) in b_data.iter().enumerate() {
+                        coeffs[a_start + i + b_start + j] += *a * *b;
+                    }
+                }
+            }
+        }
+
+        Ok(Self::from_coeffs(coeffs))
+    }
+
     /// Horner-style weighted sum of polynomials by powers of `scale_factor`.
     ///
     /// Given polynomials $p\_{0}, p\_{1}, \ldots, p\_{k-1}$ and factor
@@ -348,13 +552,33 @@ impl<F: Field, R: Rank> Polynomial<F, R> {
     /// $$\text{fold} = \alpha^{k-1} p\_{0} + \alpha^{k-2} p\_{1} + \cdots + p\_{k-1}$$
     pub fn fold<E: Borrow<Self>>(polys: impl IntoIterator<Item = E>, scale_factor: F) -> Self {
         polys.into_iter().fold(Self::default(), |mut acc, poly| {
-            acc.scale(scale_factor);
-            acc.add_assign(poly.borrow());
+            acc.add_assign_scaled(scale_factor, poly.borrow());
             acc
         })
     }
 
+    /// Weighted sum of polynomials by ascending powers of `scale`.
+    ///
+    /// Given polynomials $p\_{0}, p\_{1}, \ldots, p\_{k-1}$ and factor
+    /// $s$:
+    ///
+    /// $$p\_{0} + s \cdot p\_{1} + \cdots + s^{k-1} p\_{k-1}$$
+    ///
+    /// This is [`fold`](Self::fold) with the polynomials in reverse order, so
+    /// that the weight of each polynomial is `scale` raised to its index.
+    pub fn fold_with<E: Borrow<Self>, I>(polys: I, scale: F) -> Self
+    where
+        I: IntoIterator<Item = E>,
+        I::IntoIter: DoubleEndedIterator,
+    {
+        Self::fold(polys.into_iter().rev(), scale)
+    }
+
     /// Evaluates this polynomial at `z` using reverse Horner's method by block.
+    ///
+    /// Coefficients within a block cost a single multiplication and addition
+    /// each; only the gaps between blocks (and below the lowest block) are
+    /// skipped with an exponentiation.
     pub fn eval(&self, z: F) -> F {
         let mut result = F::ZERO;
         let mut prev_start = R::num_coeffs();
@@ -392,13 +616,35 @@ impl<F: Field, R: Rank> Polynomial<F, R> {
         }
     }
 
+    /// Transforms `p(X)` into `p(X / z)`, undoing [`dilate`](Self::dilate),
+    /// so that `p.clone().dilate(z).dilate_inv(z) == p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `z` is zero, which has no inverse.
+    pub fn dilate_inv(&mut self, z: F) {
+        let z_inv = Option::<F>::from(z.invert()).expect("cannot undo a dilation by zero");
+        self.dilate(z_inv);
+    }
+
     /// Inner product of `self` with the coefficient-reversed `other`.
     ///
     /// Computes $\sum\_{k} \text{self}\[k\] \cdot \text{other}\[4n - 1 - k\]$.
     ///
     /// Uses a two-pointer merge over both block lists for $O(\text{nnz})$
     /// time.
+    ///
+    /// Both operands share the rank `R`, so their capacities always agree;
+    /// the merge additionally relies on every block lying within
+    /// `[0, R::num_coeffs())`. This is upheld by all constructors and is
+    /// re-checked here in debug builds only, as `revdot` sits on the hot path.
     pub fn revdot(&self, other: &Self) -> F {
+        #[cfg(debug_assertions)]
+        {
+            self.assert_invariants();
+            other.assert_invariants();
+        }
+
         let max_deg = R::num_coeffs() - 1;
         let mut result = F::ZERO;
 
@@ -451,13 +697,18 @@ impl<F: Field, R: Rank> Polynomial<F, R> {
     /// [`batch_to_affine`](ragu_arithmetic::batch_to_affine) to efficiently
     /// convert multiple projective commitments to affine with a single
     /// field inversion.
+    ///
+    /// Only the generators at the degrees of stored coefficients take part in
+    /// the MSM, so a polynomial supported on its first `k` coefficients touches
+    /// only the first `k` generators; no separate prefix view of `generators`
+    /// is needed to commit short polynomials cheaply.
     pub fn commit<C: CurveAffine<ScalarExt = F>>(
         &self,
         generators: &impl ragu_arithmetic::FixedGenerators<C>,
     ) -> C::Curve {
-        assert!(generators.g().len() >= R::num_coeffs());
-
         let g = generators.g();
+        assert!(g.len() >= R::num_coeffs());
+
         ragu_arithmetic::mul(
             self.blocks.iter().flat_map(|(_, data)| data.iter()),
             self.blocks
@@ -476,6 +727,23 @@ impl<F: Field, R: Rank> Polynomial<F, R> {
     ) -> C {
         self.commit(generators).into()
     }
+
+    /// Computes commitments to several polynomials that share the same
+    /// generators, normalized to affine with a single shared field inversion.
+    ///
+    /// The result is identical to calling
+    /// [`commit_to_affine`](Self::commit_to_affine) on each polynomial
+    /// independently. Each MSM is parallelized when the `multicore` feature is
+    /// enabled.
+    pub fn commit_batch<C: CurveAffine<ScalarExt = F>>(
+        polys: &[&Self],
+        generators: &impl ragu_arithmetic::FixedGenerators<C>,
+    ) -> Vec<C> {
+        let projectives: Vec<C::Curve> = polys.iter().map(|p| p.commit(generators)).collect();
+        let mut affines = alloc::vec![C::identity(); projectives.len()];
+        <C::Curve as group::Curve>::batch_normalize(&projectives, &mut affines);
+        affines
+    }
 }
 
 /// An iterator over all coefficients of a sparse polynomial in ascending
@@ -248,6 +248,14 @@ proptest! {
         prop_assert_eq!(dilated.eval(x), original_eval);
     }
 
+    #[test]
+    fn dilate_inv_roundtrip(poly in arb_any_poly(), z in arb_nonzero_fe()) {
+        let mut dilated = poly.clone();
+        dilated.dilate(z);
+        dilated.dilate_inv(z);
+        prop_assert_eq!(dilated, poly);
+    }
+
     #[test]
     fn revdot_matches_dense(a in arb_any_poly(), b in arb_any_poly()) {
         let a_dense = a.to_dense();
@@ -287,6 +295,20 @@ proptest! {
         }
     }
 
+    #[test]
+    fn add_assign_scaled_matches_two_step(
+        a in arb_any_poly(),
+        b in arb_any_poly(),
+        scale in prop_oneof![Just(Fp::ZERO), arb_fe()],
+    ) {
+        let mut expected = a.clone();
+        expected.scale(scale);
+        expected.add_assign(&b);
+        let mut fused = a;
+        fused.add_assign_scaled(scale, &b);
+        prop_assert_eq!(fused.to_dense(), expected.to_dense());
+    }
+
     #[test]
     fn sub_assign_correct(a in arb_any_poly(), b in arb_any_poly(), x in arb_fe()) {
         let expected = a.eval(x) - b.eval(x);
@@ -295,6 +317,26 @@ proptest! {
         prop_assert_eq!(diff.eval(x), expected);
     }
 
+    #[test]
+    fn blocks_roundtrip(poly in arb_any_poly()) {
+        let blocks = poly.blocks().map(|(start, data)| (start, data.to_vec())).collect();
+        prop_assert_eq!(&Polynomial::<Fp, R>::try_from_blocks(blocks).unwrap(), &poly);
+    }
+
+    #[test]
+    fn eq_ignores_block_layout(a in arb_any_poly(), b in arb_any_poly()) {
+        prop_assert_eq!(&Polynomial::<Fp, R>::from_coeffs(a.to_dense()), &a);
+
+        let mut roundtrip = a.clone();
+        roundtrip.add_assign(&b);
+        roundtrip.sub_assign(&b);
+        prop_assert_eq!(&roundtrip, &a);
+
+        let mut tweaked = a.clone();
+        tweaked.add_assign(&Polynomial::from_coeffs(vec![Fp::ONE]));
+        prop_assert_ne!(&tweaked, &a);
+    }
+
     #[test]
     fn add_commutative(a in arb_any_poly(), b in arb_any_poly()) {
         let mut ab = a.clone();
@@ -367,6 +409,22 @@ proptest! {
         prop_assert_eq!(folded.eval(x), expected);
     }
 
+    #[test]
+    fn fold_with_ascending_powers(
+        polys in prop::collection::vec(arb_any_poly(), 0..4),
+        scale in arb_fe(),
+        x in arb_fe(),
+    ) {
+        let folded = Polynomial::<Fp, R>::fold_with(&polys, scale);
+        let mut power = Fp::ONE;
+        let mut expected = Fp::ZERO;
+        for poly in &polys {
+            expected += power * poly.eval(x);
+            power *= scale;
+        }
+        prop_assert_eq!(folded.eval(x), expected);
+    }
+
     #[test]
     fn fold_single(poly in arb_any_poly(), alpha in arb_fe(), x in arb_fe()) {
         let folded = Polynomial::<Fp, R>::fold([&poly], alpha);
@@ -413,6 +471,45 @@ proptest! {
         prop_assert_eq!(sparse_commit, dense_commit);
     }
 
+    #[test]
+    fn commit_short_poly_matches_generator_prefix(
+        coeffs in proptest::collection::vec(arb_nonzero_fe(), 1..=16),
+    ) {
+        use ragu_arithmetic::{Cycle, FixedGenerators};
+        use ragu_pasta::Pasta;
+
+        let pasta = Pasta::baked();
+        let generators = Pasta::host_generators(pasta);
+
+        let k = coeffs.len();
+        let poly = Polynomial::<Fp, R>::from_coeffs(coeffs.clone());
+        let prefix_commit: <Pasta as Cycle>::HostCurve =
+            ragu_arithmetic::mul(coeffs.iter(), generators.g()[..k].iter()).into();
+
+        prop_assert_eq!(poly.commit_to_affine(generators), prefix_commit);
+    }
+
+    #[test]
+    fn commit_batch_matches_independent(
+        p0 in arb_any_poly(),
+        p1 in arb_any_poly(),
+        p2 in arb_any_poly(),
+    ) {
+        use ragu_arithmetic::Cycle;
+        use ragu_pasta::Pasta;
+
+        let pasta = Pasta::baked();
+        let generators = Pasta::host_generators(pasta);
+
+        let batch = Polynomial::commit_batch(&[&p0, &p1, &p2], generators);
+        let independent: Vec<<Pasta as Cycle>::HostCurve> = [&p0, &p1, &p2]
+            .iter()
+            .map(|p| p.commit_to_affine(generators))
+            .collect();
+
+        prop_assert_eq!(batch, independent);
+    }
+
     #[test]
     fn iter_coeffs_interleaved(poly in arb_any_poly()) {
         let dense = poly.to_dense();
@@ -476,6 +573,16 @@ proptest! {
         prop_assert_eq!(from_iter, dense);
     }
 
+    #[test]
+    fn coeff_at_matches_iter_coeffs(poly in arb_any_poly()) {
+        let coeffs: Vec<Fp> = poly.iter_coeffs().collect();
+        prop_assert_eq!(poly.len_coeffs(), coeffs.len());
+        for (i, coeff) in coeffs.iter().enumerate() {
+            prop_assert_eq!(poly.coeff_at(i), Some(*coeff));
+        }
+        prop_assert_eq!(poly.coeff_at(coeffs.len()), None);
+    }
+
     #[test]
     fn sub_self_is_zero(poly in arb_any_poly()) {
         let mut result = poly.clone();
@@ -493,6 +600,32 @@ proptest! {
         let x = Fp::random(&mut rand::rng());
         prop_assert_eq!(result.eval(x), Fp::ZERO, "add_assign(-self) should yield zero");
     }
+
+    #[test]
+    fn mul_matches_eval(
+        a in proptest::collection::vec(arb_fe(), 0..=R::num_coeffs() / 2),
+        b in proptest::collection::vec(arb_fe(), 0..=R::num_coeffs() / 2),
+        x in arb_fe(),
+    ) {
+        let a = Polynomial::<Fp, R>::from_coeffs(a);
+        let b = Polynomial::<Fp, R>::from_coeffs(b);
+        let product = a.mul(&b).unwrap();
+        prop_assert_eq!(product.eval(x), a.eval(x) * b.eval(x));
+    }
+}
+
+#[test]
+fn mul_rejects_excess_degree() {
+    let x = Polynomial::<Fp, R>::from_coeffs(vec![Fp::ZERO, Fp::ONE]);
+    let mut coeffs = vec![Fp::ZERO; R::num_coeffs()];
+    coeffs[R::num_coeffs() - 1] = Fp::ONE;
+    let top = Polynomial::<Fp, R>::from_coeffs(coeffs);
+
+    assert!(matches!(
+        top.mul(&x),
+        Err(ragu_core::Error::DegreeBoundExceeded { .. })
+    ));
+    assert_eq!(top.mul(&Polynomial::new()).unwrap(), Polynomial::new());
 }
 
 #[test]
@@ -663,19 +796,25 @@ fn iter_coeffs_fully_drain_both_ends() {
     }
 }
 
+#[test]
+fn random_trace_satisfies_gates() {
+    let n = R::n();
+    let dense = Polynomial::<Fp, R>::random_trace(&mut rand::rng()).to_dense();
+    for i in 0..n {
+        let a = dense[2 * n + i];
+        let b = dense[2 * n - 1 - i];
+        let c = dense[i];
+        let d = dense[4 * n - 1 - i];
+        assert_eq!(a * b, c);
+        assert_eq!(d, Fp::ZERO);
+    }
+}
+
 /// Verifies the product identity: for a valid multiplication-gate assignment
 /// (c = a * b), `rx.revdot(rx_dilated + tz) == 0`.
 #[test]
 fn product_identity() {
-    let mut view = View::<_, R, _>::trace();
-    for _ in 0..R::n() {
-        let a = Fp::random(&mut rand::rng());
-        let b = Fp::random(&mut rand::rng());
-        view.a.push(a);
-        view.b.push(b);
-        view.c.push(a * b);
-    }
-    let rx = view.build();
+    let rx = Polynomial::<Fp, R>::random_trace(&mut rand::rng());
 
     let mut rzx = rx.clone();
     let z = Fp::random(&mut rand::rng());
@@ -745,3 +884,83 @@ fn ring_convolution() {
 
     assert_eq!(a.revdot(&b), cx);
 }
+
+#[test]
+#[should_panic(expected = "cannot undo a dilation by zero")]
+fn dilate_inv_rejects_zero() {
+    let mut poly = Polynomial::<Fp, R>::from_coeffs(vec![Fp::ONE]);
+    poly.dilate_inv(Fp::ZERO);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "exceeds capacity")]
+fn revdot_rejects_out_of_capacity_blocks() {
+    let a = Polynomial::<Fp, R> {
+        blocks: vec![(R::num_coeffs() - 1, vec![Fp::ONE, Fp::ONE])],
+        _marker: core::marker::PhantomData,
+    };
+    let b = Polynomial::<Fp, R>::from_coeffs(vec![Fp::ONE; R::num_coeffs()]);
+    let _ = a.revdot(&b);
+}
+
+#[test]
+fn from_wires_matches_trace_view() {
+    let a: Vec<Fp> = (1..=5).map(Fp::from).collect();
+    let b: Vec<Fp> = (6..=10).map(Fp::from).collect();
+    let c: Vec<Fp> = (11..=15).map(Fp::from).collect();
+    let d: Vec<Fp> = (16..=20).map(Fp::from).collect();
+
+    let poly = Polynomial::<Fp, R>::from_wires(a.clone(), b.clone(), c.clone(), d.clone()).unwrap();
+    let coeffs = poly.to_dense();
+    let n = R::n();
+    for i in 0..a.len() {
+        assert_eq!(coeffs[2 * n + i], a[i]);
+        assert_eq!(coeffs[2 * n - 1 - i], b[i]);
+        assert_eq!(coeffs[i], c[i]);
+        assert_eq!(coeffs[4 * n - 1 - i], d[i]);
+    }
+
+    let mut view = View::<Fp, R, _>::trace();
+    view.a = a;
+    view.b = b;
+    view.c = c;
+    view.d = d;
+    assert_eq!(poly.to_dense(), view.build().to_dense());
+}
+
+#[test]
+fn from_wires_rejects_malformed_wires() {
+    let wire = |len| vec![Fp::ONE; len];
+
+    assert!(matches!(
+        Polynomial::<Fp, R>::from_wires(wire(3), wire(3), wire(2), wire(3)),
+        Err(ragu_core::Error::VectorLengthMismatch {
+            expected: 3,
+            actual: 2
+        })
+    ));
+
+    let n = R::n() + 1;
+    assert!(matches!(
+        Polynomial::<Fp, R>::from_wires(wire(n), wire(n), wire(n), wire(n)),
+        Err(ragu_core::Error::GateBoundExceeded { .. })
+    ));
+}
+
+#[test]
+fn try_from_blocks_rejects_malformed_blocks() {
+    let n = R::num_coeffs();
+    for blocks in [
+        vec![(0, vec![])],
+        vec![(n - 1, vec![Fp::ONE, Fp::ONE])],
+        vec![(usize::MAX, vec![Fp::ONE])],
+        vec![(2, vec![Fp::ONE, Fp::ONE]), (3, vec![Fp::ONE])],
+        vec![(3, vec![Fp::ONE]), (0, vec![Fp::ONE])],
+    ] {
+        assert!(matches!(
+            Polynomial::<Fp, R>::try_from_blocks(blocks),
+            Err(ragu_core::Error::MalformedEncoding(_))
+        ));
+    }
+}
@@ -15,7 +15,7 @@
 //! to compile the added circuits into a registry polynomial representation that can
 //! be efficiently evaluated at different restrictions.
 
-use alloc::{boxed::Box, collections::btree_map::BTreeMap, vec::Vec};
+use alloc::{boxed::Box, collections::btree_map::BTreeMap, sync::Arc, vec::Vec};
 
 use blake2b_simd::Params;
 use ff::{Field, FromUniformBytes, PrimeField};
@@ -83,6 +83,7 @@ pub struct RegistryBuilder<'params, F: PrimeField, R: Rank> {
     bonding: Vec<Box<dyn CircuitObject<F, R> + 'params>>,
     internal_steps: Vec<Box<dyn CircuitObject<F, R> + 'params>>,
     application_steps: Vec<Box<dyn CircuitObject<F, R> + 'params>>,
+    max_routine_depth: usize,
 }
 
 impl<F: FromUniformBytes<64>, R: Rank> Default for RegistryBuilder<'_, F, R> {
@@ -99,9 +100,31 @@ impl<'params, F: FromUniformBytes<64>, R: Rank> RegistryBuilder<'params, F, R> {
             bonding: Vec::new(),
             internal_steps: Vec::new(),
             application_steps: Vec::new(),
+            max_routine_depth: crate::DEFAULT_MAX_ROUTINE_DEPTH,
         }
     }
 
+    /// Creates a new empty [`Registry`] builder with space reserved for
+    /// `num_application_steps` application steps.
+    pub fn with_capacity(num_application_steps: usize) -> Self {
+        Self {
+            application_steps: Vec::with_capacity(num_application_steps),
+            ..Self::new()
+        }
+    }
+
+    /// Sets how many levels deep routines may nest within circuits registered
+    /// after this call, in place of [`DEFAULT_MAX_ROUTINE_DEPTH`].
+    ///
+    /// Registering a circuit that nests deeper fails with
+    /// [`Error::RoutineRecursionLimit`].
+    ///
+    /// [`DEFAULT_MAX_ROUTINE_DEPTH`]: crate::DEFAULT_MAX_ROUTINE_DEPTH
+    pub fn with_max_routine_depth(mut self, max_routine_depth: usize) -> Self {
+        self.max_routine_depth = max_routine_depth;
+        self
+    }
+
     /// Returns the number of internal circuits (circuits + bonding).
     pub fn num_internal_circuits(&self) -> usize {
         self.bonding.len() + self.internal_circuits.len()
@@ -123,7 +146,10 @@ impl<'params, F: FromUniformBytes<64>, R: Rank> RegistryBuilder<'params, F, R> {
         C: Circuit<F> + 'params,
     {
         self.application_steps
-            .push(crate::into_circuit_object(circuit)?);
+            .push(crate::into_circuit_object_with_max_routine_depth(
+                circuit,
+                self.max_routine_depth,
+            )?);
         Ok(self)
     }
 
@@ -133,7 +159,10 @@ impl<'params, F: FromUniformBytes<64>, R: Rank> RegistryBuilder<'params, F, R> {
         C: Circuit<F> + 'params,
     {
         self.internal_circuits
-            .push(crate::into_circuit_object(circuit)?);
+            .push(crate::into_circuit_object_with_max_routine_depth(
+                circuit,
+                self.max_routine_depth,
+            )?);
         Ok(self)
     }
 
@@ -143,7 +172,10 @@ impl<'params, F: FromUniformBytes<64>, R: Rank> RegistryBuilder<'params, F, R> {
         C: Circuit<F> + 'params,
     {
         self.internal_steps
-            .push(crate::into_circuit_object(circuit)?);
+            .push(crate::into_circuit_object_with_max_routine_depth(
+                circuit,
+                self.max_routine_depth,
+            )?);
         Ok(self)
     }
 
@@ -215,6 +247,8 @@ impl<'params, F: FromUniformBytes<64>, R: Rank> RegistryBuilder<'params, F, R> {
             floor_plans,
             omega_lookup,
             key: Key::default(),
+            #[cfg(any(feature = "std", test))]
+            last_at: std::sync::Mutex::new(None),
         };
         registry.key = Key::new(registry.compute_registry_digest());
 
@@ -309,6 +343,12 @@ pub struct Registry<'params, F: PrimeField, R: Rank> {
 
     /// Registry key used to bind circuits to this registry.
     key: Key<F>,
+
+    /// The most recent $W$ point passed to [`Registry::at`], along with its
+    /// Lagrange state and mask coefficient sum, shared with every
+    /// [`RegistryAt`] requested at the same point.
+    #[cfg(any(feature = "std", test))]
+    last_at: std::sync::Mutex<Option<(F, Arc<LagrangeCache<F>>, F)>>,
 }
 
 /// Cached Lagrange state for a fixed W point.
@@ -328,7 +368,7 @@ enum LagrangeCache<F> {
 /// evaluating at multiple X/Y points.
 pub struct RegistryAt<'a, F: PrimeField, R: Rank> {
     registry: &'a Registry<'a, F, R>,
-    cache: LagrangeCache<F>,
+    cache: Arc<LagrangeCache<F>>,
     mask_coeff_sum: F,
 }
 
@@ -363,6 +403,27 @@ impl<F: PrimeField, R: Rank> Registry<'_, F, R> {
         self.assemble_with_alpha(trace, circuit, F::random(rng))
     }
 
+    /// Like [`assemble`](Self::assemble), but also pushes the sampled
+    /// `alpha` onto `alphas_out`.
+    ///
+    /// Commitments to assembled polynomials carry no separate blinding
+    /// factor; `alpha` is the only randomness they depend on. Passing the
+    /// recorded value back to [`assemble_with_alpha`](Self::assemble_with_alpha)
+    /// reproduces the same polynomial, and therefore the same commitment,
+    /// which is useful for debugging and for replaying a proof's witness
+    /// polynomials deterministically.
+    pub fn assemble_recording(
+        &self,
+        trace: &crate::trace::Trace<F>,
+        circuit: CircuitIndex,
+        rng: &mut impl rand::CryptoRng,
+        alphas_out: &mut Vec<F>,
+    ) -> Result<sparse::Polynomial<F, R>> {
+        let alpha = F::random(rng);
+        alphas_out.push(alpha);
+        self.assemble_with_alpha(trace, circuit, alpha)
+    }
+
     /// Like [`assemble`](Self::assemble), but accepts an explicit
     /// `alpha` instead of sampling one from an RNG.
     ///
@@ -455,6 +516,37 @@ impl<F: PrimeField, R: Rank> Registry<'_, F, R> {
         self.wxy(i.omega_j(), x, y)
     }
 
+    /// Computes $s_i(x, Y)$ for circuit `i` once, returning a closure that
+    /// evaluates it at any $y$ in agreement with
+    /// [`circuit_xy`](Self::circuit_xy), for any circuit `i` registered in
+    /// this registry.
+    ///
+    /// This amortizes the cost of evaluating $s_i(x, y)$ at a fixed $x$ for
+    /// many values of $y$. The circuit is evaluated directly rather than
+    /// through [`Registry::at`], so the restriction it retains is left intact.
+    pub fn circuit_x_cached(&self, i: CircuitIndex, x: F) -> impl Fn(F) -> F {
+        let i = usize::from(i);
+        let circuit = self.circuits.get(i).map(|circuit| {
+            (
+                circuit.sx_cached(x, &self.floor_plans[i]),
+                circuit.is_mask(),
+            )
+        });
+
+        move |y| {
+            let mut result = self.key_sxy(x, y);
+            if let Some((sx, is_mask)) = &circuit {
+                result += sx(y);
+                // Masking polynomials return only -notch; add the shared
+                // global scalar.
+                if *is_mask {
+                    result += crate::staging::mask::global_mask::<F, R>(x, y);
+                }
+            }
+            result
+        }
+    }
+
     /// Returns true if the circuit's $\omega^j$ value is in the registry domain.
     ///
     /// See [`CircuitIndex::omega_j`] for details on the $\omega^j$ mapping.
@@ -547,7 +639,40 @@ impl<F: PrimeField, R: Rank> Registry<'_, F, R> {
     ///
     /// Returns a [`RegistryAt`] that can be used to evaluate the registry
     /// polynomial at multiple $X$/$Y$ points without recomputing the W-restriction.
+    ///
+    /// With the `std` feature enabled, the most recent restriction is retained,
+    /// so that repeated calls with the same $W$ point do not recompute it.
     pub fn at(&self, w: F) -> RegistryAt<'_, F, R> {
+        #[cfg(any(feature = "std", test))]
+        let (cache, mask_coeff_sum) = {
+            let cached = self
+                .last_at
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .as_ref()
+                .filter(|(last_w, _, _)| *last_w == w)
+                .map(|(_, cache, mask_coeff_sum)| (Arc::clone(cache), *mask_coeff_sum));
+
+            cached.unwrap_or_else(|| {
+                let (cache, mask_coeff_sum) = self.restrict(w);
+                *self.last_at.lock().unwrap_or_else(|e| e.into_inner()) =
+                    Some((w, Arc::clone(&cache), mask_coeff_sum));
+                (cache, mask_coeff_sum)
+            })
+        };
+        #[cfg(not(any(feature = "std", test)))]
+        let (cache, mask_coeff_sum) = self.restrict(w);
+
+        RegistryAt {
+            registry: self,
+            cache,
+            mask_coeff_sum,
+        }
+    }
+
+    /// Computes the Lagrange state and mask coefficient sum for the $W$ point
+    /// `w`.
+    fn restrict(&self, w: F) -> (Arc<LagrangeCache<F>>, F) {
         let cache = if let Some(coeffs) = self.domain.ell(w, self.domain.n()) {
             // w is not in the domain; use Lagrange coefficients to interpolate.
             LagrangeCache::Interpolate(coeffs)
@@ -559,11 +684,7 @@ impl<F: PrimeField, R: Rank> Registry<'_, F, R> {
             LagrangeCache::Empty
         };
         let mask_coeff_sum = self.mask_coeff_sum(&cache);
-        RegistryAt {
-            registry: self,
-            cache,
-            mask_coeff_sum,
-        }
+        (Arc::new(cache), mask_coeff_sum)
     }
 }
 
@@ -700,11 +821,14 @@ mod tests {
 
     use ff::{Field, PrimeField};
     use ragu_arithmetic::{Domain, bitreverse};
-    use ragu_core::Result;
+    use ragu_core::{Error, Result};
     use ragu_pasta::Fp;
 
     use super::{CircuitIndex, OmegaKey, RegistryBuilder};
-    use crate::{polynomials::TestRank, tests::SquareCircuit};
+    use crate::{
+        CircuitExt, DEFAULT_MAX_ROUTINE_DEPTH, metrics::tests::RecursiveCircuit,
+        polynomials::TestRank, tests::SquareCircuit,
+    };
     type TestRegistryBuilder<'a> = RegistryBuilder<'a, Fp, TestRank>;
 
     #[test]
@@ -775,6 +899,64 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_circuit_x_cached() -> Result<()> {
+        let registry = TestRegistryBuilder::new()
+            .register_circuit(SquareCircuit { times: 2 })?
+            .register_circuit(SquareCircuit { times: 5 })?
+            .finalize()?;
+
+        let x = Fp::random(&mut rand::rng());
+        for i in 0..registry.num_circuits() {
+            let i = CircuitIndex::new(i);
+            let sx = registry.circuit_x_cached(i, x);
+            let circuit = &registry.circuits[usize::from(i)];
+            let floor_plan = &registry.floor_plans[usize::from(i)];
+            let circuit_sx = circuit.sx_cached(x, floor_plan);
+            for _ in 0..4 {
+                let y = Fp::random(&mut rand::rng());
+                assert_eq!(sx(y), registry.circuit_xy(i, x, y));
+                assert_eq!(circuit_sx(y), circuit.sxy(x, y, floor_plan));
+            }
+        }
+
+        // The circuits are evaluated without displacing the restriction
+        // retained by `Registry::at`.
+        let w = Fp::random(&mut rand::rng());
+        let at = registry.at(w);
+        let _ = registry.circuit_x_cached(CircuitIndex::new(0), x);
+        assert!(Arc::ptr_eq(&registry.at(w).cache, &at.cache));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_assemble_recording_replays() -> Result<()> {
+        let registry = TestRegistryBuilder::new()
+            .register_circuit(SquareCircuit { times: 2 })?
+            .register_circuit(SquareCircuit { times: 5 })?
+            .finalize()?;
+
+        let circuit = CircuitIndex::new(1);
+        let trace = SquareCircuit { times: 5 }
+            .trace(Fp::from(3u64))?
+            .into_output();
+
+        let mut alphas = alloc::vec::Vec::new();
+        let recorded =
+            registry.assemble_recording(&trace, circuit, &mut rand::rng(), &mut alphas)?;
+        assert_eq!(alphas.len(), 1);
+
+        let replayed = registry.assemble_with_alpha(&trace, circuit, alphas[0])?;
+        let point = Fp::random(&mut rand::rng());
+        assert_eq!(recorded.eval(point), replayed.eval(point));
+
+        let fresh = registry.assemble_with_alpha(&trace, circuit, alphas[0] + Fp::ONE)?;
+        assert_ne!(recorded.eval(point), fresh.eval(point));
+
+        Ok(())
+    }
+
     #[test]
     fn test_registry_at_consistency() -> Result<()> {
         let registry = TestRegistryBuilder::new()
@@ -821,6 +1003,35 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_registry_at_reuses_last_restriction() -> Result<()> {
+        let registry = TestRegistryBuilder::new()
+            .register_circuit(SquareCircuit { times: 2 })?
+            .register_circuit(SquareCircuit { times: 5 })?
+            .finalize()?;
+
+        let w = Fp::random(&mut rand::rng());
+        let x = Fp::random(&mut rand::rng());
+        let y = Fp::random(&mut rand::rng());
+
+        let first = registry.at(w);
+
+        // The same point shares the retained restriction...
+        let again = registry.at(w);
+        assert!(Arc::ptr_eq(&first.cache, &again.cache));
+        assert_eq!(again.xy(x, y), first.xy(x, y));
+
+        // ... while a different point replaces it.
+        let other = registry.at(w + Fp::ONE);
+        assert!(!Arc::ptr_eq(&first.cache, &other.cache));
+        assert_ne!(other.xy(x, y), first.xy(x, y));
+        let recomputed = registry.at(w);
+        assert!(!Arc::ptr_eq(&first.cache, &recomputed.cache));
+        assert_eq!(recomputed.xy(x, y), first.xy(x, y));
+
+        Ok(())
+    }
+
     #[test]
     fn test_out_of_domain_w_uses_interpolation() -> Result<()> {
         let registry = TestRegistryBuilder::new()
@@ -908,6 +1119,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_max_routine_depth() -> Result<()> {
+        // The outermost routine sits at depth 1, so `RecursiveCircuit(n)`
+        // nests `n + 1` levels deep.
+        let deep = || RecursiveCircuit(DEFAULT_MAX_ROUTINE_DEPTH);
+        assert!(matches!(
+            TestRegistryBuilder::new().register_circuit(deep()),
+            Err(Error::RoutineRecursionLimit {
+                limit: DEFAULT_MAX_ROUTINE_DEPTH
+            })
+        ));
+        TestRegistryBuilder::new()
+            .with_max_routine_depth(DEFAULT_MAX_ROUTINE_DEPTH + 1)
+            .register_circuit(deep())?
+            .finalize()?;
+
+        let builder = TestRegistryBuilder::new()
+            .with_max_routine_depth(4)
+            .register_circuit(RecursiveCircuit(3))?;
+        assert!(matches!(
+            builder.register_circuit(RecursiveCircuit(4)),
+            Err(Error::RoutineRecursionLimit { limit: 4 })
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_omega_j_consistency() -> Result<()> {
         for num_circuits in [2usize, 3, 7, 8, 15, 16, 32] {
@@ -257,8 +257,8 @@ mod tests {
         StageMask,
     };
     use crate::{
-        CircuitObject, WithAux, floor_planner, into_circuit_object, into_raw_circuit_object,
-        metrics,
+        CircuitObject, DEFAULT_MAX_ROUTINE_DEPTH, WithAux, floor_planner, into_circuit_object,
+        into_raw_circuit_object, metrics,
         polynomials::{Rank, sparse},
         raw::GateWires,
         staging::StageBuilder,
@@ -344,7 +344,7 @@ mod tests {
     fn mask_circuit_object(
         mask: StageMask<R>,
     ) -> alloc::boxed::Box<dyn CircuitObject<Fp, R> + 'static> {
-        let metrics = metrics::eval_raw::<Fp, _>(&mask).unwrap();
+        let metrics = metrics::eval_raw::<Fp, _>(&mask, DEFAULT_MAX_ROUTINE_DEPTH).unwrap();
         into_raw_circuit_object::<Fp, _, R>(mask, metrics).unwrap()
     }
 
@@ -623,6 +623,31 @@ mod tests {
 
         }
 
+        #[test]
+        fn test_check_grid_proptest(skip in 1..R::n(), num in 0..R::n()) {
+            prop_assume!(skip + num <= R::n());
+
+            let generic = mask_circuit_object(StageMask::<R>::new(skip, num).unwrap());
+            let plan = floor_planner::floor_plan(generic.segment_records());
+            let stripped = crate::staging::bonding::Stripped::new(generic);
+            let stage_mask = StageMask::<R>::new(skip, num).unwrap();
+
+            let xs = [Fp::ZERO, Fp::random(&mut rand::rng()), Fp::random(&mut rand::rng())];
+            let ys = [Fp::ZERO, Fp::random(&mut rand::rng())];
+
+            let grid = stripped.sample_grid(&xs, &ys, &plan);
+            prop_assert_eq!(grid.len(), xs.len());
+            for (row, &x) in grid.iter().zip(&xs) {
+                prop_assert_eq!(row.len(), ys.len());
+                for (&sxy, &y) in row.iter().zip(&ys) {
+                    prop_assert_eq!(sxy, stripped.sxy(x, y, &plan));
+                }
+            }
+
+            prop_assert!(stripped.check_grid(&xs, &ys, &plan));
+            prop_assert!(stage_mask.check_grid(&xs, &ys, &[]));
+        }
+
         /// Two adjacent `StageMask`s that partition gates `1..n` must have
         /// notch projections that sum to the global projection (negated),
         /// and notch scalars that sum to the global scalar (negated).
@@ -692,6 +717,42 @@ mod tests {
         }
     }
 
+    /// A stage on top of [`ConstrainedStage`] that cannot fit within `R::n()`.
+    struct OversizedStage;
+
+    impl Stage<Fp, R> for OversizedStage {
+        type Parent = ConstrainedStage;
+        type Witness<'source> = ();
+        type OutputKind = ();
+
+        fn values() -> usize {
+            2 * R::n()
+        }
+
+        fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = Fp>>(
+            &self,
+            _: &mut D,
+            _: DriverValue<D, Self::Witness<'source>>,
+        ) -> Result<Bound<'dr, D, Self::OutputKind>>
+        where
+            Self: 'dr,
+        {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_oversized_stage_chain_rejected() {
+        assert!(matches!(
+            OversizedStage::mask(),
+            Err(ragu_core::Error::GateBoundExceeded { limit }) if limit == R::n()
+        ));
+        assert!(matches!(
+            OversizedStage::final_mask(),
+            Err(ragu_core::Error::GateBoundExceeded { limit }) if limit == R::n()
+        ));
+    }
+
     #[test]
     fn test_enforce_stage_works() {
         let result =
@@ -734,7 +795,8 @@ mod tests {
                 let (mul_from_method, linear_from_method) =
                     <StageMask<R> as CircuitObject<Fp, R>>::constraint_counts(&stage_mask);
 
-                let metrics = metrics::eval_raw::<Fp, _>(&stage_mask).unwrap();
+                let metrics =
+                    metrics::eval_raw::<Fp, _>(&stage_mask, DEFAULT_MAX_ROUTINE_DEPTH).unwrap();
 
                 assert_eq!(
                     mul_from_method, metrics.num_gates,
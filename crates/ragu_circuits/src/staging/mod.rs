@@ -153,6 +153,13 @@ pub trait Stage<F: Field, R: Rank> {
     type OutputKind: GadgetKind<F>;
 
     /// Returns the number of values that are allocated in this stage.
+    ///
+    /// Because this is an ordinary trait method rather than an associated
+    /// constant, a stage chain that does not fit within `R::n()` gates cannot
+    /// be rejected at compile time. It is instead rejected with
+    /// [`GateBoundExceeded`](ragu_core::Error::GateBoundExceeded) when its
+    /// [`mask`](StageExt::mask) or [`final_mask`](StageExt::final_mask) is
+    /// created, which happens during registration.
     fn values() -> usize;
 
     /// Computes the witness for this stage.
@@ -10,7 +10,7 @@ use ragu_pasta::Fp;
 use ragu_primitives::{Element, Simulator};
 
 use crate::{
-    Circuit, WithAux,
+    Circuit, DEFAULT_MAX_ROUTINE_DEPTH, WithAux,
     metrics::{self, RoutineFingerprint, RoutineIdentity},
 };
 
@@ -1329,7 +1329,11 @@ where
         + Sync,
     for<'dr> Ro::Aux<'dr>: Send + Clone,
 {
-    let m = metrics::eval(&SingleRoutineCircuit(routine.clone())).unwrap();
+    let m = metrics::eval(
+        &SingleRoutineCircuit(routine.clone()),
+        DEFAULT_MAX_ROUTINE_DEPTH,
+    )
+    .unwrap();
     assert!(
         m.segments.len() >= 2,
         "fingerprint_via_eval expects at least 2 segments (root + routine); \
@@ -1402,7 +1406,11 @@ fn test_determinism() {
 /// tokens than the first, producing a divergent Horner accumulator.
 #[test]
 fn test_repeated_invocation_fingerprint_stability() {
-    let metrics = metrics::eval(&SingleRoutineCircuit(DoubleSquare)).unwrap();
+    let metrics = metrics::eval(
+        &SingleRoutineCircuit(DoubleSquare),
+        DEFAULT_MAX_ROUTINE_DEPTH,
+    )
+    .unwrap();
     // segments[0] = root, [1] = DoubleSquare, [2] = first SquareOnce,
     // [3] = second SquareOnce.
     assert_eq!(metrics.segments.len(), 4);
@@ -1488,7 +1496,8 @@ fn test_degenerate_cases() {
 /// Segment 0 is Root; segments 1+ are Routine.
 #[test]
 fn test_root_identity() {
-    let metrics = metrics::eval(&SingleRoutineCircuit(SquareOnce)).unwrap();
+    let metrics =
+        metrics::eval(&SingleRoutineCircuit(SquareOnce), DEFAULT_MAX_ROUTINE_DEPTH).unwrap();
 
     assert_eq!(metrics.segments.len(), 2);
     assert!(matches!(
@@ -1513,7 +1522,8 @@ fn test_known_value_regression() {
 /// Fingerprint from metrics::eval matches standalone fingerprint_routine.
 #[test]
 fn test_metrics_integration() {
-    let metrics = metrics::eval(&SingleRoutineCircuit(SquareOnce)).unwrap();
+    let metrics =
+        metrics::eval(&SingleRoutineCircuit(SquareOnce), DEFAULT_MAX_ROUTINE_DEPTH).unwrap();
     let direct = fingerprint_elem(&SquareOnce);
 
     match *metrics.segments[1].identity() {
@@ -1547,7 +1557,11 @@ fn test_triple_nesting() {
     assert_eq!(triple, fingerprint_elem(&PureNesting));
     assert_ne!(triple, fingerprint_elem(&SquareOnce));
 
-    let metrics = metrics::eval(&SingleRoutineCircuit(TripleNesting)).unwrap();
+    let metrics = metrics::eval(
+        &SingleRoutineCircuit(TripleNesting),
+        DEFAULT_MAX_ROUTINE_DEPTH,
+    )
+    .unwrap();
     assert_eq!(metrics.segments.len(), 4);
 }
 
@@ -1608,12 +1622,24 @@ fn test_aliasing_leading_trivial_constraints() {
 /// confirmed by differing metrics (segment counts, child routines).
 #[test]
 fn test_aliasing_metrics_confirm_different_structure() {
-    let m1 = metrics::eval(&SingleRoutineCircuit(DelegateThenEnforce)).unwrap();
-    let m2 = metrics::eval(&SingleRoutineCircuit(AllocThenEnforce)).unwrap();
+    let m1 = metrics::eval(
+        &SingleRoutineCircuit(DelegateThenEnforce),
+        DEFAULT_MAX_ROUTINE_DEPTH,
+    )
+    .unwrap();
+    let m2 = metrics::eval(
+        &SingleRoutineCircuit(AllocThenEnforce),
+        DEFAULT_MAX_ROUTINE_DEPTH,
+    )
+    .unwrap();
     assert_ne!(m1.segments.len(), m2.segments.len());
 
-    let m3 = metrics::eval(&SingleRoutineCircuit(PureNesting)).unwrap();
-    let m4 = metrics::eval(&SingleRoutineCircuit(AllocOnly)).unwrap();
+    let m3 = metrics::eval(
+        &SingleRoutineCircuit(PureNesting),
+        DEFAULT_MAX_ROUTINE_DEPTH,
+    )
+    .unwrap();
+    let m4 = metrics::eval(&SingleRoutineCircuit(AllocOnly), DEFAULT_MAX_ROUTINE_DEPTH).unwrap();
     assert_ne!(m3.segments.len(), m4.segments.len());
 }
 
@@ -1644,8 +1670,16 @@ fn test_delegation_indistinguishable_from_alloc_with_matched_counts() {
 /// is enforced (child output vs local alloc), producing distinct scalars.
 #[test]
 fn test_wire_collision_metrics_identical() {
-    let m1 = metrics::eval(&SingleRoutineCircuit(DelegateEnforceChild)).unwrap();
-    let m2 = metrics::eval(&SingleRoutineCircuit(DelegateEnforceLocal)).unwrap();
+    let m1 = metrics::eval(
+        &SingleRoutineCircuit(DelegateEnforceChild),
+        DEFAULT_MAX_ROUTINE_DEPTH,
+    )
+    .unwrap();
+    let m2 = metrics::eval(
+        &SingleRoutineCircuit(DelegateEnforceLocal),
+        DEFAULT_MAX_ROUTINE_DEPTH,
+    )
+    .unwrap();
     assert_eq!(m1.segments.len(), m2.segments.len());
     assert_eq!(m1.segments.len(), 3);
     for (s1, s2) in m1.segments.iter().zip(m2.segments.iter()) {
@@ -1732,8 +1766,16 @@ fn test_wire_collision_via_eval() {
 /// distinguished solely by the Horner scalar.
 #[test]
 fn test_wire_collision_via_eval_metrics_identical() {
-    let m1 = metrics::eval(&SingleRoutineCircuit(DelegatePadEnforceOutput)).unwrap();
-    let m2 = metrics::eval(&SingleRoutineCircuit(DelegateAllocEnforceFirst)).unwrap();
+    let m1 = metrics::eval(
+        &SingleRoutineCircuit(DelegatePadEnforceOutput),
+        DEFAULT_MAX_ROUTINE_DEPTH,
+    )
+    .unwrap();
+    let m2 = metrics::eval(
+        &SingleRoutineCircuit(DelegateAllocEnforceFirst),
+        DEFAULT_MAX_ROUTINE_DEPTH,
+    )
+    .unwrap();
     assert_eq!(m1.segments.len(), m2.segments.len());
     assert_eq!(m1.segments.len(), 3);
     for (s1, s2) in m1.segments.iter().zip(m2.segments.iter()) {
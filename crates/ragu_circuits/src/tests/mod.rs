@@ -16,7 +16,7 @@ use ragu_primitives::{Element, Simulator};
 
 use crate::{
     Circuit, CircuitExt, CircuitObject, WithAux, floor_planner, into_circuit_object,
-    polynomials::{Rank, TestRank},
+    polynomials::{Rank, TestRank, sparse},
 };
 
 /// Dummy circuit.
@@ -147,7 +147,7 @@ fn test_simple_circuit() {
     let obj = into_circuit_object::<_, _, MyRank>(MySimpleCircuit).unwrap();
     let plan = floor_planner::floor_plan(obj.segment_records());
 
-    let assignment = trace.assemble(&plan, Fp::ZERO).unwrap();
+    let assignment = sparse::Polynomial::<Fp, MyRank>::from_trace(&trace).unwrap();
 
     consistency_checks::<MyRank>(&*obj);
 
@@ -4,6 +4,7 @@
 
 use alloc::{format, vec, vec::Vec};
 
+use ff::Field;
 use proptest::prelude::*;
 use ragu_arithmetic::Coeff;
 use ragu_core::{
@@ -14,7 +15,10 @@ use ragu_core::{
 };
 use ragu_pasta::Fp;
 
-use crate::{Circuit, WithAux};
+use crate::{
+    Circuit, WithAux,
+    polynomials::{ProductionRank, sparse},
+};
 
 /// Maximum number of wire allocations generated at any one point in a scope.
 const MAX_ALLOCS: usize = 6;
@@ -179,7 +183,7 @@ proptest! {
     fn segment_dfs_order(tree in arb_tree()) {
         let circuit = TreeCircuit(tree);
 
-        let metrics = crate::metrics::eval::<Fp, _>(&circuit)
+        let metrics = crate::metrics::eval::<Fp, _>(&circuit, crate::DEFAULT_MAX_ROUTINE_DEPTH)
             .map_err(|e| TestCaseError::fail(format!("metrics: {e:?}")))?;
         let trace = crate::trace::eval::<Fp, _>(&circuit, ())
             .map_err(|e| TestCaseError::fail(format!("trace: {e:?}")))?.into_output();
@@ -201,4 +205,22 @@ proptest! {
             );
         }
     }
+
+    /// Checks that [`sparse::Polynomial::from_trace`] agrees with assembly
+    /// using the [`floor_plan`](crate::floor_planner::floor_plan) of the
+    /// circuit's segment records.
+    #[test]
+    fn from_trace_matches_floor_plan(tree in arb_tree()) {
+        let circuit = TreeCircuit(tree);
+
+        let metrics = crate::metrics::eval::<Fp, _>(&circuit, crate::DEFAULT_MAX_ROUTINE_DEPTH)
+            .map_err(|e| TestCaseError::fail(format!("metrics: {e:?}")))?;
+        let trace = crate::trace::eval::<Fp, _>(&circuit, ())
+            .map_err(|e| TestCaseError::fail(format!("trace: {e:?}")))?.into_output();
+
+        let plan = crate::floor_planner::floor_plan(&metrics.segments);
+        let expected = trace.assemble::<ProductionRank>(&plan, Fp::ZERO).ok();
+        let actual = sparse::Polynomial::<Fp, ProductionRank>::from_trace(&trace).ok();
+        prop_assert!(expected == actual);
+    }
 }
@@ -145,6 +145,29 @@ impl<F: Field> Trace<F> {
 
         Ok(view.build())
     }
+
+    /// Assembles this trace with segments placed in synthesis order and a zero
+    /// `alpha`; see [`sparse::Polynomial::from_trace`].
+    pub(crate) fn assemble_trivial<R: Rank>(&self) -> Result<sparse::Polynomial<F, R>> {
+        // Only the gate placement is used by assembly.
+        let mut gate_start = 0;
+        let floor_plan = self
+            .segments
+            .iter()
+            .map(|seg| {
+                let segment = ConstraintSegment {
+                    gate_start,
+                    constraint_start: 0,
+                    num_gates: seg.a.len(),
+                    num_constraints: 0,
+                };
+                gate_start += seg.a.len();
+                segment
+            })
+            .collect::<Vec<_>>();
+
+        self.assemble(&floor_plan, F::ZERO)
+    }
 }
 
 /// Per-routine state that is saved and restored by [`DriverScope`].
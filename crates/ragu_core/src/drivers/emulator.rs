@@ -165,7 +165,39 @@ impl<M: MaybeKind, F: Field> Mode for Wireless<M, F> {
 ///
 /// The [`Emulator`] driver is parameterized on a [`Mode`], which determines
 /// whether wire assignments are tracked or not ([`Wired`] vs. [`Wireless`]).
-pub struct Emulator<M: Mode>(PhantomData<M>);
+pub struct Emulator<M: Mode> {
+    stats: EmulatorStats,
+    _marker: PhantomData<M>,
+}
+
+/// Counts of the operations performed by an [`Emulator`], as returned by
+/// [`Emulator::stats`].
+///
+/// Since the [`Emulator`] short-circuits [`Routine`]s whose output can be
+/// predicted, these counts describe the work done during emulation, which may
+/// be less than the size of the circuit being emulated.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EmulatorStats {
+    /// The number of multiplication gates allocated.
+    pub num_gates: usize,
+    /// The number of `enforce_zero` constraints.
+    pub num_enforce_zero: usize,
+}
+
+impl<M: Mode> Emulator<M> {
+    fn new() -> Self {
+        Emulator {
+            stats: EmulatorStats::default(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of gates and constraints this [`Emulator`] has seen
+    /// so far.
+    pub fn stats(&self) -> EmulatorStats {
+        self.stats
+    }
+}
 
 impl<F: Field> Emulator<Wired<F>> {
     /// Extract the wires from a gadget produced using a wired [`Emulator`].
@@ -197,7 +229,7 @@ impl<F: Field> Emulator<Wired<F>> {
     /// This is useful for extracting wire assignments from a [`Gadget`] using
     /// [`Emulator::wires`].
     pub fn extractor() -> Self {
-        Emulator(PhantomData)
+        Self::new()
     }
 
     /// Helper utility for executing a closure with a freshly created wired
@@ -215,7 +247,7 @@ impl<M: MaybeKind, F: Field> Emulator<Wireless<M, F>> {
     /// Creates a new [`Emulator`] driver in [`Wireless`] mode, parameterized on
     /// the existence of a witness.
     pub fn wireless() -> Self {
-        Emulator(PhantomData)
+        Self::new()
     }
 
     /// Runs [`Routine::predict`] on a fresh wireless emulator, converting the
@@ -236,6 +268,17 @@ impl<M: MaybeKind, F: Field> Emulator<Wireless<M, F>> {
         let input = StripWires::remap(input)?;
         routine.predict(&mut Self::wireless(), &input)
     }
+
+    /// Resets the counts reported by [`Emulator::stats`] to `stats`,
+    /// usually a value saved from an earlier call to [`Emulator::stats`].
+    ///
+    /// This allows exploring a branch of circuit logic (for instance, with a
+    /// different witness) without the branch being counted. Only the counts
+    /// are rolled back: the emulator holds no wire assignments, and the
+    /// values of gadgets allocated during the branch belong to the caller.
+    pub fn restore_stats(&mut self, stats: EmulatorStats) {
+        self.stats = stats;
+    }
 }
 
 impl<F: Field> Emulator<Wireless<Empty, F>> {
@@ -286,6 +329,7 @@ impl<M: Mode> DriverTypes for Emulator<M> {
         &mut self,
         values: impl Fn() -> Result<(Coeff<M::F>, Coeff<M::F>, Coeff<M::F>, Coeff<M::F>)>,
     ) -> Result<(M::Wire, M::Wire, M::Wire, M::Wire)> {
+        self.stats.num_gates += 1;
         M::gate(values)
     }
 }
@@ -300,6 +344,7 @@ impl<'dr, M: MaybeKind, F: Field> Driver<'dr> for Emulator<Wireless<M, F>> {
     fn add(&mut self, _: impl Fn(Self::LCadd) -> Self::LCadd) -> Self::Wire {}
 
     fn enforce_zero(&mut self, _: impl Fn(Self::LCenforce) -> Self::LCenforce) -> Result<()> {
+        self.stats.num_enforce_zero += 1;
         Ok(())
     }
 
@@ -329,6 +374,7 @@ impl<'dr, F: Field> Driver<'dr> for Emulator<Wired<F>> {
     fn enforce_zero(&mut self, _: impl Fn(Self::LCenforce) -> Self::LCenforce) -> Result<()> {
         // Despite wires existing, the emulator does not enforce linear
         // constraints.
+        self.stats.num_enforce_zero += 1;
 
         Ok(())
     }
@@ -441,6 +487,98 @@ mod tests {
         Ok(())
     }
 
+    // Stats count gates (including allocations) and enforce_zero calls, but
+    // not free operations.
+    #[test]
+    fn stats_count_gates_and_constraints() -> Result<()> {
+        let mut dr = Emulator::<Wireless<Always<()>, F>>::execute();
+        assert_eq!(dr.stats(), EmulatorStats::default());
+
+        let a = dr.alloc(|| Ok(Coeff::One))?;
+        let (x, _, _) = dr.mul(|| Ok((Coeff::One, Coeff::One, Coeff::One)))?;
+        let sum = dr.add(|lc| lc.add(&a).add(&x));
+        let _ = dr.constant(Coeff::Two);
+        dr.enforce_equal(&sum, &a)?;
+
+        assert_eq!(
+            dr.stats(),
+            EmulatorStats {
+                num_gates: 2,
+                num_enforce_zero: 1,
+            }
+        );
+
+        let mut dr = Emulator::<Wired<F>>::extractor();
+        let a = dr.alloc(|| Ok(Coeff::One))?;
+        dr.enforce_zero(|lc| lc.add(&a))?;
+        dr.enforce_zero(|lc| lc.sub(&a))?;
+        assert_eq!(
+            dr.stats(),
+            EmulatorStats {
+                num_gates: 1,
+                num_enforce_zero: 2,
+            }
+        );
+
+        Ok(())
+    }
+
+    // Restoring saved stats discards the counts of a branch, and the
+    // emulator then continues exactly as if the branch had never run.
+    #[test]
+    fn wireless_restore_stats() -> Result<()> {
+        type Executor = Emulator<Wireless<Always<()>, F>>;
+
+        fn prefix(dr: &mut Executor) -> Result<()> {
+            dr.alloc(|| Ok(Coeff::Arbitrary(F::from(3u64))))?;
+            dr.alloc(|| Ok(Coeff::Arbitrary(F::from(5u64))))?;
+            Ok(())
+        }
+
+        // Squares `x` in circuit, returning the witnessed product.
+        fn branch(dr: &mut Executor, x: u64) -> Result<F> {
+            let x = F::from(x);
+            let (a, b, c) = dr.mul(|| {
+                Ok((
+                    Coeff::Arbitrary(x),
+                    Coeff::Arbitrary(x),
+                    Coeff::Arbitrary(x.square()),
+                ))
+            })?;
+            dr.enforce_zero(|lc| lc.add(&a).add(&b).sub(&c))?;
+            Ok(x.square())
+        }
+
+        let mut dr = Executor::execute();
+        prefix(&mut dr)?;
+        let saved = dr.stats();
+
+        // The value computed on the abandoned branch is the caller's.
+        let abandoned = branch(&mut dr, 3)?;
+        assert_ne!(dr.stats(), saved);
+        dr.restore_stats(saved);
+        assert_eq!(dr.stats(), saved);
+        assert_eq!(abandoned, F::from(9u64));
+
+        let taken = branch(&mut dr, 4)?;
+
+        // A fresh emulator that only ever took the second branch ends up in
+        // the same state with the same result.
+        let mut fresh = Executor::execute();
+        prefix(&mut fresh)?;
+        assert_eq!(branch(&mut fresh, 4)?, taken);
+        assert_eq!(fresh.stats(), dr.stats());
+        assert_eq!(
+            dr.stats(),
+            EmulatorStats {
+                num_gates: 3,
+                num_enforce_zero: 1,
+            }
+        );
+
+        Ok(())
+    }
+
     // Constant wires hold the expected field element for each Coeff variant.
     #[test]
     fn wired_constant_returns_correct_wire() -> Result<()> {
@@ -42,6 +42,23 @@ pub enum Error {
         limit: usize,
     },
 
+    /// Circuit placement fails if a computed circuit index does not fit within
+    /// the domain of the circuit registry.
+    #[error("circuit index does not fit within the registry domain ({limit})")]
+    CircuitIndexOverflow {
+        /// The maximum number of circuits the registry domain can hold.
+        limit: usize,
+    },
+
+    /// Drivers may refuse to synthesize routines nested more deeply than some
+    /// limit, so that unbounded recursion is reported rather than overflowing
+    /// the stack.
+    #[error("exceeded the maximum routine nesting depth ({limit})")]
+    RoutineRecursionLimit {
+        /// The maximum routine nesting depth allowed by the driver.
+        limit: usize,
+    },
+
     /// Polynomials that exceed some degree bound will trigger this error.
     #[error("exceeded the maximum degree of a polynomial ({limit})")]
     DegreeBoundExceeded {
@@ -49,6 +66,16 @@ pub enum Error {
         limit: usize,
     },
 
+    /// Setup fails if there are fewer fixed generators than the number of
+    /// coefficients that polynomials must be committed with.
+    #[error("insufficient generators (required {required}, available {available})")]
+    InsufficientGenerators {
+        /// The number of generators required.
+        required: usize,
+        /// The number of generators available.
+        available: usize,
+    },
+
     /// Circuits may fail if they're asked to process, construct or verify
     /// witness data without (known) satisfiability.
     #[error("invalid witness: {0}")]
@@ -59,6 +86,20 @@ pub enum Error {
     #[error("malformed encoding: {0}")]
     MalformedEncoding(#[source] Box<dyn error::Error + Send + Sync + 'static>),
 
+    /// Decoding fails if a stream like a proof string ends before everything
+    /// it encodes has been read.
+    #[error("unexpected end of encoded data")]
+    UnexpectedEof,
+
+    /// Proofs may be rejected before verification if a commitment is not a
+    /// valid curve point, or is the identity where a non-identity point is
+    /// required.
+    #[error("degenerate commitment ({commitment})")]
+    DegenerateCommitment {
+        /// The name of the offending commitment.
+        commitment: &'static str,
+    },
+
     /// Violation of length constraint for a fixed-length vector
     #[error("vector does not have the expected length: (expected {expected}, actual {actual})")]
     VectorLengthMismatch {
@@ -68,6 +109,33 @@ pub enum Error {
         actual: usize,
     },
 
+    /// A serialized polynomial does not have the number of coefficients
+    /// required by the rank it is being read at.
+    #[error(
+        "polynomial does not have the expected number of coefficients: (expected {expected}, actual {actual})"
+    )]
+    PolynomialLengthMismatch {
+        /// Number of coefficients required by the rank
+        expected: usize,
+        /// Number of coefficients recorded by the encoding
+        actual: usize,
+    },
+
+    /// Registration fails if a step uses an index that is reserved for steps
+    /// defined internally by the proof system.
+    #[error("step index is reserved for internal use")]
+    ReservedStepIndex,
+
+    /// Fusing fails if the same proof is provided as both children to a step
+    /// that does not allow it.
+    #[error("step does not allow fusing a proof with itself")]
+    SelfFuseNotAllowed,
+
+    /// Fusing fails if a child proof was produced by a step whose output
+    /// header has a different suffix than the header the step expects.
+    #[error("child proof header suffix does not match the step's header")]
+    HeaderSuffixMismatch,
+
     /// Failure in the process of performing setup or other initialization steps.
     #[error("initialization failed: {0}")]
     Initialization(#[source] Box<dyn error::Error + Send + Sync + 'static>),
@@ -89,10 +157,28 @@ fn test_error_display() {
         format!("{}", Error::CircuitBoundExceeded { limit: 256 }),
         "exceeded the maximum number of circuits (256)"
     );
+    assert_eq!(
+        format!("{}", Error::CircuitIndexOverflow { limit: 512 }),
+        "circuit index does not fit within the registry domain (512)"
+    );
+    assert_eq!(
+        format!("{}", Error::RoutineRecursionLimit { limit: 128 }),
+        "exceeded the maximum routine nesting depth (128)"
+    );
     assert_eq!(
         format!("{}", Error::DegreeBoundExceeded { limit: 64 }),
         "exceeded the maximum degree of a polynomial (64)"
     );
+    assert_eq!(
+        format!(
+            "{}",
+            Error::InsufficientGenerators {
+                required: 16,
+                available: 8
+            }
+        ),
+        "insufficient generators (required 16, available 8)"
+    );
     assert_eq!(
         format!("{}", Error::InvalidWitness("division by zero".into())),
         "invalid witness: division by zero"
@@ -101,6 +187,19 @@ fn test_error_display() {
         format!("{}", Error::MalformedEncoding("stream ended".into())),
         "malformed encoding: stream ended"
     );
+    assert_eq!(
+        format!("{}", Error::UnexpectedEof),
+        "unexpected end of encoded data"
+    );
+    assert_eq!(
+        format!(
+            "{}",
+            Error::DegenerateCommitment {
+                commitment: "bridge_f"
+            }
+        ),
+        "degenerate commitment (bridge_f)"
+    );
     assert_eq!(
         format!(
             "{}",
@@ -111,6 +210,28 @@ fn test_error_display() {
         ),
         "vector does not have the expected length: (expected 10, actual 5)"
     );
+    assert_eq!(
+        format!(
+            "{}",
+            Error::PolynomialLengthMismatch {
+                expected: 8192,
+                actual: 128
+            }
+        ),
+        "polynomial does not have the expected number of coefficients: (expected 8192, actual 128)"
+    );
+    assert_eq!(
+        format!("{}", Error::ReservedStepIndex),
+        "step index is reserved for internal use"
+    );
+    assert_eq!(
+        format!("{}", Error::SelfFuseNotAllowed),
+        "step does not allow fusing a proof with itself"
+    );
+    assert_eq!(
+        format!("{}", Error::HeaderSuffixMismatch),
+        "child proof header suffix does not match the step's header"
+    );
     assert_eq!(
         format!(
             "{}",
@@ -146,7 +267,7 @@ fn test_error_source() {
         "Initialization should have a source"
     );
 
-    // Bound variants and VectorLengthMismatch should not chain an inner error.
+    // Variants that do not wrap an error should not chain an inner error.
     let err = Error::GateBoundExceeded { limit: 1 };
     assert!(err.source().is_none());
 
@@ -156,12 +277,45 @@ fn test_error_source() {
     let err = Error::CircuitBoundExceeded { limit: 1 };
     assert!(err.source().is_none());
 
+    let err = Error::CircuitIndexOverflow { limit: 1 };
+    assert!(err.source().is_none());
+
+    let err = Error::RoutineRecursionLimit { limit: 1 };
+    assert!(err.source().is_none());
+
     let err = Error::DegreeBoundExceeded { limit: 1 };
     assert!(err.source().is_none());
 
+    let err = Error::InsufficientGenerators {
+        required: 2,
+        available: 1,
+    };
+    assert!(err.source().is_none());
+
+    let err = Error::UnexpectedEof;
+    assert!(err.source().is_none());
+
+    let err = Error::DegenerateCommitment { commitment: "a" };
+    assert!(err.source().is_none());
+
     let err = Error::VectorLengthMismatch {
         expected: 3,
         actual: 2,
     };
     assert!(err.source().is_none());
+
+    let err = Error::PolynomialLengthMismatch {
+        expected: 3,
+        actual: 2,
+    };
+    assert!(err.source().is_none());
+
+    let err = Error::ReservedStepIndex;
+    assert!(err.source().is_none());
+
+    let err = Error::SelfFuseNotAllowed;
+    assert!(err.source().is_none());
+
+    let err = Error::HeaderSuffixMismatch;
+    assert!(err.source().is_none());
 }
@@ -26,6 +26,9 @@ impl<T: Send> Maybe<T> for Always<T> {
     fn try_just<R: Send, E>(f: impl FnOnce() -> Result<R, E>) -> Result<Perhaps<Self::Kind, R>, E> {
         Ok(Always(f()?))
     }
+    fn constant(value: T) -> Self {
+        Self::just(|| value)
+    }
     fn take(self) -> T {
         self.0
     }
@@ -23,6 +23,9 @@ impl<T: Send> Maybe<T> for Empty {
     fn try_just<R: Send, E>(_: impl FnOnce() -> Result<R, E>) -> Result<Perhaps<Self::Kind, R>, E> {
         Ok(Empty)
     }
+    fn constant(value: T) -> Self {
+        Self::just(|| value)
+    }
     fn take(self) -> T {
         // This panic is guaranteed to occur at compile-time if this function is
         // invoked. (`Maybe<T>` is not dyn compatible so dynamic dispatch will
@@ -100,6 +100,16 @@ pub trait Maybe<T: Send>: sealed::Sealed + Send {
     /// not represent an existing value.
     fn try_just<R: Send, E>(f: impl FnOnce() -> Result<R, E>) -> Result<Perhaps<Self::Kind, R>, E>;
 
+    /// Creates a new value of this `Maybe<T>` from an already computed
+    /// `value`, which is discarded if the concrete type does not represent
+    /// existing values.
+    ///
+    /// Unlike [`just`](Maybe::just), this returns `Self`, so that it can be
+    /// called through an alias such as
+    /// [`DriverValue`](crate::drivers::DriverValue):
+    /// `DriverValue::<D, T>::constant(value)`.
+    fn constant(value: T) -> Self;
+
     /// In contexts where the `Maybe<T>` is known or guaranteed to be an
     /// existing value, this returns the enclosed value. In other contexts, this
     /// will fail at compile time.
@@ -365,12 +375,25 @@ mod tests {
         });
     }
 
+    fn check_constant<K: MaybeKind>() {
+        let v = Perhaps::<K, usize>::constant(42);
+        K::maybe_just(|| {
+            assert_eq!(v.snag(), &42);
+        });
+    }
+
     #[test]
     fn test_just_and_snag() {
         check_just_and_snag::<Always<()>>();
         check_just_and_snag::<Empty>();
     }
 
+    #[test]
+    fn test_constant() {
+        check_constant::<Always<()>>();
+        check_constant::<Empty>();
+    }
+
     #[test]
     fn test_map() {
         check_map::<Always<()>>();
@@ -93,6 +93,21 @@ pub fn derive_consistent(input: TokenStream) -> TokenStream {
     })
 }
 
+#[cfg(test)]
+#[allow(unused_imports)]
+use ragu_pcd::header::define_headers as _;
+
+// Documentation for the `define_headers` macro is in `macro@ragu_pcd::header::define_headers`.
+#[allow(missing_docs)]
+#[proc_macro]
+pub fn define_headers(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as proc::headers::Input);
+    macro_body(|| {
+        let ragu_pcd_path = path_resolution::RaguPcdPath::resolve()?;
+        proc::headers::evaluate(input, ragu_pcd_path)
+    })
+}
+
 #[cfg(test)]
 #[allow(unused_imports)]
 use ragu_core::maybe::MaybeCast as _;
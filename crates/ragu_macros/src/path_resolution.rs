@@ -1,4 +1,4 @@
-//! Resolving paths for `ragu_core` and `ragu_primitives`.
+//! Resolving paths for `ragu_core`, `ragu_primitives` and `ragu_pcd`.
 //!
 //! If the end-user invoking the procedural macro is using the `ragu` crate and
 //! not importing `ragu_core`, we need to identify the path inside `ragu` that
@@ -16,6 +16,9 @@ pub struct RaguCorePath(Path);
 #[derive(Clone)]
 pub struct RaguPrimitivesPath(Path);
 
+#[derive(Clone)]
+pub struct RaguPcdPath(Path);
+
 impl ToTokens for RaguCorePath {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         self.0.to_tokens(tokens)
@@ -28,6 +31,12 @@ impl ToTokens for RaguPrimitivesPath {
     }
 }
 
+impl ToTokens for RaguPcdPath {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
 impl Default for RaguCorePath {
     fn default() -> Self {
         Self(parse_quote! { ::ragu_core })
@@ -40,6 +49,12 @@ impl Default for RaguPrimitivesPath {
     }
 }
 
+impl Default for RaguPcdPath {
+    fn default() -> Self {
+        Self(parse_quote! { ::ragu_pcd })
+    }
+}
+
 fn ragu_core_path() -> Result<Path> {
     Ok(match (crate_name("ragu_core"), crate_name("ragu")) {
         (Ok(FoundCrate::Itself), _) => parse_quote! { ::ragu_core },
@@ -78,6 +93,22 @@ fn ragu_primitives_path() -> Result<Path> {
     })
 }
 
+fn ragu_pcd_path() -> Result<Path> {
+    Ok(match crate_name("ragu_pcd") {
+        Ok(FoundCrate::Itself) => parse_quote! { ::ragu_pcd },
+        Ok(FoundCrate::Name(name)) => {
+            let name: Ident = format_ident!("{}", name);
+            parse_quote! { ::#name }
+        }
+        Err(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "Failed to find ragu_pcd crate. Ensure it is included in your Cargo.toml.",
+            ));
+        }
+    })
+}
+
 impl RaguCorePath {
     pub fn resolve() -> Result<Self> {
         ragu_core_path().map(Self)
@@ -89,3 +120,9 @@ impl RaguPrimitivesPath {
         ragu_primitives_path().map(Self)
     }
 }
+
+impl RaguPcdPath {
+    pub fn resolve() -> Result<Self> {
+        ragu_pcd_path().map(Self)
+    }
+}
@@ -0,0 +1,64 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Result, Token, Type,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+use crate::path_resolution::RaguPcdPath;
+
+pub struct Input {
+    f: Type,
+    _semicolon: Token![;],
+    headers: Punctuated<Type, Token![,]>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            f: input.parse()?,
+            _semicolon: input.parse()?,
+            headers: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+pub fn evaluate(input: Input, ragu_pcd_path: RaguPcdPath) -> Result<TokenStream> {
+    let Input { f, headers, .. } = input;
+    let headers = headers.iter();
+
+    Ok(quote! {
+        const _: () = ::core::assert!(
+            #ragu_pcd_path::header::Suffix::all_distinct(&[
+                #(<#headers as #ragu_pcd_path::header::Header<#f>>::SUFFIX,)*
+            ]),
+            "header suffixes must be distinct"
+        );
+    })
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_evaluate() {
+    use syn::parse_quote;
+
+    assert_eq!(
+        evaluate(
+            parse_quote!(F; HeaderA, HeaderB<C>),
+            RaguPcdPath::default()
+        )
+        .unwrap()
+        .to_string(),
+        quote!(
+            const _: () = ::core::assert!(
+                ::ragu_pcd::header::Suffix::all_distinct(&[
+                    <HeaderA as ::ragu_pcd::header::Header<F>>::SUFFIX,
+                    <HeaderB<C> as ::ragu_pcd::header::Header<F>>::SUFFIX,
+                ]),
+                "header suffixes must be distinct"
+            );
+        )
+        .to_string()
+    );
+}
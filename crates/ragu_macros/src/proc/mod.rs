@@ -1,3 +1,4 @@
+pub mod headers;
 pub mod kind;
 pub mod maybe_cast;
 pub mod repr;
@@ -64,6 +64,9 @@ impl Cycle for Pasta {
     type NestedCurve = pasta_curves::EpAffine;
     type HostCurve = pasta_curves::EqAffine;
 
+    const CIRCUIT_FIELD_BYTES: usize = 32;
+    const SCALAR_FIELD_BYTES: usize = 32;
+
     type HostGenerators = VestaGenerators;
     type NestedGenerators = PallasGenerators;
 
@@ -113,6 +116,17 @@ impl FixedGenerators<pasta_curves::EqAffine> for VestaGenerators {
     }
 }
 
+#[test]
+fn test_field_bytes() {
+    use ff::{Field, PrimeField};
+
+    assert_eq!(
+        Pasta::CIRCUIT_FIELD_BYTES,
+        Fp::ZERO.to_repr().as_ref().len()
+    );
+    assert_eq!(Pasta::SCALAR_FIELD_BYTES, Fq::ZERO.to_repr().as_ref().len());
+}
+
 #[cfg(feature = "baked")]
 mod baked {
     use alloc::vec::Vec;
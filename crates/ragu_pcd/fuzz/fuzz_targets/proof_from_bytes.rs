@@ -0,0 +1,14 @@
+//! Decodes arbitrary bytes as a [`Proof`], which must never panic.
+//!
+//! Run with `cargo fuzz run proof_from_bytes` from `crates/ragu_pcd`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_pasta::Pasta;
+use ragu_pcd::Proof;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = Proof::<Pasta, ProductionRank>::from_bytes(data);
+});
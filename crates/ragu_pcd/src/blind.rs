@@ -0,0 +1,40 @@
+//! Sources of randomness for proof generation.
+//!
+//! [`Application::fuse`](crate::Application::fuse),
+//! [`Application::seed`](crate::Application::seed) and
+//! [`Application::rerandomize`](crate::Application::rerandomize) accept any
+//! [`BlindSource`]. Every [`CryptoRng`] is a [`BlindSource`], so callers that
+//! already hold a random number generator are unaffected.
+//!
+//! Deployments whose entropy comes from elsewhere (a hardware RNG, an enclave
+//! callback, …) can implement [`BlindSource`] directly by handing out a
+//! [`CryptoRng`] seeded from that entropy for the duration of each call.
+
+use rand::CryptoRng;
+
+/// A source of randomness used to sample blinding factors during proof
+/// generation.
+///
+/// [`rng`](BlindSource::rng) is called once per proof-generating operation
+/// and the returned generator is used for all of the randomness that
+/// operation requires.
+pub trait BlindSource {
+    /// The random number generator handed out for a single operation.
+    type Rng<'a>: CryptoRng
+    where
+        Self: 'a;
+
+    /// Returns a random number generator for a single operation.
+    fn rng(&mut self) -> Self::Rng<'_>;
+}
+
+impl<T: CryptoRng> BlindSource for T {
+    type Rng<'a>
+        = &'a mut T
+    where
+        Self: 'a;
+
+    fn rng(&mut self) -> Self::Rng<'_> {
+        self
+    }
+}
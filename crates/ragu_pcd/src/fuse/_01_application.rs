@@ -1,18 +1,23 @@
 //! Evaluate the [`Step`] circuit.
 //!
-//! This creates a witness for the step circuit given the two input [`Pcd`]s and
-//! the step witness. This sets the application fields on the [`ProofBuilder`]
-//! and returns the child proofs along with the output data from the step circuit.
+//! This creates a witness for the step circuit given the two child proofs, the
+//! data they carry and the step witness. This sets the application fields on
+//! the [`ProofBuilder`] and returns the output data from the step circuit.
 
 use ragu_arithmetic::Cycle;
 use ragu_circuits::{CircuitExt, polynomials::Rank};
-use ragu_core::Result;
+use ragu_core::{Error, Result};
 use rand::CryptoRng;
 
 use crate::{
-    Application, Header, Pcd, Proof,
+    Application, Header, Proof,
+    header::Suffix,
+    internal::native::InternalCircuitIndex,
     proof::ProofBuilder,
-    step::{Step, internal::adapter::Adapter},
+    step::{
+        NUM_INTERNAL_STEPS, Step,
+        internal::{adapter::Adapter, rerandomize},
+    },
 };
 
 impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_SIZE> {
@@ -21,18 +26,19 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         rng: &mut RNG,
         step: S,
         witness: S::Witness<'source>,
-        left: Pcd<C, R, S::Left>,
-        right: Pcd<C, R, S::Right>,
+        (left, left_data): (&Proof<C, R>, <S::Left as Header<C::CircuitField>>::Data),
+        (right, right_data): (&Proof<C, R>, <S::Right as Header<C::CircuitField>>::Data),
         builder: &mut ProofBuilder<'_, C, R>,
     ) -> Result<(
-        Proof<C, R>,
-        Proof<C, R>,
+        S,
         <S::Output as Header<C::CircuitField>>::Data,
         S::Aux<'source>,
     )> {
-        let (left_proof, left_data) = left.into_parts();
-        let (right_proof, right_data) = right.into_parts();
-        let (trace, aux) = Adapter::<C, S, R, HEADER_SIZE>::new(step)
+        self.check_output_suffix::<S::Left>(left)?;
+        self.check_output_suffix::<S::Right>(right)?;
+
+        let adapter = Adapter::<C, S, R, HEADER_SIZE>::new(step);
+        let (trace, aux) = adapter
             .trace((left_data, right_data, witness))?
             .into_parts();
         let rx = self.native_registry.assemble(
@@ -43,11 +49,102 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
 
         let ((left_header, right_header), output_data, step_aux) = aux;
 
-        builder.set_circuit_id(S::INDEX.circuit_index(self.num_application_steps)?);
+        builder.set_circuit_id(self.circuit_index_of::<S>()?);
         builder.set_left_header(left_header.into_inner());
         builder.set_right_header(right_header.into_inner());
         builder.set_native_application_rx(rx);
 
-        Ok((left_proof, right_proof, output_data, step_aux))
+        Ok((adapter.into_step(), output_data, step_aux))
+    }
+
+    /// Checks that `proof` was produced by a step whose output header has
+    /// the same suffix as `H`.
+    ///
+    /// Application proofs are checked against the output suffix registered
+    /// for their step. Rerandomized proofs carry the suffix of the header
+    /// they preserve at the end of their left header, and trivial proofs
+    /// stand in for the trivial header `()`.
+    fn check_output_suffix<H: Header<C::CircuitField>>(&self, proof: &Proof<C, R>) -> Result<()> {
+        let suffix = |suffix: Suffix| C::CircuitField::from(suffix.get());
+        let circuit_id = usize::from(proof.circuit_id);
+        let rerandomize = InternalCircuitIndex::NUM + rerandomize::INTERNAL_ID as usize;
+
+        let actual = match circuit_id.checked_sub(NUM_INTERNAL_STEPS + InternalCircuitIndex::NUM) {
+            Some(step) => self.output_suffixes.get(step).copied().map(suffix),
+            None if circuit_id == rerandomize => proof.left_header.last().copied(),
+            None => Some(suffix(<() as Header<C::CircuitField>>::SUFFIX)),
+        };
+
+        match actual {
+            Some(actual) if actual != suffix(H::SUFFIX) => Err(Error::HeaderSuffixMismatch),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ragu_circuits::{polynomials::ProductionRank, registry::CircuitIndex};
+    use ragu_core::drivers::{Driver, DriverValue};
+    use ragu_pasta::{Fp, Pasta};
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+    use crate::{
+        ApplicationBuilder,
+        step::{Encoded, Index},
+    };
+
+    // Local: ragu_testing's fixtures implement the non-test build of this
+    // crate's traits, so they cannot be registered from unit tests.
+    struct Unit;
+
+    impl Step<Pasta> for Unit {
+        const INDEX: Index = Index::new(0);
+        type Witness<'source> = ();
+        type Aux<'source> = ();
+        type Left = ();
+        type Right = ();
+        type Output = ();
+        fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = Fp>, const HEADER_SIZE: usize>(
+            &self,
+            dr: &mut D,
+            _: DriverValue<D, Self::Witness<'source>>,
+            left: DriverValue<D, ()>,
+            right: DriverValue<D, ()>,
+        ) -> Result<(
+            (
+                Encoded<'dr, D, Self::Left, HEADER_SIZE>,
+                Encoded<'dr, D, Self::Right, HEADER_SIZE>,
+                Encoded<'dr, D, Self::Output, HEADER_SIZE>,
+            ),
+            DriverValue<D, ()>,
+            DriverValue<D, Self::Aux<'source>>,
+        )> {
+            let left = Encoded::new(dr, left)?;
+            let right = Encoded::new(dr, right)?;
+            let output = Encoded::new(dr, D::unit())?;
+            Ok(((left, right, output), D::unit(), D::unit()))
+        }
+    }
+
+    #[test]
+    fn circuit_index_of_follows_internal_circuits() -> Result<()> {
+        let pasta = Pasta::baked();
+        let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+            .register(Unit)?
+            .finalize(pasta)?;
+
+        // Application steps come after the internal circuits and steps.
+        let index = app.circuit_index_of::<Unit>()?;
+        assert_eq!(
+            index,
+            CircuitIndex::new(InternalCircuitIndex::NUM + NUM_INTERNAL_STEPS)
+        );
+
+        let (pcd, ()) = app.seed(&mut StdRng::seed_from_u64(1911), Unit, ())?;
+        assert_eq!(pcd.proof().circuit_id(), index);
+
+        Ok(())
     }
 }
@@ -45,7 +45,7 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
             // TODO: these can all be evaluated at the same time; in fact,
             // that's what registry.xy is supposed to allow.
             fixed_registry: native::InternalCircuitValues::from_fn(|id| {
-                registry_xy_poly.eval(id.circuit_index().omega_j())
+                registry_xy_poly.eval(*self.internal_omega_j.get(id))
             }),
             registry_wxy: registry_xy_poly.eval(w),
             left: native::stages::query::ChildEvaluationsWitness::from_proof(
@@ -112,7 +112,7 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         let alpha = *alpha.value().take();
 
         let omega_j = |idx: native::InternalCircuitIndex| -> C::CircuitField {
-            idx.circuit_index().omega_j()
+            *self.internal_omega_j.get(idx)
         };
 
         // This must exactly match the ordering of the `poly_queries` function
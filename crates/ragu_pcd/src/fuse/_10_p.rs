@@ -10,7 +10,6 @@
 //! The commitment is computed via [`PointsWitness`] Horner evaluation.
 
 use alloc::vec::Vec;
-use core::ops::AddAssign;
 
 use ff::Field;
 use ragu_arithmetic::Cycle;
@@ -19,7 +18,7 @@ use ragu_circuits::{
     polynomials::{Rank, sparse},
     staging::{MultiStage, StageExt},
 };
-use ragu_core::{Result, drivers::Driver, maybe::Maybe};
+use ragu_core::{Error, Result, drivers::Driver, maybe::Maybe};
 use ragu_primitives::{Element, extract_endoscalar, lift_endoscalar, vec::Len};
 
 use super::{NativeF, NativeSPrime, RegistryWy};
@@ -31,7 +30,7 @@ use crate::{
             PointsWitness,
         },
         native::{RxComponent, RxIndex},
-        nested::NUM_ENDOSCALING_POINTS,
+        nested::{NUM_CHILD_PROOF_POINTS, NUM_CHILDREN, NUM_ENDOSCALING_POINTS, StagePoint},
     },
     proof::ProofBuilder,
 };
@@ -44,14 +43,31 @@ struct Accumulator<'a, C: Cycle, R: Rank> {
 }
 
 impl<C: Cycle, R: Rank> Accumulator<'_, C, R> {
-    fn acc<P>(&mut self, poly: &P, commitment: C::HostCurve)
-    where
-        for<'p> sparse::Polynomial<C::CircuitField, R>: AddAssign<&'p P>,
-    {
-        self.poly.scale(self.beta);
-        *self.poly += poly;
+    fn acc(&mut self, poly: &sparse::Polynomial<C::CircuitField, R>, commitment: C::HostCurve) {
+        self.poly.add_assign_scaled(self.beta, poly);
         self.commitments.push(commitment);
     }
+
+    /// Accumulates every polynomial of a child proof, contributing exactly
+    /// [`NUM_CHILD_PROOF_POINTS`] commitments.
+    fn acc_proof(&mut self, proof: &Proof<C, R>) {
+        for &id in &RxIndex::ALL {
+            self.acc(&proof[id], proof.native_rx_commitment(id));
+        }
+        self.acc(
+            &proof[RxComponent::AbA],
+            proof.native_commitment(RxComponent::AbA),
+        );
+        self.acc(
+            &proof[RxComponent::AbB],
+            proof.native_commitment(RxComponent::AbB),
+        );
+        self.acc(
+            proof.native_registry_xy_poly(),
+            proof.native_registry_xy_commitment(),
+        );
+        self.acc(proof.native_p_poly(), proof.native_p_commitment());
+    }
 }
 
 impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_SIZE> {
@@ -92,34 +108,29 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
                 beta: effective_beta,
             };
 
-            for proof in [left, right] {
-                for &id in &RxIndex::ALL {
-                    acc.acc(&proof[id], proof.native_rx_commitment(id));
-                }
-                acc.acc(
-                    &proof[RxComponent::AbA],
-                    proof.native_commitment(RxComponent::AbA),
-                );
-                acc.acc(
-                    &proof[RxComponent::AbB],
-                    proof.native_commitment(RxComponent::AbB),
-                );
-                acc.acc(
-                    proof.native_registry_xy_poly(),
-                    proof.native_registry_xy_commitment(),
-                );
-                acc.acc(proof.native_p_poly(), proof.native_p_commitment());
+            let children: [&Proof<C, R>; NUM_CHILDREN] = [left, right];
+            for proof in children {
+                acc.acc_proof(proof);
             }
 
-            acc.acc(&s_prime.registry_wx0_poly, s_prime.registry_wx0_commitment);
-            acc.acc(&s_prime.registry_wx1_poly, s_prime.registry_wx1_commitment);
-            acc.acc(&registry_wy.poly, registry_wy.commitment);
-            acc.acc(builder.native_a_poly(), builder.native_a_commitment());
-            acc.acc(builder.native_b_poly(), builder.native_b_commitment());
-            acc.acc(
-                builder.native_registry_xy_poly(),
-                builder.native_registry_xy_commitment(),
-            );
+            for point in StagePoint::ALL {
+                use StagePoint::*;
+                match point {
+                    RegistryWx0 => {
+                        acc.acc(&s_prime.registry_wx0_poly, s_prime.registry_wx0_commitment)
+                    }
+                    RegistryWx1 => {
+                        acc.acc(&s_prime.registry_wx1_poly, s_prime.registry_wx1_commitment)
+                    }
+                    RegistryWy => acc.acc(&registry_wy.poly, registry_wy.commitment),
+                    AbA => acc.acc(builder.native_a_poly(), builder.native_a_commitment()),
+                    AbB => acc.acc(builder.native_b_poly(), builder.native_b_commitment()),
+                    RegistryXy => acc.acc(
+                        builder.native_registry_xy_poly(),
+                        builder.native_registry_xy_commitment(),
+                    ),
+                }
+            }
         }
 
         // Construct commitment via PointsWitness Horner evaluation.
@@ -128,6 +139,12 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
             let mut points = Vec::with_capacity(NUM_ENDOSCALING_POINTS);
             points.push(f.commitment);
             points.extend_from_slice(&commitments);
+            if points.len() != NUM_ENDOSCALING_POINTS {
+                return Err(Error::VectorLengthMismatch {
+                    expected: NUM_ENDOSCALING_POINTS,
+                    actual: points.len(),
+                });
+            }
 
             let witness =
                 PointsWitness::<C::HostCurve, NUM_ENDOSCALING_POINTS>::new(beta_endo, &points);
@@ -157,7 +174,7 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
                 let step_rx = self.nested_registry.assemble(
                     &step_trace,
                     crate::internal::nested::InternalCircuitIndex::EndoscalingStep(step as u32)
-                        .circuit_index(),
+                        .circuit_index()?,
                     &mut *rng,
                 )?;
                 step_rxs.push(step_rx);
@@ -182,3 +199,71 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ragu_circuits::polynomials::ProductionRank;
+    use ragu_pasta::Pasta;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+    use crate::{ApplicationBuilder, step::internal::trivial::Trivial};
+
+    type TestR = ProductionRank;
+
+    #[test]
+    fn child_proof_contributes_expected_points() {
+        let pasta = Pasta::baked();
+        let app = ApplicationBuilder::<Pasta, TestR, 4>::new()
+            .finalize(pasta)
+            .unwrap();
+        let proof = app.trivial_proof();
+
+        let mut poly = sparse::Polynomial::<<Pasta as Cycle>::CircuitField, TestR>::new();
+        let mut commitments = Vec::new();
+        let mut acc: Accumulator<'_, Pasta, TestR> = Accumulator {
+            poly: &mut poly,
+            commitments: &mut commitments,
+            beta: <Pasta as Cycle>::CircuitField::ONE,
+        };
+        acc.acc_proof(&proof);
+
+        assert_eq!(commitments.len(), NUM_CHILD_PROOF_POINTS);
+    }
+
+    #[test]
+    fn derived_points_match_compute_p() {
+        let pasta = Pasta::baked();
+        let app = ApplicationBuilder::<Pasta, TestR, 4>::new()
+            .finalize(pasta)
+            .unwrap();
+        let mut rng = StdRng::seed_from_u64(1234);
+
+        // `compute_p` fails unless `points.len()` equals
+        // `NUM_ENDOSCALING_POINTS`; seeding exercises that path.
+        let (pcd, ()) = app.seed(&mut rng, Trivial::new(), ()).unwrap();
+
+        assert_eq!(
+            pcd.proof().nested_endoscaling_step_rxs.len(),
+            NumStepsLen::<NUM_ENDOSCALING_POINTS>::len()
+        );
+    }
+
+    #[test]
+    fn step_rxs_match_trivial_proof() -> Result<()> {
+        let pasta = Pasta::baked();
+        let app = ApplicationBuilder::<Pasta, TestR, 4>::new().finalize(pasta)?;
+        let mut rng = StdRng::seed_from_u64(1874);
+
+        let expected = app.trivial_proof().nested_endoscaling_step_rxs.len();
+
+        let (left, ()) = app.seed(&mut rng, Trivial::new(), ())?;
+        let (right, ()) = app.seed(&mut rng, Trivial::new(), ())?;
+        assert_eq!(left.proof().nested_endoscaling_step_rxs.len(), expected);
+
+        let (pcd, ()) = app.fuse(&mut rng, Trivial::new(), (), left, right)?;
+        assert_eq!(pcd.proof().nested_endoscaling_step_rxs.len(), expected);
+
+        Ok(())
+    }
+}
@@ -52,7 +52,7 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
             native::RevdotParameters,
         >::new(
             self.params,
-            total_circuit_counts(self.num_application_steps).1,
+            total_circuit_counts(self.num_application_steps)?.1,
         )
         .trace(native::circuits::hashes_1::Witness {
             unified,
@@ -62,7 +62,7 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         .into_parts();
         let hashes_1_rx = self.native_registry.assemble(
             &hashes_1_trace,
-            native::InternalCircuitIndex::Hashes1Circuit.circuit_index(),
+            native::InternalCircuitIndex::Hashes1Circuit.circuit_index()?,
             &mut *rng,
         )?;
 
@@ -79,7 +79,7 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         .into_parts();
         let hashes_2_rx = self.native_registry.assemble(
             &hashes_2_trace,
-            native::InternalCircuitIndex::Hashes2Circuit.circuit_index(),
+            native::InternalCircuitIndex::Hashes2Circuit.circuit_index()?,
             &mut *rng,
         )?;
 
@@ -98,7 +98,7 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         .into_parts();
         let inner_collapse_rx = self.native_registry.assemble(
             &inner_collapse_trace,
-            native::InternalCircuitIndex::InnerCollapseCircuit.circuit_index(),
+            native::InternalCircuitIndex::InnerCollapseCircuit.circuit_index()?,
             &mut *rng,
         )?;
 
@@ -116,22 +116,24 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         .into_parts();
         let outer_collapse_rx = self.native_registry.assemble(
             &outer_collapse_trace,
-            native::InternalCircuitIndex::OuterCollapseCircuit.circuit_index(),
+            native::InternalCircuitIndex::OuterCollapseCircuit.circuit_index()?,
             &mut *rng,
         )?;
 
         let (compute_v_trace, unified) =
-            native::circuits::compute_v::Circuit::<C, R, HEADER_SIZE>::new()
-                .trace(native::circuits::compute_v::Witness {
-                    unified,
-                    preamble_witness,
-                    query_witness,
-                    eval_witness,
-                })?
-                .into_parts();
+            native::circuits::compute_v::Circuit::<C, R, HEADER_SIZE>::with_omega_j(
+                &self.internal_omega_j,
+            )
+            .trace(native::circuits::compute_v::Witness {
+                unified,
+                preamble_witness,
+                query_witness,
+                eval_witness,
+            })?
+            .into_parts();
         let compute_v_rx = self.native_registry.assemble(
             &compute_v_trace,
-            native::InternalCircuitIndex::ComputeVCircuit.circuit_index(),
+            native::InternalCircuitIndex::ComputeVCircuit.circuit_index()?,
             &mut *rng,
         )?;
 
@@ -246,8 +246,9 @@ impl<'m, 'rx, F: PrimeField, R: Rank> Processor<Atom<'rx, FoldKey, F, R>, Circui
         &mut self,
         id: InternalCircuitIndex,
         rxs: impl Iterator<Item = Atom<'rx, FoldKey, F, R>>,
-    ) {
-        self.circuit_impl(id.circuit_index(), TrackedPoly::sum(rxs));
+    ) -> Result<()> {
+        self.circuit_impl(id.circuit_index()?, TrackedPoly::sum(rxs));
+        Ok(())
     }
 
     fn grouped_bonding_claim(
@@ -256,7 +257,68 @@ impl<'m, 'rx, F: PrimeField, R: Rank> Processor<Atom<'rx, FoldKey, F, R>, Circui
         groups: impl Iterator<Item = impl Iterator<Item = Atom<'rx, FoldKey, F, R>>>,
     ) -> Result<()> {
         let folded = fold_revdot::fold(groups.map(TrackedPoly::sum), self.z);
-        self.bonding_impl(id.circuit_index(), folded);
+        self.bonding_impl(id.circuit_index()?, folded);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ragu_circuits::polynomials::ProductionRank;
+    use ragu_pasta::Pasta;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+    use crate::{
+        ApplicationBuilder,
+        internal::native::{self, RxIndex},
+        step::internal::trivial::Trivial,
+    };
+
+    #[test]
+    fn two_child_claims() -> Result<()> {
+        let pasta = Pasta::baked();
+        let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new().finalize(pasta)?;
+        let mut rng = StdRng::seed_from_u64(1857);
+
+        let (left, ()) = app.seed(&mut rng, Trivial::new(), ())?;
+        let (right, ()) = app.seed(&mut rng, Trivial::new(), ())?;
+        let (left, ()) = left.into_parts();
+        let (right, ()) = right.into_parts();
+        let source = FuseProofSource {
+            left: &left,
+            right: &right,
+        };
+
+        let y = <Pasta as Cycle>::CircuitField::random(&mut rng);
+        let z = <Pasta as Cycle>::CircuitField::random(&mut rng);
+        let mut builder: FuseBuilder<'_, '_, _, ProductionRank> =
+            Builder::new(&app.native_registry, y, z);
+        native::claims::build(&source, &mut builder)?;
+
+        // One raw and one application claim per child, one claim per child
+        // for each internal circuit, and one bonding claim per stage that
+        // aggregates both children.
+        let num_circuits = 5;
+        let num_bonding = InternalCircuitIndex::ALL.len() - num_circuits;
+        assert_eq!(
+            builder.claims().count(),
+            2 * (2 + num_circuits) + num_bonding
+        );
+
+        // The raw claims borrow the children's `ab` polynomials directly.
+        let mut claims = builder.claims();
+        for proof in [&left, &right] {
+            let (a, b) = claims.next().unwrap();
+            assert!(core::ptr::eq(a, &proof[RxComponent::AbA]));
+            assert!(core::ptr::eq(b, &proof[RxComponent::AbB]));
+        }
+        // The application claims borrow the children's application traces.
+        for proof in [&left, &right] {
+            let (a, _) = claims.next().unwrap();
+            assert!(core::ptr::eq(a, &proof[RxIndex::Application]));
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,213 @@
+//! Static cost estimation for [`Application::fuse`].
+//!
+//! The estimate is derived from the shape of the protocol and the constraint
+//! counts recorded in the registries at finalization time; no witness is
+//! generated and no polynomial is committed to.
+
+use ragu_arithmetic::Cycle;
+use ragu_circuits::polynomials::Rank;
+use ragu_core::Result;
+use ragu_primitives::vec::Len;
+
+use crate::{
+    Application,
+    internal::{
+        endoscalar::NumStepsLen,
+        native,
+        nested::{self, NUM_COMMITTED_STAGE_POINTS, NUM_ENDOSCALING_POINTS},
+    },
+    step::Step,
+};
+
+/// Number of full-size host-curve commitments made during a fuse: one per
+/// [`native::RxIndex`] component and one per committed
+/// [`StagePoint`](nested::StagePoint), plus the `f` commitment that
+/// `compute_p` accumulates them onto.
+///
+/// The `ab.a` and `p` commitments are not counted, as they are derived from
+/// known commitments via small MSMs.
+const NUM_HOST_COMMITS: usize = native::RxIndex::NUM + NUM_COMMITTED_STAGE_POINTS + 1;
+
+/// A static estimate of the work performed by a single
+/// [`Application::fuse`] call, as returned by
+/// [`Application::estimate_fuse_cost`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FuseCostEstimate {
+    /// The number of full-size polynomial commitments (multi-scalar
+    /// multiplications) over both curves of the cycle.
+    pub num_commits: usize,
+    /// The total number of multiplication gates across every circuit that is
+    /// traced: the step itself, the native internal circuits and the nested
+    /// endoscaling steps.
+    pub total_gates: usize,
+    /// The number of nested endoscaling steps used to verify the accumulation
+    /// of commitments.
+    pub num_endoscaling_steps: usize,
+}
+
+impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_SIZE> {
+    /// Estimates the cost of [`fuse`](Application::fuse)-ing with the step
+    /// `S`, without generating a proof.
+    ///
+    /// The step `S` must have been registered with this [`Application`]; an
+    /// error is returned otherwise.
+    pub fn estimate_fuse_cost<S: Step<C>>(&self) -> Result<FuseCostEstimate> {
+        let num_endoscaling_steps = NumStepsLen::<NUM_ENDOSCALING_POINTS>::len();
+
+        let num_nested_commits = nested::RxIndex::NUM;
+
+        let (step_gates, _) = self
+            .native_registry
+            .constraint_counts(self.circuit_index_of::<S>()?);
+        let internal_gates = native::RxIndex::ALL
+            .iter()
+            .filter_map(|idx| idx.internal_circuit())
+            .map(|id| {
+                let (gates, _) = self.native_registry.constraint_counts(id.circuit_index()?);
+                Ok(gates)
+            })
+            .sum::<Result<usize>>()?;
+        let endoscaling_gates = (0..num_endoscaling_steps)
+            .map(|step| {
+                let id = nested::InternalCircuitIndex::EndoscalingStep(step as u32);
+                let (gates, _) = self.nested_registry.constraint_counts(id.circuit_index()?);
+                Ok(gates)
+            })
+            .sum::<Result<usize>>()?;
+
+        Ok(FuseCostEstimate {
+            num_commits: NUM_HOST_COMMITS + num_nested_commits,
+            total_gates: step_gates + internal_gates + endoscaling_gates,
+            num_endoscaling_steps,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use ragu_arithmetic::{CurveAffine, FixedGenerators};
+    use ragu_circuits::polynomials::ProductionRank;
+    use ragu_pasta::{Pasta, PastaParams};
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+    use crate::{ApplicationBuilder, step::internal::trivial};
+
+    /// Generators that count how many times they are used to commit.
+    struct Counting<G> {
+        inner: &'static G,
+        commits: AtomicUsize,
+    }
+
+    impl<G> Counting<G> {
+        fn new(inner: &'static G) -> Self {
+            Counting {
+                inner,
+                commits: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    impl<C: CurveAffine, G: FixedGenerators<C>> FixedGenerators<C> for Counting<G> {
+        fn g(&self) -> &[C] {
+            // Every commitment reads the generators exactly once.
+            self.commits.fetch_add(1, Ordering::Relaxed);
+            self.inner.g()
+        }
+
+        fn h(&self) -> &C {
+            self.inner.h()
+        }
+    }
+
+    struct CountingParams {
+        pasta: &'static PastaParams,
+        host: Counting<<Pasta as Cycle>::HostGenerators>,
+        nested: Counting<<Pasta as Cycle>::NestedGenerators>,
+    }
+
+    impl CountingParams {
+        fn commits(&self) -> usize {
+            self.host.commits.load(Ordering::Relaxed) + self.nested.commits.load(Ordering::Relaxed)
+        }
+    }
+
+    /// The Pasta cycle, with generators that count the commitments made.
+    #[derive(Clone, Copy, Default)]
+    struct CountingPasta;
+
+    impl Cycle for CountingPasta {
+        type CircuitField = <Pasta as Cycle>::CircuitField;
+        type ScalarField = <Pasta as Cycle>::ScalarField;
+        type NestedCurve = <Pasta as Cycle>::NestedCurve;
+        type HostCurve = <Pasta as Cycle>::HostCurve;
+
+        const CIRCUIT_FIELD_BYTES: usize = Pasta::CIRCUIT_FIELD_BYTES;
+        const SCALAR_FIELD_BYTES: usize = Pasta::SCALAR_FIELD_BYTES;
+
+        type NestedGenerators = Counting<<Pasta as Cycle>::NestedGenerators>;
+        type HostGenerators = Counting<<Pasta as Cycle>::HostGenerators>;
+
+        type CircuitPoseidon = <Pasta as Cycle>::CircuitPoseidon;
+        type ScalarPoseidon = <Pasta as Cycle>::ScalarPoseidon;
+
+        type Params = CountingParams;
+
+        fn nested_generators(params: &Self::Params) -> &Self::NestedGenerators {
+            &params.nested
+        }
+
+        fn host_generators(params: &Self::Params) -> &Self::HostGenerators {
+            &params.host
+        }
+
+        fn circuit_poseidon(params: &Self::Params) -> &Self::CircuitPoseidon {
+            Pasta::circuit_poseidon(params.pasta)
+        }
+
+        fn scalar_poseidon(params: &Self::Params) -> &Self::ScalarPoseidon {
+            Pasta::scalar_poseidon(params.pasta)
+        }
+
+        fn generate() -> Self::Params {
+            let pasta = Pasta::baked();
+            CountingParams {
+                pasta,
+                host: Counting::new(Pasta::host_generators(pasta)),
+                nested: Counting::new(Pasta::nested_generators(pasta)),
+            }
+        }
+    }
+
+    #[test]
+    fn estimate_matches_fuse_commitments() -> Result<()> {
+        let params = CountingPasta::generate();
+        let app =
+            ApplicationBuilder::<CountingPasta, ProductionRank, 4>::new().finalize(&params)?;
+
+        let estimate = app.estimate_fuse_cost::<trivial::Trivial>()?;
+
+        // Build the trivial children up front so that only the fuse itself
+        // is counted.
+        let trivial = app.trivial();
+        let before = params.commits();
+        app.seed_from_trivial(
+            &mut StdRng::seed_from_u64(1847),
+            trivial::Trivial::new(),
+            (),
+            &trivial,
+        )?;
+        assert_eq!(estimate.num_commits, params.commits() - before);
+
+        let endoscaling_steps = nested::RxIndex::ALL
+            .iter()
+            .filter(|idx| matches!(idx, nested::RxIndex::EndoscalingStep(_)))
+            .count();
+        assert_eq!(estimate.num_endoscaling_steps, endoscaling_steps);
+        assert!(estimate.total_gates > 0);
+
+        Ok(())
+    }
+}
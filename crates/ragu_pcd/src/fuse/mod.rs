@@ -15,17 +15,19 @@ mod _09_eval;
 mod _10_p;
 mod _11_circuits;
 pub(crate) mod claims;
+mod cost;
 
 use claims::FuseProofSource;
+pub use cost::FuseCostEstimate;
 use ff::Field;
 use ragu_arithmetic::Cycle;
 use ragu_circuits::polynomials::{Rank, sparse};
-use ragu_core::{Result, drivers::emulator::Emulator, maybe::Maybe};
-use ragu_primitives::{GadgetExt, Point, vec::CollectFixed};
-use rand::CryptoRng;
+use ragu_core::{Error, Result, drivers::emulator::Emulator, maybe::Maybe};
+use ragu_primitives::{GadgetExt, Point};
 
 use crate::{
-    Application, Pcd, RAGU_TAG, internal::transcript::Transcript, proof::ProofBuilder, step::Step,
+    Application, BlindSource, Header, Pcd, Proof, RAGU_TAG, internal::transcript::Transcript,
+    proof::ProofBuilder, step::Step,
 };
 
 /// Ephemeral native-field data for $f(X)$, used only during the fuse step.
@@ -56,19 +58,37 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
     ///
     /// ## Parameters
     ///
-    /// * `rng`: a random number generator used to sample randomness during
-    ///   proof generation. The fact that this method takes a random number
-    ///   generator is not an indication that the resulting proof-carrying data
-    ///   is zero-knowledge; that must be ensured by performing
-    ///   [`Application::rerandomize`] at a later point.
+    /// * `rng`: a random number generator (or other [`BlindSource`]) used to
+    ///   sample randomness during proof generation. The fact that this method
+    ///   takes a random number generator is not an indication that the
+    ///   resulting proof-carrying data is zero-knowledge; that must be ensured
+    ///   by performing [`Application::rerandomize`] at a later point.
     /// * `step`: the [`Step`] instance that has been registered in this
     ///   [`Application`].
-    /// * `witness`: the witness data for the [`Step`]
+    /// * `witness`: the witness data for the [`Step`], which is first checked
+    ///   with [`Step::validate_witness`].
     /// * `left`: the left [`Pcd`] to fuse in this step; must correspond to the
     ///   [`Step::Left`] header.
     /// * `right`: the right [`Pcd`] to fuse in this step; must correspond to
     ///   the [`Step::Right`] header.
-    pub fn fuse<'source, RNG: CryptoRng, S: Step<C>>(
+    ///
+    /// ## Randomness
+    ///
+    /// [`BlindSource::rng`] is called once, and every blinding factor is drawn
+    /// from the returned generator on the calling thread, in a fixed order:
+    /// the proof's bridge alpha, then each proof component in the order it is
+    /// computed below (application, preamble, $s'$, inner error, outer error,
+    /// query, $f$, eval, $p$ and finally the internal circuits). Work that the
+    /// `multicore` feature parallelizes never samples randomness, so a seeded
+    /// generator produces the same proof regardless of thread scheduling.
+    ///
+    /// ## Transcript
+    ///
+    /// The first challenge $w$ is squeezed only after absorbing the preamble
+    /// commitment, which commits to each child's unified instance, including
+    /// all of the child's own challenges (such as $u$). The new transcript is
+    /// therefore already bound to the specific children being fused.
+    pub fn fuse<'source, RNG: BlindSource, S: Step<C>>(
         &self,
         rng: &mut RNG,
         step: S,
@@ -76,31 +96,97 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         left: Pcd<C, R, S::Left>,
         right: Pcd<C, R, S::Right>,
     ) -> Result<(Pcd<C, R, S::Output>, S::Aux<'source>)> {
+        self.check_self_fuse(&step, left.proof(), right.proof())?;
+
+        let (left, left_data) = left.into_parts();
+        let (right, right_data) = right.into_parts();
+
+        self.fuse_parts(rng, step, witness, (&left, left_data), (&right, right_data))
+    }
+
+    /// Like [`fuse`](Application::fuse), but borrows the child [`Pcd`]s
+    /// instead of consuming them.
+    ///
+    /// Only the data carried by the children is cloned, so the same [`Pcd`]
+    /// can be used as both children, or kept after fusing, without cloning
+    /// its proof.
+    pub fn fuse_ref<'source, RNG: BlindSource, S: Step<C>>(
+        &self,
+        rng: &mut RNG,
+        step: S,
+        witness: S::Witness<'source>,
+        left: &Pcd<C, R, S::Left>,
+        right: &Pcd<C, R, S::Right>,
+    ) -> Result<(Pcd<C, R, S::Output>, S::Aux<'source>)> {
+        self.check_self_fuse(&step, left.proof(), right.proof())?;
+
+        self.fuse_parts(
+            rng,
+            step,
+            witness,
+            (left.proof(), left.data().clone()),
+            (right.proof(), right.data().clone()),
+        )
+    }
+
+    /// Rejects fusing a proof with itself if `step` does not
+    /// [allow it](Step::allow_self_fuse).
+    ///
+    /// Proofs are identified by their application commitment, which is
+    /// blinded and so differs between independently generated proofs.
+    fn check_self_fuse<S: Step<C>>(
+        &self,
+        step: &S,
+        left: &Proof<C, R>,
+        right: &Proof<C, R>,
+    ) -> Result<()> {
+        if !step.allow_self_fuse()
+            && left.native_application_commitment == right.native_application_commitment
+        {
+            return Err(Error::SelfFuseNotAllowed);
+        }
+        Ok(())
+    }
+
+    /// Fuses children given as borrowed proofs and the data they carry.
+    pub(crate) fn fuse_parts<'source, RNG: BlindSource, S: Step<C>>(
+        &self,
+        rng: &mut RNG,
+        step: S,
+        witness: S::Witness<'source>,
+        (left, left_data): (&Proof<C, R>, <S::Left as Header<C::CircuitField>>::Data),
+        (right, right_data): (&Proof<C, R>, <S::Right as Header<C::CircuitField>>::Data),
+    ) -> Result<(Pcd<C, R, S::Output>, S::Aux<'source>)> {
+        step.validate_witness(&witness)?;
+
+        let rng = &mut rng.rng();
         let mut builder = ProofBuilder::new(self.params, C::ScalarField::random(&mut *rng));
 
-        let (left, right, application_data, application_aux) =
-            self.compute_application_proof(rng, step, witness, left, right, &mut builder)?;
+        let (step, application_data, application_aux) = self.compute_application_proof(
+            rng,
+            step,
+            witness,
+            (left, left_data),
+            (right, right_data),
+            &mut builder,
+        )?;
 
         let mut dr = Emulator::execute();
         let mut transcript = Transcript::new(&mut dr, C::circuit_poseidon(self.params), RAGU_TAG)?;
 
-        let preamble_witness = self.compute_preamble(rng, &left, &right, &mut builder)?;
+        let preamble_witness = self.compute_preamble(rng, left, right, &mut builder)?;
         let preamble_commitment = Point::constant(&mut dr, builder.bridge_preamble_commitment())?;
         preamble_commitment.write(&mut dr, &mut transcript)?;
         let w = transcript.challenge(&mut dr)?;
         let native_registry = self.native_registry.at(*w.value().take());
 
         let native_s_prime =
-            self.compute_s_prime(rng, &native_registry, &left, &right, &mut builder)?;
+            self.compute_s_prime(rng, &native_registry, left, right, &mut builder)?;
         let s_prime_commitment = Point::constant(&mut dr, builder.bridge_s_prime_commitment())?;
         s_prime_commitment.write(&mut dr, &mut transcript)?;
-        let y = transcript.challenge(&mut dr)?;
-        let z = transcript.challenge(&mut dr)?;
+        let [y, z] = transcript.challenges(&mut dr)?;
 
-        let source = FuseProofSource {
-            left: &left,
-            right: &right,
-        };
+        let source = FuseProofSource { left, right };
 
         let (inner_error_witness, claims, registry_wy) =
             self.inner_error_terms(rng, &native_registry, &y, &z, &source, &mut builder)?;
@@ -115,12 +201,9 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
             .save_state(&mut dr)
             .expect("save_state should succeed after absorbing")
             .into_elements()
-            .into_iter()
-            .map(|e| *e.value().take())
-            .collect_fixed()?;
+            .map(|e| *e.value().take());
 
-        let mu = transcript.challenge(&mut dr)?;
-        let nu = transcript.challenge(&mut dr)?;
+        let [mu, nu] = transcript.challenges(&mut dr)?;
 
         let (outer_error_witness, a, b) = self.outer_error_terms(
             rng,
@@ -136,25 +219,15 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         let outer_error_commitment =
             Point::constant(&mut dr, builder.bridge_outer_error_commitment()?)?;
         outer_error_commitment.write(&mut dr, &mut transcript)?;
-        let mu_prime = transcript.challenge(&mut dr)?;
-        let nu_prime = transcript.challenge(&mut dr)?;
+        let [mu_prime, nu_prime] = transcript.challenges(&mut dr)?;
 
         self.compute_ab(a, b, &source, &mu_prime, &nu_prime, &mut builder)?;
         let ab_commitment = Point::constant(&mut dr, builder.bridge_ab_commitment()?)?;
         ab_commitment.write(&mut dr, &mut transcript)?;
         let x = transcript.challenge(&mut dr)?;
 
-        let query_witness = self.compute_query(
-            rng,
-            &w,
-            &x,
-            &y,
-            &z,
-            &registry_wy,
-            &left,
-            &right,
-            &mut builder,
-        )?;
+        let query_witness =
+            self.compute_query(rng, &w, &x, &y, &z, &registry_wy, left, right, &mut builder)?;
         let query_commitment = Point::constant(&mut dr, builder.bridge_query_commitment()?)?;
         query_commitment.write(&mut dr, &mut transcript)?;
         let alpha = transcript.challenge(&mut dr)?;
@@ -169,8 +242,8 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
             &native_s_prime,
             &registry_wy,
             &mut builder,
-            &left,
-            &right,
+            left,
+            right,
         )?;
         let f_commitment = Point::constant(&mut dr, builder.bridge_f_commitment())?;
         f_commitment.write(&mut dr, &mut transcript)?;
@@ -179,8 +252,8 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         let eval_witness = self.compute_eval(
             rng,
             &u,
-            &left,
-            &right,
+            left,
+            right,
             &native_s_prime,
             &registry_wy,
             &mut builder,
@@ -192,8 +265,8 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         self.compute_p(
             rng,
             &pre_beta,
-            &left,
-            &right,
+            left,
+            right,
             &native_s_prime,
             &registry_wy,
             &native_f,
@@ -224,7 +297,130 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         )?;
 
         let proof = builder.build()?;
+        step.post_fuse(&proof, &application_aux)?;
 
         Ok((proof.carry(application_data), application_aux))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ragu_arithmetic::CurveAffine;
+    use ragu_circuits::polynomials::ProductionRank;
+    use ragu_pasta::{Fp, Pasta};
+    use ragu_primitives::{Element, Simulator, io::Buffer};
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+    use crate::{ApplicationBuilder, Proof, step::internal::trivial::Trivial};
+
+    /// Replays `transcript.golden` against a fresh transcript, absorbing the
+    /// named commitments of a fused proof and checking that every squeeze
+    /// reproduces the challenge the fuse recorded under that name. Any change
+    /// to the fuse's absorb/squeeze order changes the challenges and fails.
+    #[test]
+    fn transcript_matches_golden() -> Result<()> {
+        let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new().finalize(Pasta::baked())?;
+        let (pcd, ()) = app.seed(&mut StdRng::seed_from_u64(1909), Trivial::new(), ())?;
+        let proof = pcd.proof();
+
+        let commitment = |name: &str| match name {
+            "preamble" => proof.bridge_preamble_commitment(),
+            "s_prime" => proof.bridge_s_prime_commitment(),
+            "inner_error" => proof.bridge_inner_error_commitment(),
+            "outer_error" => proof.bridge_outer_error_commitment(),
+            "ab" => proof.bridge_ab_commitment(),
+            "query" => proof.bridge_query_commitment(),
+            "f" => proof.bridge_f_commitment(),
+            "eval" => proof.bridge_eval_commitment(),
+            _ => panic!("unknown commitment {name}"),
+        };
+        let challenge = |name: &str| match name {
+            "w" => proof.w(),
+            "y" => proof.y(),
+            "z" => proof.z(),
+            "mu" => proof.mu(),
+            "nu" => proof.nu(),
+            "mu_prime" => proof.mu_prime(),
+            "nu_prime" => proof.nu_prime(),
+            "x" => proof.x(),
+            "alpha" => proof.alpha(),
+            "u" => proof.u(),
+            "pre_beta" => proof.pre_beta(),
+            _ => panic!("unknown challenge {name}"),
+        };
+
+        // `Transcript::new` absorbs the length-prefixed tag itself.
+        let mut lines = include_str!("transcript.golden").lines().peekable();
+        let mut tag_absorbs = 0;
+        while lines.next_if_eq(&"absorb tag").is_some() {
+            tag_absorbs += 1;
+        }
+        assert_eq!(tag_absorbs, 1 + RAGU_TAG.len().div_ceil(16));
+
+        let mut dr = Simulator::<Fp>::new();
+        let mut transcript =
+            Transcript::new(&mut dr, Pasta::circuit_poseidon(Pasta::baked()), RAGU_TAG)?;
+        for line in lines {
+            match line.split_once(' ') {
+                Some(("absorb", coordinate)) => {
+                    let (name, axis) = coordinate.rsplit_once('.').expect("absorb names an axis");
+                    let coordinates = commitment(name).coordinates().unwrap();
+                    let value = match axis {
+                        "x" => *coordinates.x(),
+                        "y" => *coordinates.y(),
+                        _ => panic!("unknown axis in {line}"),
+                    };
+                    transcript.write(&mut dr, &Element::constant(&mut dr, value))?;
+                }
+                Some(("squeeze", name)) => {
+                    let squeezed = transcript.challenge(&mut dr)?;
+                    assert_eq!(*squeezed.value().take(), challenge(name), "{line}");
+                }
+                _ => panic!("malformed golden line {line}"),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn fuse_is_deterministic_for_seeded_rng() -> Result<()> {
+        let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new().finalize(Pasta::baked())?;
+        let fuse = |seed: u64| -> Result<Proof<Pasta, ProductionRank>> {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let (pcd, ()) = app.seed(&mut rng, Trivial::new(), ())?;
+            Ok(pcd.into_parts().0)
+        };
+
+        assert!(fuse(1889)? == fuse(1889)?);
+        assert!(fuse(1889)? != fuse(1890)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn child_challenges_bind_transcript() -> Result<()> {
+        let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new().finalize(Pasta::baked())?;
+        let child = |seed: u64| app.seed(&mut StdRng::seed_from_u64(seed), Trivial::new(), ());
+        let (a, ()) = child(1905)?;
+        let (b, ()) = child(1906)?;
+        assert_ne!(a.proof().u(), b.proof().u());
+
+        // The parent is fused with identical randomness in both cases.
+        let parent = |child: &Pcd<Pasta, ProductionRank, ()>| {
+            app.fuse_ref(
+                &mut StdRng::seed_from_u64(1907),
+                Trivial::new(),
+                (),
+                child,
+                child,
+            )
+        };
+        let (from_a, ()) = parent(&a)?;
+        let (from_b, ()) = parent(&b)?;
+        assert_ne!(from_a.proof().w(), from_b.proof().w());
+
+        Ok(())
+    }
+}
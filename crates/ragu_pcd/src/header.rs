@@ -37,8 +37,28 @@ pub struct Suffix {
 }
 
 impl Suffix {
+    /// The largest value accepted by [`Suffix::new`].
+    ///
+    /// Application suffixes are offset past the suffixes reserved for internal
+    /// use, so they can never collide with them; this bound ensures that the
+    /// offset value does not overflow.
+    pub const MAX: usize = usize::MAX - NUM_INTERNAL_SUFFIXES as usize;
+
     /// Creates a new application-defined [`Header`] suffix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` exceeds [`Suffix::MAX`]. Since [`Header::SUFFIX`] is
+    /// a constant, this is reported at compile time:
+    ///
+    /// ```rust,compile_fail
+    /// # use ragu_pcd::header::Suffix;
+    /// const SUFFIX: Suffix = Suffix::new(usize::MAX);
+    /// # let _ = SUFFIX;
+    /// ```
     pub const fn new(value: usize) -> Self {
+        assert!(value <= Suffix::MAX, "header suffix exceeds Suffix::MAX");
+
         Suffix {
             suffix: HeaderSuffix::Application(value),
         }
@@ -46,13 +66,32 @@ impl Suffix {
 
     /// Obtain this suffix's `u64` value based on whether this represents an
     /// internal or application [`Header`] suffix.
-    pub(crate) fn get(&self) -> u64 {
+    pub(crate) const fn get(&self) -> u64 {
         match self.suffix {
             HeaderSuffix::Internal(i) => i as u64,
             HeaderSuffix::Application(i) => (i + NUM_INTERNAL_SUFFIXES as usize) as u64,
         }
     }
 
+    /// Returns `true` if no two of the provided suffixes are equal.
+    ///
+    /// This is a `const fn` so that it can be evaluated at compile time; see
+    /// [`define_headers!`].
+    pub const fn all_distinct(suffixes: &[Suffix]) -> bool {
+        let mut i = 0;
+        while i < suffixes.len() {
+            let mut j = i + 1;
+            while j < suffixes.len() {
+                if suffixes[i].get() == suffixes[j].get() {
+                    return false;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        true
+    }
+
     /// Creates a new internal-defined [`Header`] suffix. Only called internally
     /// by Ragu.
     pub(crate) const fn internal(value: usize) -> Self {
@@ -75,10 +114,46 @@ fn test_suffix_map() {
     assert_eq!(Suffix::new(1).get(), 3);
 }
 
+#[test]
+fn test_suffix_max() {
+    assert_eq!(Suffix::new(Suffix::MAX).get(), usize::MAX as u64);
+    assert!(Suffix::all_distinct(&[
+        Suffix::internal(0),
+        Suffix::internal(1),
+        Suffix::new(0),
+        Suffix::new(Suffix::MAX)
+    ]));
+}
+
+#[test]
+#[should_panic(expected = "header suffix exceeds Suffix::MAX")]
+fn test_suffix_above_max() {
+    let _ = Suffix::new(Suffix::MAX + 1);
+}
+
+#[test]
+fn test_suffix_all_distinct() {
+    assert!(Suffix::all_distinct(&[]));
+    assert!(Suffix::all_distinct(&[Suffix::new(0), Suffix::new(1)]));
+    assert!(Suffix::all_distinct(&[Suffix::internal(1), Suffix::new(0)]));
+    assert!(!Suffix::all_distinct(&[
+        Suffix::new(0),
+        Suffix::new(1),
+        Suffix::new(0)
+    ]));
+}
+
 /// Headers are succinct representations of data, essentially used as public
 /// inputs to recursive proofs in order to represent the current state of the
 /// computation.
 ///
+/// Anything a [`Pcd`](crate::Pcd) should attest to about its history, such as
+/// the depth of the tree of steps that produced it, belongs in its header and
+/// must be enforced by the steps that output it. Ragu does not track this
+/// itself: [`Application::rerandomize`](crate::Application::rerandomize) and
+/// seeding internally perform additional fuses which are not part of the
+/// application's computation.
+///
 /// See the [Writing Circuits](https://tachyon.z.cash/ragu/guide/writing_circuits.html)
 /// guide for usage patterns and examples.
 pub trait Header<F: Field>: Send + Sync + Any {
@@ -99,6 +174,71 @@ pub trait Header<F: Field>: Send + Sync + Any {
     ) -> Result<Bound<'dr, D, Self::Output>>;
 }
 
+/// Asserts at compile time that a set of [`Header`] types have distinct
+/// [`SUFFIX`](Header::SUFFIX) values.
+///
+/// Steps whose headers share a suffix are otherwise only rejected when they
+/// are registered with an [`ApplicationBuilder`](crate::ApplicationBuilder).
+/// The macro is provided the field type `F` and the header types, separated by
+/// a semicolon:
+///
+/// ```rust
+/// # use ragu_core::{Result, drivers::{Driver, DriverValue}, gadgets::{Bound, Kind}};
+/// # use ragu_pasta::Fp;
+/// # use ragu_pcd::header::{Header, Suffix, define_headers};
+/// # use ragu_primitives::Element;
+/// # macro_rules! header {
+/// #     ($name:ident, $suffix:expr) => {
+/// #         struct $name;
+/// #         impl Header<Fp> for $name {
+/// #             const SUFFIX: Suffix = Suffix::new($suffix);
+/// #             type Data = Fp;
+/// #             type Output = Kind![Fp; Element<'_, _>];
+/// #             fn encode<'dr, D: Driver<'dr, F = Fp>>(
+/// #                 dr: &mut D,
+/// #                 witness: DriverValue<D, Self::Data>,
+/// #             ) -> Result<Bound<'dr, D, Self::Output>> {
+/// #                 Element::alloc(dr, witness)
+/// #             }
+/// #         }
+/// #     };
+/// # }
+/// header!(Leaf, 0);
+/// header!(Node, 1);
+///
+/// define_headers!(Fp; Leaf, Node);
+/// ```
+///
+/// Duplicate suffixes fail to compile:
+///
+/// ```rust,compile_fail
+/// # use ragu_core::{Result, drivers::{Driver, DriverValue}, gadgets::{Bound, Kind}};
+/// # use ragu_pasta::Fp;
+/// # use ragu_pcd::header::{Header, Suffix, define_headers};
+/// # use ragu_primitives::Element;
+/// # macro_rules! header {
+/// #     ($name:ident, $suffix:expr) => {
+/// #         struct $name;
+/// #         impl Header<Fp> for $name {
+/// #             const SUFFIX: Suffix = Suffix::new($suffix);
+/// #             type Data = Fp;
+/// #             type Output = Kind![Fp; Element<'_, _>];
+/// #             fn encode<'dr, D: Driver<'dr, F = Fp>>(
+/// #                 dr: &mut D,
+/// #                 witness: DriverValue<D, Self::Data>,
+/// #             ) -> Result<Bound<'dr, D, Self::Output>> {
+/// #                 Element::alloc(dr, witness)
+/// #             }
+/// #         }
+/// #     };
+/// # }
+/// header!(Leaf, 0);
+/// header!(Node, 0);
+///
+/// define_headers!(Fp; Leaf, Node);
+/// ```
+pub use ragu_macros::define_headers;
+
 /// Trivial header that encodes no data.
 impl<F: Field> Header<F> for () {
     const SUFFIX: Suffix = Suffix::internal(1);
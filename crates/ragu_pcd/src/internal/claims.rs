@@ -91,6 +91,17 @@ where
         }
     }
 
+    /// Returns the accumulated `(a, b)` polynomial pair of each claim, in the
+    /// order in which the claims were pushed.
+    pub fn claims(
+        &self,
+    ) -> impl Iterator<Item = (&sparse::Polynomial<F, R>, &sparse::Polynomial<F, R>)> {
+        self.a
+            .iter()
+            .map(Borrow::borrow)
+            .zip(self.b.iter().map(|b| &**b))
+    }
+
     /// Push a circuit claim. Computes `b` from `a.borrow()` (the polynomial).
     pub fn circuit_impl(&mut self, circuit_id: CircuitIndex, a: A) {
         let rx = a.borrow();
@@ -34,7 +34,7 @@ use ragu_core::{
 };
 use ragu_primitives::{
     Element, Endoscalar, Point,
-    vec::{FixedVec, Len},
+    vec::{FixedVec, Len, StaticLen},
 };
 
 /// Number of endoscaling operations per step. This is how many we can fit into
@@ -46,11 +46,17 @@ pub struct InputsLen<const NUM_POINTS: usize>;
 
 impl<const NUM_POINTS: usize> Len for InputsLen<NUM_POINTS> {
     fn len() -> usize {
-        const { assert!(NUM_POINTS > 0) };
-        NUM_POINTS - 1
+        Self::LEN
     }
 }
 
+impl<const NUM_POINTS: usize> StaticLen for InputsLen<NUM_POINTS> {
+    const LEN: usize = {
+        assert!(NUM_POINTS > 0);
+        NUM_POINTS - 1
+    };
+}
+
 /// Compute the number of endoscaling steps for `num_points` curve points.
 ///
 /// This is `ceil((num_points - 1) / ENDOSCALINGS_PER_STEP).max(1)`.
@@ -66,10 +72,14 @@ pub struct NumStepsLen<const NUM_POINTS: usize>;
 
 impl<const NUM_POINTS: usize> Len for NumStepsLen<NUM_POINTS> {
     fn len() -> usize {
-        num_steps(NUM_POINTS)
+        Self::LEN
     }
 }
 
+impl<const NUM_POINTS: usize> StaticLen for NumStepsLen<NUM_POINTS> {
+    const LEN: usize = num_steps(NUM_POINTS);
+}
+
 /// Stage for allocating the endoscalar witness.
 #[derive(Default)]
 pub struct EndoscalarStage;
@@ -11,7 +11,7 @@ use ragu_core::{Result, drivers::Driver};
 use ragu_primitives::{
     Element,
     io::Buffer,
-    vec::{CollectFixed, ConstLen, FixedVec, Len},
+    vec::{CollectFixed, ConstLen, FixedVec, Len, StaticLen},
 };
 
 /// The two operations a Horner-style fold needs: scale all components, then
@@ -73,6 +73,10 @@ impl<L: Len> Len for NumErrorTerms<L> {
     }
 }
 
+impl<L: StaticLen> StaticLen for NumErrorTerms<L> {
+    const LEN: usize = L::LEN * L::LEN - L::LEN;
+}
+
 /// Returns an iterator over off-diagonal (i, j) pairs where i != j.
 fn off_diagonal_pairs(n: usize) -> impl Iterator<Item = (usize, usize)> {
     (0..n).flat_map(move |i| (0..n).filter_map(move |j| (i != j).then_some((i, j))))
@@ -288,11 +292,12 @@ mod tests {
     use alloc::{vec, vec::Vec};
 
     use ff::Field;
+    use proptest::prelude::*;
     use ragu_circuits::polynomials::{TestRank, sparse};
     use ragu_core::{drivers::emulator::Emulator, maybe::Maybe};
     use ragu_pasta::Fp;
     use ragu_primitives::{Simulator, vec::CollectFixed};
-    use rand::SeedableRng;
+    use rand::{SeedableRng, rngs::StdRng};
 
     use super::*;
     use crate::internal::native::RevdotParameters;
@@ -945,4 +950,71 @@ mod tests {
 
         Ok(())
     }
+
+    /// Naive reference for [`compute_errors_impl`]: for each of the
+    /// `num_groups` groups, the revdot product of every ordered pair $(i, j)$
+    /// with $i \neq j$, in row-major order, treating absent polynomials as
+    /// zero.
+    fn reference_error_terms(
+        a: &[sparse::Polynomial<Fp, TestRank>],
+        b: &[sparse::Polynomial<Fp, TestRank>],
+        num_groups: usize,
+        group_size: usize,
+    ) -> Vec<Vec<Fp>> {
+        let zero = sparse::Polynomial::new();
+
+        let mut groups = Vec::new();
+        for group in 0..num_groups {
+            let lhs = |i: usize| a.get(group * group_size + i).unwrap_or(&zero);
+            let rhs = |j: usize| b.get(group * group_size + j).unwrap_or(&zero);
+
+            let mut terms = Vec::new();
+            for i in 0..group_size {
+                for j in 0..group_size {
+                    if i != j {
+                        terms.push(lhs(i).revdot(rhs(j)));
+                    }
+                }
+            }
+            groups.push(terms);
+        }
+        groups
+    }
+
+    fn random_claims(seed: u64, count: usize) -> Vec<sparse::Polynomial<Fp, TestRank>> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..count)
+            .map(|_| sparse::Polynomial::random(&mut rng))
+            .collect()
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn inner_error_terms_match_reference(seed in any::<u64>(), count in 0usize..=12) {
+            type P = TestParams<3, 4>;
+
+            let a = random_claims(seed, count);
+            let b = random_claims(seed.wrapping_add(1), count);
+
+            let errors = inner_error_terms::<Fp, TestRank, P>(&a, &b);
+            let expected = reference_error_terms(&a, &b, 3, 4);
+            for (group, expected) in errors.iter().zip(&expected) {
+                prop_assert_eq!(&group[..], &expected[..]);
+            }
+        }
+
+        #[test]
+        fn outer_error_terms_match_reference(seed in any::<u64>(), count in 0usize..=5) {
+            type P = TestParams<5, 2>;
+
+            let a = random_claims(seed, count);
+            let b = random_claims(seed.wrapping_add(1), count);
+
+            let errors = outer_error_terms::<Fp, TestRank, P>(&a, &b);
+            let expected = reference_error_terms(&a, &b, 1, 5);
+            prop_assert_eq!(&errors[..], &expected[0][..]);
+        }
+    }
 }
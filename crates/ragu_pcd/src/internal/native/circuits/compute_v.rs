@@ -43,7 +43,7 @@
 //! [$\mu'$]: unified::Output::mu_prime
 //! [$\nu'$]: unified::Output::nu_prime
 
-use alloc::{vec, vec::Vec};
+use alloc::{borrow::Cow, vec, vec::Vec};
 use core::marker::PhantomData;
 
 use ff::Field;
@@ -84,13 +84,29 @@ use crate::internal::{
 ///
 /// [module-level documentation]: self
 /// [$v$]: unified::Output::v
-pub struct Circuit<C: Cycle, R, const HEADER_SIZE: usize> {
+pub struct Circuit<'a, C: Cycle, R, const HEADER_SIZE: usize> {
+    /// The $\omega^j$ evaluation point of each internal circuit.
+    omega_j: Cow<'a, InternalCircuitValues<C::CircuitField>>,
     _marker: PhantomData<(C, R)>,
 }
 
-impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Circuit<C, R, HEADER_SIZE> {
-    pub fn new() -> MultiStage<C::CircuitField, R, Self> {
+impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Circuit<'static, C, R, HEADER_SIZE> {
+    pub fn new() -> Result<MultiStage<C::CircuitField, R, Self>> {
+        Ok(MultiStage::new(Circuit {
+            omega_j: Cow::Owned(InternalCircuitValues::omega_j()?),
+            _marker: PhantomData,
+        }))
+    }
+}
+
+impl<'a, C: Cycle, R: Rank, const HEADER_SIZE: usize> Circuit<'a, C, R, HEADER_SIZE> {
+    /// Like [`new`](Circuit::new), but borrows a precomputed table of internal
+    /// circuit $\omega^j$ values (see [`InternalCircuitValues::omega_j`]).
+    pub fn with_omega_j(
+        omega_j: &'a InternalCircuitValues<C::CircuitField>,
+    ) -> MultiStage<C::CircuitField, R, Self> {
         MultiStage::new(Circuit {
+            omega_j: Cow::Borrowed(omega_j),
             _marker: PhantomData,
         })
     }
@@ -116,7 +132,7 @@ pub struct Witness<'a, C: Cycle, R: Rank, const HEADER_SIZE: usize> {
 }
 
 impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> MultiStageCircuit<C::CircuitField, R>
-    for Circuit<C, R, HEADER_SIZE>
+    for Circuit<'_, C, R, HEADER_SIZE>
 {
     type Last = native_eval::Stage<C, R, HEADER_SIZE>;
 
@@ -204,12 +220,14 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> MultiStageCircuit<C::CircuitFi
             let fu = {
                 let alpha = unified_output.alpha.read(dr)?;
                 let u = unified_output.u.read(dr)?;
-                let denominators = Denominators::new(dr, &u, &w, &x, &y, &z, &preamble)?;
+                let denominators =
+                    Denominators::new(dr, &u, &w, &x, &y, &z, &preamble, &self.omega_j)?;
                 let mut horner = Horner::new(&alpha);
                 for (pu, v, denominator) in poly_queries(
                     &eval,
                     &query,
-                    &preamble,
+                    &preamble.left.unified.v,
+                    &preamble.right.unified.v,
                     &denominators,
                     &computed_ax,
                     &computed_bx,
@@ -284,6 +302,7 @@ impl<'dr, D: Driver<'dr>> Denominators<'dr, D> {
         y: &Element<'dr, D>,
         z: &Element<'dr, D>,
         preamble: &native_preamble::Output<'dr, D, C, HEADER_SIZE>,
+        omega_j: &InternalCircuitValues<D::F>,
     ) -> Result<Self>
     where
         D::F: ff::PrimeField,
@@ -306,7 +325,7 @@ impl<'dr, D: Driver<'dr>> Denominators<'dr, D> {
         let challenges_xz = inverter.add(dr, &xz)?;
 
         let circuit_indices =
-            InternalCircuitValues::try_from_fn(|id| inverter.add_circuit(dr, id))?;
+            InternalCircuitValues::try_from_fn(|id| inverter.add_constant(dr, *omega_j.get(id)))?;
 
         let inverted = inverter.invert(dr)?;
 
@@ -436,7 +455,7 @@ impl<'a, 'dr, D: Driver<'dr>> Processor<&'a Element<'dr, D>, &'a Element<'dr, D>
         &mut self,
         id: InternalCircuitIndex,
         rxs: impl Iterator<Item = &'a Element<'dr, D>>,
-    ) {
+    ) -> Result<()> {
         let sy = self.fixed_registry.get(id);
 
         let mut sum = Element::zero(self.dr);
@@ -450,6 +469,7 @@ impl<'a, 'dr, D: Driver<'dr>> Processor<&'a Element<'dr, D>, &'a Element<'dr, D>
 
         // b(x) = rx(xz) + s_y + t(xz)
         self.bx.push(sum.add(self.dr, sy).add(self.dr, self.txz));
+        Ok(())
     }
 
     fn grouped_bonding_claim(
@@ -532,6 +552,9 @@ fn compute_axbx<'dr, D: Driver<'dr>, P: Parameters>(
 /// - $v = p(x_i)$ is the prover's claimed evaluation (from query stage)
 /// - $(u - x_i)^{-1}$ is the precomputed inverse denominator
 ///
+/// The child proofs' own $p(u) = v$ checks use `left_v` and `right_v`, the
+/// claimed [$v$] values taken from the preamble stage.
+///
 /// ## Query Categories
 ///
 /// The queries are organized into groups:
@@ -556,18 +579,19 @@ fn compute_axbx<'dr, D: Driver<'dr>, P: Parameters>(
 /// [`compute_f`]: crate::Application::compute_f
 /// [$\alpha$]: unified::Output::alpha
 #[rustfmt::skip]
-fn poly_queries<'a, 'dr, D: Driver<'dr>, C: Cycle<CircuitField = D::F>, const HEADER_SIZE: usize>(
+fn poly_queries<'a, 'dr, D: Driver<'dr>>(
     eval: &'a native_eval::Output<'dr, D>,
     query: &'a native_query::Output<'dr, D>,
-    preamble: &'a native_preamble::Output<'dr, D, C, HEADER_SIZE>,
+    left_v: &'a Element<'dr, D>,
+    right_v: &'a Element<'dr, D>,
     d: &'a Denominators<'dr, D>,
     computed_ax: &'a Element<'dr, D>,
     computed_bx: &'a Element<'dr, D>,
 ) -> impl Iterator<Item = (&'a Element<'dr, D>, &'a Element<'dr, D>, &'a Element<'dr, D>)> {
     [
         // Check p(u) = v for each child proof.
-        (&eval.left.p_poly,        &preamble.left.unified.v,                     &d.left.u),
-        (&eval.right.p_poly,       &preamble.right.unified.v,                    &d.right.u),
+        (&eval.left.p_poly,        left_v,                                       &d.left.u),
+        (&eval.right.p_poly,       right_v,                                      &d.right.u),
         // m(W, x_i, y_i) -> m(w, x_i, Y)
         (&eval.left.registry_xy_poly,  &query.left.child_registry_xy_at_current_w,       &d.challenges.w),
         (&eval.right.registry_xy_poly, &query.right.child_registry_xy_at_current_w,      &d.challenges.w),
@@ -670,15 +694,6 @@ impl<'dr, D: Driver<'dr, F: ff::PrimeField>> Inverter<'dr, D> {
         self.add(dr, &constant)
     }
 
-    /// Adds an internal circuit's $\omega^j$ value to subtract from the base.
-    ///
-    /// This is a convenience method for adding the FFT domain element
-    /// corresponding to an internal circuit's index. The $\omega^j$ value is
-    /// computed from `circuit.circuit_index().omega_j()` at compile time.
-    fn add_circuit(&mut self, dr: &mut D, circuit: InternalCircuitIndex) -> Result<usize> {
-        self.add_constant(dr, circuit.circuit_index().omega_j())
-    }
-
     /// Performs batch inversion on all accumulated differences.
     ///
     /// Consumes the inverter and returns a vector of inverted [`Element`]s.
@@ -710,3 +725,328 @@ impl<'dr, D: Driver<'dr, F: ff::PrimeField>> Inverter<'dr, D> {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String, vec::Vec};
+
+    use ragu_circuits::staging::Stage;
+    use ragu_core::{
+        drivers::emulator::{Emulator, Wireless},
+        maybe::Always,
+    };
+    use ragu_pasta::{Fp, Pasta};
+
+    use super::*;
+    use crate::{
+        ApplicationBuilder,
+        internal::{
+            native::RxValues,
+            tests::{HEADER_SIZE, R},
+        },
+    };
+
+    #[test]
+    fn denominators_match_with_cached_omega_j() -> Result<()> {
+        let app = ApplicationBuilder::<Pasta, R, HEADER_SIZE>::new().finalize(Pasta::baked())?;
+        let proof = app.trivial();
+        let header = [Fp::ZERO; HEADER_SIZE];
+        let witness = native_preamble::Witness::new(&proof, &proof, &header, &header)?;
+
+        Emulator::emulate_wireless(&witness, |dr, witness| {
+            let preamble =
+                native_preamble::Stage::<Pasta, R, HEADER_SIZE>::default().witness(dr, witness)?;
+            let [u, w, x, y, z] =
+                core::array::from_fn(|_| Element::constant(dr, Fp::random(&mut rand::rng())));
+
+            let uncached = Denominators::new::<Pasta, HEADER_SIZE>(
+                dr,
+                &u,
+                &w,
+                &x,
+                &y,
+                &z,
+                &preamble,
+                &InternalCircuitValues::omega_j()?,
+            )?;
+            let cached = Denominators::new::<Pasta, HEADER_SIZE>(
+                dr,
+                &u,
+                &w,
+                &x,
+                &y,
+                &z,
+                &preamble,
+                &app.internal_omega_j,
+            )?;
+
+            for &id in InternalCircuitIndex::ALL {
+                assert_eq!(
+                    *cached.internal.get(id).value().take(),
+                    *uncached.internal.get(id).value().take(),
+                    "{id:?}: denominators differ"
+                );
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Which proof (or internal circuit) a labelled query operand belongs to.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Tag {
+        Left,
+        Right,
+        Internal(InternalCircuitIndex),
+    }
+
+    struct Label {
+        name: String,
+        tag: Option<Tag>,
+    }
+
+    /// Structural regression guard for [`poly_queries`].
+    ///
+    /// Each row is given as the labels of its `(p(u), v, denominator)`
+    /// operands. Checks that there are exactly `expected_rows` rows, that
+    /// every denominator in `denominators` is used, that no row is
+    /// duplicated, and that the operands of each row agree on which proof (or
+    /// internal circuit) they belong to.
+    fn check_poly_queries(
+        rows: &[[&Label; 3]],
+        denominators: &[&Label],
+        expected_rows: usize,
+    ) -> core::result::Result<(), String> {
+        if rows.len() != expected_rows {
+            return Err(format!(
+                "expected {expected_rows} query rows, got {}",
+                rows.len()
+            ));
+        }
+
+        for d in denominators {
+            if !rows.iter().any(|[_, _, denom]| denom.name == d.name) {
+                return Err(format!("denominator {} is never used", d.name));
+            }
+        }
+
+        for (i, row) in rows.iter().enumerate() {
+            let [pu, v, denom] = row;
+            if rows[..i]
+                .iter()
+                .any(|prev| (0..3).all(|j| prev[j].name == row[j].name))
+            {
+                return Err(format!(
+                    "duplicate query row ({}, {}, {})",
+                    pu.name, v.name, denom.name
+                ));
+            }
+
+            let mut tags = row.iter().filter_map(|label| label.tag);
+            if let Some(first) = tags.next()
+                && tags.any(|tag| tag != first)
+            {
+                return Err(format!(
+                    "query row ({}, {}, {}) mixes operands of different proofs",
+                    pu.name, v.name, denom.name
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn eval_child<'dr, D: Driver<'dr>>(dr: &mut D) -> native_eval::ChildEvaluations<'dr, D> {
+        native_eval::ChildEvaluations {
+            rx: RxValues::from_fn(|_| Element::zero(dr)),
+            a_poly: Element::zero(dr),
+            b_poly: Element::zero(dr),
+            registry_xy_poly: Element::zero(dr),
+            p_poly: Element::zero(dr),
+        }
+    }
+
+    fn query_child<'dr, D: Driver<'dr>>(dr: &mut D) -> ChildEvaluations<'dr, D> {
+        ChildEvaluations {
+            rx: RxValues::from_fn(|_| Element::zero(dr)),
+            a_poly_at_xz: Element::zero(dr),
+            b_poly_at_x: Element::zero(dr),
+            child_registry_xy_at_current_w: Element::zero(dr),
+            current_registry_xy_at_child_circuit_id: Element::zero(dr),
+            current_registry_wy_at_child_x: Element::zero(dr),
+        }
+    }
+
+    fn child_denominators<'dr, D: Driver<'dr>>(dr: &mut D) -> ChildDenominators<'dr, D> {
+        ChildDenominators {
+            u: Element::zero(dr),
+            y: Element::zero(dr),
+            x: Element::zero(dr),
+            circuit_id: Element::zero(dr),
+        }
+    }
+
+    fn lookup<'l, 'dr, D: Driver<'dr>>(
+        labels: &'l [(&Element<'dr, D>, Label)],
+        element: &Element<'dr, D>,
+    ) -> &'l Label {
+        &labels
+            .iter()
+            .find(|(candidate, _)| core::ptr::eq(*candidate, element))
+            .expect("poly_queries referenced an unlabelled element")
+            .1
+    }
+
+    #[test]
+    fn poly_queries_structure() {
+        let dr = &mut Emulator::<Wireless<Always<()>, Fp>>::execute();
+
+        let eval = native_eval::Output {
+            left: eval_child(dr),
+            right: eval_child(dr),
+            registry_wx0: Element::zero(dr),
+            registry_wx1: Element::zero(dr),
+            registry_wy: Element::zero(dr),
+            a_poly: Element::zero(dr),
+            b_poly: Element::zero(dr),
+            registry_xy: Element::zero(dr),
+        };
+        let query = native_query::Output {
+            fixed_registry: InternalCircuitValues::from_fn(|_| Element::zero(dr)),
+            registry_wxy: Element::zero(dr),
+            left: query_child(dr),
+            right: query_child(dr),
+        };
+        let d = Denominators {
+            left: child_denominators(dr),
+            right: child_denominators(dr),
+            challenges: ChallengeDenominators {
+                w: Element::zero(dr),
+                x: Element::zero(dr),
+                y: Element::zero(dr),
+                xz: Element::zero(dr),
+            },
+            internal: InternalCircuitValues::from_fn(|_| Element::zero(dr)),
+        };
+        let left_v = Element::zero(dr);
+        let right_v = Element::zero(dr);
+        let computed_ax = Element::zero(dr);
+        let computed_bx = Element::zero(dr);
+
+        // Label every operand that `poly_queries` may reference.
+        let mut labels = Vec::new();
+        let mut label = |element, name: String, tag| labels.push((element, Label { name, tag }));
+        for (side, tag, eval, query, d, v) in [
+            ("left", Tag::Left, &eval.left, &query.left, &d.left, &left_v),
+            (
+                "right",
+                Tag::Right,
+                &eval.right,
+                &query.right,
+                &d.right,
+                &right_v,
+            ),
+        ] {
+            let tag = Some(tag);
+            for &id in RxIndex::ALL {
+                label(eval.rx.get(id), format!("eval.{side}.rx[{id:?}]"), tag);
+                label(query.rx.get(id), format!("query.{side}.rx[{id:?}]"), tag);
+            }
+            label(&eval.a_poly, format!("eval.{side}.a_poly"), tag);
+            label(&eval.b_poly, format!("eval.{side}.b_poly"), tag);
+            label(
+                &eval.registry_xy_poly,
+                format!("eval.{side}.registry_xy_poly"),
+                tag,
+            );
+            label(&eval.p_poly, format!("eval.{side}.p_poly"), tag);
+            label(
+                &query.a_poly_at_xz,
+                format!("query.{side}.a_poly_at_xz"),
+                tag,
+            );
+            label(&query.b_poly_at_x, format!("query.{side}.b_poly_at_x"), tag);
+            label(
+                &query.child_registry_xy_at_current_w,
+                format!("query.{side}.child_registry_xy_at_current_w"),
+                tag,
+            );
+            label(
+                &query.current_registry_xy_at_child_circuit_id,
+                format!("query.{side}.current_registry_xy_at_child_circuit_id"),
+                tag,
+            );
+            label(
+                &query.current_registry_wy_at_child_x,
+                format!("query.{side}.current_registry_wy_at_child_x"),
+                tag,
+            );
+            label(&d.u, format!("d.{side}.u"), tag);
+            label(&d.y, format!("d.{side}.y"), tag);
+            label(&d.x, format!("d.{side}.x"), tag);
+            label(&d.circuit_id, format!("d.{side}.circuit_id"), tag);
+            label(v, format!("preamble.{side}.unified.v"), tag);
+        }
+        for &id in InternalCircuitIndex::ALL {
+            let tag = Some(Tag::Internal(id));
+            label(
+                query.fixed_registry.get(id),
+                format!("query.fixed_registry[{id:?}]"),
+                tag,
+            );
+            label(d.internal.get(id), format!("d.internal[{id:?}]"), tag);
+        }
+        label(&eval.registry_wx0, "eval.registry_wx0".into(), None);
+        label(&eval.registry_wx1, "eval.registry_wx1".into(), None);
+        label(&eval.registry_wy, "eval.registry_wy".into(), None);
+        label(&eval.a_poly, "eval.a_poly".into(), None);
+        label(&eval.b_poly, "eval.b_poly".into(), None);
+        label(&eval.registry_xy, "eval.registry_xy".into(), None);
+        label(&query.registry_wxy, "query.registry_wxy".into(), None);
+        label(&d.challenges.w, "d.challenges.w".into(), None);
+        label(&d.challenges.x, "d.challenges.x".into(), None);
+        label(&d.challenges.y, "d.challenges.y".into(), None);
+        label(&d.challenges.xz, "d.challenges.xz".into(), None);
+        label(&computed_ax, "computed_ax".into(), None);
+        label(&computed_bx, "computed_bx".into(), None);
+
+        let rows: Vec<[&Label; 3]> = poly_queries(
+            &eval,
+            &query,
+            &left_v,
+            &right_v,
+            &d,
+            &computed_ax,
+            &computed_bx,
+        )
+        .map(|(pu, v, denom)| {
+            [
+                lookup(&labels, pu),
+                lookup(&labels, v),
+                lookup(&labels, denom),
+            ]
+        })
+        .collect();
+
+        let denominators: Vec<&Label> = labels
+            .iter()
+            .map(|(_, label)| label)
+            .filter(|label| label.name.starts_with("d."))
+            .collect();
+
+        let expected_rows = 20 + 2 * RxIndex::ALL.len() + InternalCircuitIndex::ALL.len();
+        check_poly_queries(&rows, &denominators, expected_rows).unwrap();
+
+        // Deleting any single row must be caught.
+        for i in 0..rows.len() {
+            let mut truncated = rows.clone();
+            truncated.remove(i);
+            assert!(check_poly_queries(&truncated, &denominators, expected_rows).is_err());
+        }
+
+        // So must pairing a child's query with the other child's denominator.
+        let mut swapped = rows.clone();
+        swapped[0][2] = rows[1][2];
+        assert!(check_poly_queries(&swapped, &denominators, expected_rows).is_err());
+    }
+}
@@ -70,7 +70,11 @@ pub trait Processor<Rx, AppCircuitId> {
     ///
     /// The processor looks up registry via [`InternalCircuitIndex`] from its
     /// stored context.
-    fn internal_circuit_claim(&mut self, id: InternalCircuitIndex, rxs: impl Iterator<Item = Rx>);
+    fn internal_circuit_claim(
+        &mut self,
+        id: InternalCircuitIndex,
+        rxs: impl Iterator<Item = Rx>,
+    ) -> Result<()>;
 
     /// Process a claim whose trace is the Horner fold (with $z$) of the given
     /// rxs, with one rx per fold slot ($k(y) = 0$, equivalently
@@ -115,10 +119,11 @@ impl<'m, 'rx, F: PrimeField, R: Rank> Processor<&'rx sparse::Polynomial<F, R>, C
         &mut self,
         id: InternalCircuitIndex,
         rxs: impl Iterator<Item = &'rx sparse::Polynomial<F, R>>,
-    ) {
-        let circuit_id = id.circuit_index();
+    ) -> Result<()> {
+        let circuit_id = id.circuit_index()?;
         let rx = sum_polynomials(rxs);
         self.circuit_impl(circuit_id, rx);
+        Ok(())
     }
 
     fn grouped_bonding_claim(
@@ -126,7 +131,7 @@ impl<'m, 'rx, F: PrimeField, R: Rank> Processor<&'rx sparse::Polynomial<F, R>, C
         id: InternalCircuitIndex,
         groups: impl Iterator<Item = impl Iterator<Item = &'rx sparse::Polynomial<F, R>>>,
     ) -> Result<()> {
-        let circuit_id = id.circuit_index();
+        let circuit_id = id.circuit_index()?;
         let folded = self.fold_bonding_groups(groups);
         self.bonding_impl(circuit_id, folded);
         Ok(())
@@ -171,14 +176,14 @@ where
                     .zip(source.rx(Rx(Preamble)))
                     .zip(source.rx(Rx(OuterError)))
                 {
-                    processor.internal_circuit_claim(id, [h1, pre, en].into_iter());
+                    processor.internal_circuit_claim(id, [h1, pre, en].into_iter())?;
                 }
             }
 
             // hashes_2: Hashes2 + OuterError
             Hashes2Circuit => {
                 for (h2, en) in source.rx(Rx(Hashes2)).zip(source.rx(Rx(OuterError))) {
-                    processor.internal_circuit_claim(id, [h2, en].into_iter());
+                    processor.internal_circuit_claim(id, [h2, en].into_iter())?;
                 }
             }
 
@@ -190,7 +195,7 @@ where
                     .zip(source.rx(Rx(InnerError)))
                     .zip(source.rx(Rx(OuterError)))
                 {
-                    processor.internal_circuit_claim(id, [pc, pre, em, en].into_iter());
+                    processor.internal_circuit_claim(id, [pc, pre, em, en].into_iter())?;
                 }
             }
 
@@ -201,7 +206,7 @@ where
                     .zip(source.rx(Rx(Preamble)))
                     .zip(source.rx(Rx(OuterError)))
                 {
-                    processor.internal_circuit_claim(id, [fc, pre, en].into_iter());
+                    processor.internal_circuit_claim(id, [fc, pre, en].into_iter())?;
                 }
             }
 
@@ -213,7 +218,7 @@ where
                     .zip(source.rx(Rx(Query)))
                     .zip(source.rx(Rx(Eval)))
                 {
-                    processor.internal_circuit_claim(id, [cv, pre, q, e].into_iter());
+                    processor.internal_circuit_claim(id, [cv, pre, q, e].into_iter())?;
                 }
             }
 
@@ -1,12 +1,13 @@
 //! Native curve circuits for recursive verification.
 
+use ff::PrimeField;
 use ragu_arithmetic::Cycle;
 use ragu_circuits::{
     polynomials::Rank,
     registry::{CircuitIndex, RegistryBuilder},
     staging::StageExt,
 };
-use ragu_core::Result;
+use ragu_core::{Error, Result};
 use ragu_primitives::vec::ConstLen;
 
 use crate::{internal::fold_revdot::Parameters, step};
@@ -59,13 +60,24 @@ pub enum InternalCircuitIndex {
     EvalFinalStaged,
 }
 
+/// The maximum number of circuits that can be addressed by a
+/// [`CircuitIndex`].
+pub(crate) const MAX_CIRCUITS: usize = u32::MAX as usize;
+
 /// Compute the total circuit count and log2 domain size from the number of
 /// application-defined steps.
-pub const fn total_circuit_counts(num_application_steps: usize) -> (usize, u32) {
-    let total_circuits =
-        num_application_steps + step::NUM_INTERNAL_STEPS + InternalCircuitIndex::NUM;
+///
+/// Returns [`Error::CircuitIndexOverflow`] if the total number of circuits
+/// cannot be addressed by a [`CircuitIndex`].
+pub fn total_circuit_counts(num_application_steps: usize) -> Result<(usize, u32)> {
+    let total_circuits = num_application_steps
+        .checked_add(step::NUM_INTERNAL_STEPS + InternalCircuitIndex::NUM)
+        .filter(|&total| total <= MAX_CIRCUITS)
+        .ok_or(Error::CircuitIndexOverflow {
+            limit: MAX_CIRCUITS,
+        })?;
     let log2_circuits = total_circuits.next_power_of_two().trailing_zeros();
-    (total_circuits, log2_circuits)
+    Ok((total_circuits, log2_circuits))
 }
 
 impl InternalCircuitIndex {
@@ -103,12 +115,16 @@ impl InternalCircuitIndex {
         slots
     }
 
-    pub fn circuit_index(self) -> CircuitIndex {
-        let pos = Self::ALL
+    /// Convert to a [`CircuitIndex`] for registry lookup.
+    ///
+    /// Returns [`Error::CircuitIndexOverflow`] if `self` is not one of the
+    /// [`NUM`](Self::NUM) internal circuits in [`ALL`](Self::ALL).
+    pub fn circuit_index(self) -> Result<CircuitIndex> {
+        Self::ALL
             .iter()
             .position(|&v| v == self)
-            .expect("every variant appears in ALL");
-        CircuitIndex::from_u32(pos as u32)
+            .map(CircuitIndex::new)
+            .ok_or(Error::CircuitIndexOverflow { limit: Self::NUM })
     }
 }
 
@@ -190,6 +206,17 @@ impl<T> InternalCircuitValues<T> {
     }
 }
 
+impl<F: PrimeField> InternalCircuitValues<F> {
+    /// Returns the $\omega^j$ evaluation point of every internal circuit.
+    ///
+    /// Internal circuit indices are fixed, so this table never changes for a
+    /// given field; [`Application`](crate::Application) computes it once and
+    /// reuses it rather than recomputing each $\omega^j$ per proof.
+    pub fn omega_j() -> Result<Self> {
+        Self::try_from_fn(|id| Ok(id.circuit_index()?.omega_j()))
+    }
+}
+
 /// Enum identifying which rx polynomial component to index within [`RxValues`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RxIndex {
@@ -237,6 +264,25 @@ impl RxIndex {
         assert!(c == Self::NUM);
         slots
     }
+
+    /// Returns the internal circuit that is traced to produce this rx
+    /// polynomial, or `None` for the application step and the stages.
+    pub(crate) const fn internal_circuit(self) -> Option<InternalCircuitIndex> {
+        use InternalCircuitIndex::*;
+        match self {
+            RxIndex::Hashes1 => Some(Hashes1Circuit),
+            RxIndex::Hashes2 => Some(Hashes2Circuit),
+            RxIndex::InnerCollapse => Some(InnerCollapseCircuit),
+            RxIndex::OuterCollapse => Some(OuterCollapseCircuit),
+            RxIndex::ComputeV => Some(ComputeVCircuit),
+            RxIndex::Application
+            | RxIndex::Preamble
+            | RxIndex::InnerError
+            | RxIndex::OuterError
+            | RxIndex::Query
+            | RxIndex::Eval => None,
+        }
+    }
 }
 
 /// Per-rx-component storage indexed by [`RxIndex`].
@@ -326,6 +372,9 @@ pub enum RxComponent {
 ///
 /// Does not register internal steps (rerandomize, trivial); those are
 /// registered by the caller after this function returns.
+///
+/// The circuits registered here must be exactly those traced by the fuse
+/// (see `fuse/_11_circuits.rs`), which is why they are not configurable.
 pub fn register_all<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize>(
     mut registry: RegistryBuilder<'params, C::CircuitField, R>,
     params: &'params C::Params,
@@ -407,7 +456,7 @@ pub fn register_all<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize>(
                     C,
                     R,
                     HEADER_SIZE,
-                >::new())?
+                >::new()?)?
             }
         };
     }
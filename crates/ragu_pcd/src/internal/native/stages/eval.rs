@@ -149,12 +149,18 @@ impl<'dr, D: Driver<'dr>> ChildEvaluations<'dr, D> {
         let rx = RxValues::try_from_fn(|id| {
             Element::alloc(dr, witness.as_ref().map(|w| *w.rx.get(id)))
         })?;
+        let [a_poly, b_poly, registry_xy_poly, p_poly] = Element::alloc_batch(
+            dr,
+            witness
+                .as_ref()
+                .map(|w| [w.a_poly, w.b_poly, w.registry_xy_poly, w.p_poly]),
+        )?;
         Ok(ChildEvaluations {
             rx,
-            a_poly: Element::alloc(dr, witness.as_ref().map(|w| w.a_poly))?,
-            b_poly: Element::alloc(dr, witness.as_ref().map(|w| w.b_poly))?,
-            registry_xy_poly: Element::alloc(dr, witness.as_ref().map(|w| w.registry_xy_poly))?,
-            p_poly: Element::alloc(dr, witness.as_ref().map(|w| w.p_poly))?,
+            a_poly,
+            b_poly,
+            registry_xy_poly,
+            p_poly,
         })
     }
 }
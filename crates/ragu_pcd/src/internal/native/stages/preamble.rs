@@ -74,6 +74,25 @@ pub struct ChildHeaders<'dr, D: Driver<'dr>, const HEADER_SIZE: usize> {
     pub right: HeaderVec<'dr, D, HEADER_SIZE>,
 }
 
+impl<'dr, D: Driver<'dr>, const HEADER_SIZE: usize> ChildHeaders<'dr, D, HEADER_SIZE> {
+    /// Compute k(y) for the application circuit instance that produced these
+    /// headers and `output_header`.
+    ///
+    /// Returns k(y) for `(left, right, output_header)`.
+    pub fn application_ky(
+        &self,
+        dr: &mut D,
+        output_header: &HeaderVec<'dr, D, HEADER_SIZE>,
+        y: &Element<'dr, D>,
+    ) -> Result<Element<'dr, D>> {
+        let mut ky = Horner::new(y);
+        self.left.write(dr, &mut ky)?;
+        self.right.write(dr, &mut ky)?;
+        output_header.write(dr, &mut ky)?;
+        ky.finish_ky(dr)
+    }
+}
+
 /// Processed inputs from a single child proof in the preamble stage.
 #[derive(Gadget, Consistent)]
 pub struct ProofInputs<'dr, D: Driver<'dr>, C: Cycle<CircuitField = D::F>, const HEADER_SIZE: usize>
@@ -130,11 +149,7 @@ impl<'dr, D: Driver<'dr, F = C::CircuitField>, C: Cycle, const HEADER_SIZE: usiz
     ///
     /// Returns `application_ky` = k(y) for `(children.left, children.right, output_header)`.
     pub fn application_ky(&self, dr: &mut D, y: &Element<'dr, D>) -> Result<Element<'dr, D>> {
-        let mut ky = Horner::new(y);
-        self.children.left.write(dr, &mut ky)?;
-        self.children.right.write(dr, &mut ky)?;
-        self.output_header.write(dr, &mut ky)?;
-        ky.finish_ky(dr)
+        self.children.application_ky(dr, &self.output_header, y)
     }
 
     /// Returns true if this child proof is a trivial proof (output header suffix == 1).
@@ -26,7 +26,11 @@ pub trait Processor<Rx> {
     /// rxs ($k(y) = 1$ for [`EndoscalingStep`]).
     ///
     /// [`EndoscalingStep`]: InternalCircuitIndex::EndoscalingStep
-    fn internal_circuit_claim(&mut self, id: InternalCircuitIndex, rxs: impl Iterator<Item = Rx>);
+    fn internal_circuit_claim(
+        &mut self,
+        id: InternalCircuitIndex,
+        rxs: impl Iterator<Item = Rx>,
+    ) -> Result<()>;
 
     /// Process a claim whose trace is the Horner fold (with $z$) of the given
     /// rxs, with one rx per fold slot ($k(y) = 0$).
@@ -58,10 +62,11 @@ impl<'m, 'rx, F: PrimeField, R: Rank> Processor<&'rx sparse::Polynomial<F, R>>
         &mut self,
         id: InternalCircuitIndex,
         rxs: impl Iterator<Item = &'rx sparse::Polynomial<F, R>>,
-    ) {
-        let circuit_id = id.circuit_index();
+    ) -> Result<()> {
+        let circuit_id = id.circuit_index()?;
         let rx = sum_polynomials(rxs);
         self.circuit_impl(circuit_id, rx);
+        Ok(())
     }
 
     fn grouped_bonding_claim(
@@ -69,7 +74,7 @@ impl<'m, 'rx, F: PrimeField, R: Rank> Processor<&'rx sparse::Polynomial<F, R>>
         id: InternalCircuitIndex,
         groups: impl Iterator<Item = impl Iterator<Item = &'rx sparse::Polynomial<F, R>>>,
     ) -> Result<()> {
-        let circuit_id = id.circuit_index();
+        let circuit_id = id.circuit_index()?;
         let folded = self.fold_bonding_groups(groups);
         self.bonding_impl(circuit_id, folded);
         Ok(())
@@ -100,7 +105,7 @@ where
                     .zip(source.rx(RxIndex::EndoscalarStage))
                     .zip(source.rx(RxIndex::PointsStage))
                 {
-                    processor.internal_circuit_claim(id, [step_rx, endo_rx, pts_rx].into_iter());
+                    processor.internal_circuit_claim(id, [step_rx, endo_rx, pts_rx].into_iter())?;
                 }
             }
             EndoscalarStage => {
@@ -9,21 +9,85 @@ use ragu_circuits::{
     registry::{CircuitIndex, RegistryBuilder},
     staging::{MultiStage, StageExt},
 };
-use ragu_core::Result;
+use ragu_core::{Error, Result};
 
-use crate::internal::endoscalar;
+use crate::internal::{endoscalar, native};
+
+/// Number of curve points accumulated during `compute_p` for each child proof.
+///
+/// This is one commitment per [`native::RxIndex`] component, plus the child's
+/// `ab.a`, `ab.b`, `registry_xy` and `p` commitments.
+pub const NUM_CHILD_PROOF_POINTS: usize = native::RxIndex::NUM + 4;
+
+/// Curve points accumulated during `compute_p` from the proof currently being
+/// constructed, in accumulation order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum StagePoint {
+    RegistryWx0,
+    RegistryWx1,
+    RegistryWy,
+    AbA,
+    AbB,
+    RegistryXy,
+}
+
+impl StagePoint {
+    /// Every stage point, in the order `compute_p` accumulates them.
+    pub(crate) const ALL: [StagePoint; 6] = [
+        StagePoint::RegistryWx0,
+        StagePoint::RegistryWx1,
+        StagePoint::RegistryWy,
+        StagePoint::AbA,
+        StagePoint::AbB,
+        StagePoint::RegistryXy,
+    ];
+
+    /// Returns `true` if this point is a full-size commitment to its
+    /// polynomial, rather than derived from known commitments via a small
+    /// MSM (as `ab.a` is).
+    pub(crate) const fn is_committed(self) -> bool {
+        use StagePoint::*;
+        match self {
+            RegistryWx0 | RegistryWx1 | RegistryWy | AbB | RegistryXy => true,
+            AbA => false,
+        }
+    }
+}
+
+/// Number of curve points accumulated during `compute_p` from the proof
+/// currently being constructed; see [`StagePoint`].
+pub const NUM_STAGE_POINTS: usize = StagePoint::ALL.len();
+
+/// Number of [`StagePoint`]s that are full-size commitments; see
+/// [`StagePoint::is_committed`].
+pub(crate) const NUM_COMMITTED_STAGE_POINTS: usize = {
+    let mut count = 0;
+    let mut i = 0;
+    while i < StagePoint::ALL.len() {
+        if StagePoint::ALL[i].is_committed() {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+};
+
+/// Number of child proofs folded by `compute_p`.
+pub const NUM_CHILDREN: usize = 2;
+
+/// Returns the number of curve points accumulated during `compute_p` when
+/// folding `num_children` child proofs, including the base `f.commitment`.
+pub const fn num_endoscaling_points(num_children: usize) -> usize {
+    1 + num_children * NUM_CHILD_PROOF_POINTS + NUM_STAGE_POINTS
+}
 
 /// Number of curve points accumulated during `compute_p` for nested field
 /// endoscaling verification.
 ///
-/// This is the sum of:
-/// - 2 proofs × 15 commitment components = 30
-/// - 6 stage proof components (registry_wx0, registry_wx1, registry_wy, ab.a, ab.b, registry_xy)
-/// - 1 f.commitment (base polynomial)
-///
-/// The endoscaling circuits process these points across
-/// [`NUM_ENDOSCALING_STEPS`] steps.
-pub const NUM_ENDOSCALING_POINTS: usize = 37;
+/// This is derived via [`num_endoscaling_points`] for the
+/// [`NUM_CHILDREN`] child proofs. The endoscaling circuits process these
+/// points across [`NUM_ENDOSCALING_STEPS`] steps.
+pub const NUM_ENDOSCALING_POINTS: usize = num_endoscaling_points(NUM_CHILDREN);
 
 /// Number of endoscaling steps, derived from [`NUM_ENDOSCALING_POINTS`] via
 /// [`endoscalar::num_steps`].
@@ -104,12 +168,16 @@ impl InternalCircuitIndex {
     ///
     /// Circuit indices follow the `RegistryBuilder::finalize()` concatenation
     /// order: internal circuits first, then internal masks.
-    pub fn circuit_index(self) -> CircuitIndex {
-        let pos = Self::ALL
+    ///
+    /// Returns [`Error::CircuitIndexOverflow`] if `self` is not one of the
+    /// [`NUM`](Self::NUM) internal circuits in [`ALL`](Self::ALL), such as an
+    /// [`EndoscalingStep`](Self::EndoscalingStep) past the last step.
+    pub fn circuit_index(self) -> Result<CircuitIndex> {
+        Self::ALL
             .iter()
             .position(|&v| v == self)
-            .expect("every variant appears in ALL");
-        CircuitIndex::new(pos)
+            .map(CircuitIndex::new)
+            .ok_or(Error::CircuitIndexOverflow { limit: Self::NUM })
     }
 }
 
@@ -66,7 +66,7 @@ fn test_internal_circuit_constraint_counts() {
 
     macro_rules! check_constraints {
         ($variant:ident, mul = $mul:expr, lin = $lin:expr) => {{
-            let circuit_index = InternalCircuitIndex::$variant.circuit_index();
+            let circuit_index = InternalCircuitIndex::$variant.circuit_index().unwrap();
             let (actual_gates, actual_constraints) =
                 app.native_registry.constraint_counts(circuit_index);
             assert_eq!(
@@ -143,7 +143,7 @@ fn print_internal_circuit_constraint_counts() {
 
     println!("\n// Copy-paste the following into test_internal_circuit_constraint_counts:");
     for (name, variant) in variants {
-        let circuit_index = variant.circuit_index();
+        let circuit_index = variant.circuit_index().unwrap();
         let (mul, lin) = app.native_registry.constraint_counts(circuit_index);
         println!(
             "        check_constraints!({:<24} mul = {:<4}, lin = {});",
@@ -292,6 +292,21 @@ fn test_internal_circuit_index_all_exhaustive() {
     assert_eq!(collected.as_slice(), InternalCircuitIndex::ALL);
 }
 
+#[test]
+fn test_nested_circuit_index_rejects_unknown_step() {
+    use nested::InternalCircuitIndex;
+
+    assert!(
+        InternalCircuitIndex::EndoscalingStep(0)
+            .circuit_index()
+            .is_ok()
+    );
+    assert!(matches!(
+        InternalCircuitIndex::EndoscalingStep(InternalCircuitIndex::NUM as u32).circuit_index(),
+        Err(ragu_core::Error::CircuitIndexOverflow { .. })
+    ));
+}
+
 #[test]
 fn test_rx_index_all_exhaustive() {
     let mut collected = alloc::vec::Vec::new();
@@ -300,3 +315,22 @@ fn test_rx_index_all_exhaustive() {
     });
     assert_eq!(collected.as_slice(), RxIndex::ALL);
 }
+
+#[test]
+fn test_internal_omega_j_cache() {
+    use ragu_pasta::Fp;
+
+    let pasta = Pasta::baked();
+
+    let app = ApplicationBuilder::<Pasta, R, HEADER_SIZE>::new()
+        .register_dummy_circuits(NUM_APP_STEPS)
+        .unwrap()
+        .finalize(pasta)
+        .unwrap();
+
+    for &id in InternalCircuitIndex::ALL {
+        let uncached = id.circuit_index().unwrap().omega_j::<Fp>();
+        let cached = *app.internal_omega_j.get(id);
+        assert_eq!(cached, uncached, "{id:?}: cached omega_j differs");
+    }
+}
@@ -37,21 +37,23 @@
 //! domain-separated by protocol tags during construction [`Transcript::new`].
 
 use ff::PrimeField;
-use ragu_arithmetic::PoseidonPermutation;
 use ragu_core::{Result, drivers::Driver};
 use ragu_primitives::{
     Element,
     io::Buffer,
-    poseidon::{SaveError, Sponge, SpongeState},
+    poseidon::{SaveError, Sponge, SpongeHash, SpongeState},
 };
 
 /// Transcript wrapper around Poseidon [`Sponge`] for Fiat-Shamir transforms.
-pub struct Transcript<'dr, D: Driver<'dr>, P: PoseidonPermutation<D::F>> {
+///
+/// Any [`SpongeHash`] can drive the transcript; the protocol uses
+/// [`Cycle::CircuitPoseidon`](ragu_arithmetic::Cycle::CircuitPoseidon).
+pub struct Transcript<'dr, D: Driver<'dr>, P: SpongeHash<D::F>> {
     sponge: Sponge<'dr, D, P>,
     params: &'dr P,
 }
 
-impl<'dr, D: Driver<'dr>, P: PoseidonPermutation<D::F>> Clone for Transcript<'dr, D, P> {
+impl<'dr, D: Driver<'dr>, P: SpongeHash<D::F>> Clone for Transcript<'dr, D, P> {
     fn clone(&self) -> Self {
         Transcript {
             sponge: self.sponge.clone(),
@@ -66,7 +68,7 @@ impl<'dr, D: Driver<'dr>, P: PoseidonPermutation<D::F>> Clone for Transcript<'dr
 /// constraint-checked during multi-circuit protocols.
 pub type TranscriptState<'dr, D, P> = SpongeState<'dr, D, P>;
 
-impl<'dr, D: Driver<'dr>, P: PoseidonPermutation<D::F>> Transcript<'dr, D, P> {
+impl<'dr, D: Driver<'dr>, P: SpongeHash<D::F>> Transcript<'dr, D, P> {
     /// Creates a new transcript with mandatory domain separation.
     ///
     /// The `tag` is absorbed as field elements (length-prefixed, 16 bytes per
@@ -105,6 +107,12 @@ impl<'dr, D: Driver<'dr>, P: PoseidonPermutation<D::F>> Transcript<'dr, D, P> {
         self.sponge.squeeze(dr)
     }
 
+    /// Squeezes `N` field element challenges from the transcript, in the
+    /// same order as `N` sequential calls to [`challenge`](Self::challenge).
+    pub fn challenges<const N: usize>(&mut self, dr: &mut D) -> Result<[Element<'dr, D>; N]> {
+        self.sponge.squeeze_n(dr)
+    }
+
     /// Saves the transcript state (analogous to flush).
     ///
     /// This consumes the transcript and applies a permutation to transition
@@ -142,13 +150,13 @@ impl<'dr, D: Driver<'dr>, P: PoseidonPermutation<D::F>> Transcript<'dr, D, P> {
 /// prevents the caller from accidentally absorbing (which would silently discard
 /// those values). Call [`into_transcript`][Self::into_transcript] to transition
 /// back to a full [`Transcript`] that supports absorbing.
-pub struct ResumedTranscript<'dr, D: Driver<'dr>, P: PoseidonPermutation<D::F>> {
+pub struct ResumedTranscript<'dr, D: Driver<'dr>, P: SpongeHash<D::F>> {
     sponge: Sponge<'dr, D, P>,
     params: &'dr P,
     squeezed: bool,
 }
 
-impl<'dr, D: Driver<'dr>, P: PoseidonPermutation<D::F>> ResumedTranscript<'dr, D, P> {
+impl<'dr, D: Driver<'dr>, P: SpongeHash<D::F>> ResumedTranscript<'dr, D, P> {
     /// Squeezes a single field element challenge.
     pub fn challenge(&mut self, dr: &mut D) -> Result<Element<'dr, D>> {
         self.squeezed = true;
@@ -174,7 +182,7 @@ impl<'dr, D: Driver<'dr>, P: PoseidonPermutation<D::F>> ResumedTranscript<'dr, D
     }
 }
 
-impl<'dr, D: Driver<'dr>, P: PoseidonPermutation<D::F>> Buffer<'dr, D> for Transcript<'dr, D, P> {
+impl<'dr, D: Driver<'dr>, P: SpongeHash<D::F>> Buffer<'dr, D> for Transcript<'dr, D, P> {
     fn write(&mut self, dr: &mut D, value: &Element<'dr, D>) -> Result<()> {
         self.sponge.absorb(dr, value)
     }
@@ -217,7 +225,7 @@ mod tests {
         prop_oneof![arb_field().prop_map(Op::Absorb), Just(Op::Squeeze),]
     }
 
-    fn apply_ops<P: PoseidonPermutation<Fp>>(
+    fn apply_ops<P: SpongeHash<Fp>>(
         dr: &mut Sim,
         t: &mut Transcript<'_, Sim, P>,
         ops: &[Op],
@@ -367,4 +375,23 @@ mod tests {
         // should panic because no squeeze was called
         let _ = resumed.into_transcript();
     }
+
+    #[test]
+    fn test_emulator_stats_for_transcript() {
+        use ragu_core::drivers::emulator::{Emulator, Wireless};
+        use ragu_core::maybe::Always;
+
+        let params = Pasta::baked();
+        let mut dr = Emulator::<Wireless<Always<()>, Fp>>::execute();
+
+        let mut t =
+            Transcript::new(&mut dr, Pasta::circuit_poseidon(params), crate::RAGU_TAG).unwrap();
+        let e = Element::constant(&mut dr, Fp::from(42u64));
+        e.write(&mut dr, &mut t).unwrap();
+        let _ = t.challenge(&mut dr).unwrap();
+
+        let stats = dr.stats();
+        assert!(stats.num_gates > 0);
+        assert!(stats.num_enforce_zero > 0);
+    }
 }
@@ -24,6 +24,7 @@ extern crate alloc;
 #[cfg(any(feature = "std", test))]
 extern crate std;
 
+mod blind;
 mod fuse;
 pub mod header;
 mod internal;
@@ -31,18 +32,22 @@ mod proof;
 pub mod step;
 mod verify;
 
-use alloc::collections::BTreeMap;
+use alloc::{collections::BTreeMap, vec::Vec};
 use core::{any::TypeId, cell::OnceCell, marker::PhantomData};
 
+pub use blind::BlindSource;
+use ff::PrimeField;
+pub use fuse::FuseCostEstimate;
 use header::Header;
+#[cfg(feature = "internals")]
+pub use proof::internals;
 pub use proof::{Pcd, Proof};
-use ragu_arithmetic::Cycle;
+use ragu_arithmetic::{Cycle, FixedGenerators};
 use ragu_circuits::{
     polynomials::Rank,
-    registry::{Registry, RegistryBuilder},
+    registry::{CircuitIndex, Registry, RegistryBuilder},
 };
 use ragu_core::{Error, Result};
-use rand::CryptoRng;
 use step::{Step, internal::adapter::Adapter};
 
 /// Domain separation tag for Ragu PCD protocol.
@@ -50,10 +55,17 @@ use step::{Step, internal::adapter::Adapter};
 pub(crate) const RAGU_TAG: &[u8] = b"FIXME";
 
 /// Builder for an [`Application`] for proof-carrying data.
+///
+/// Only application [`Step`]s are registered through the builder. The
+/// internal circuits that verify proofs recursively are registered by
+/// [`finalize`](Self::finalize) and cannot be replaced: the prover traces each
+/// of them directly during [`Application::fuse`], so a different circuit in
+/// the registry would no longer agree with the proofs it produces.
 pub struct ApplicationBuilder<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize> {
     native_registry: RegistryBuilder<'params, C::CircuitField, R>,
     nested_registry: RegistryBuilder<'params, C::ScalarField, R>,
     num_application_steps: usize,
+    output_suffixes: Vec<header::Suffix>,
     header_map: BTreeMap<header::Suffix, TypeId>,
     _marker: PhantomData<[(); HEADER_SIZE]>,
 }
@@ -75,11 +87,40 @@ impl<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize>
             native_registry: RegistryBuilder::new(),
             nested_registry: RegistryBuilder::new(),
             num_application_steps: 0,
+            output_suffixes: Vec::new(),
             header_map: BTreeMap::new(),
             _marker: PhantomData,
         }
     }
 
+    /// Create an empty [`ApplicationBuilder`] with space reserved for
+    /// `num_steps` application-defined [`Step`]s, avoiding reallocation as
+    /// they are registered.
+    pub fn with_capacity(num_steps: usize) -> Self {
+        ApplicationBuilder {
+            native_registry: RegistryBuilder::with_capacity(num_steps),
+            output_suffixes: Vec::with_capacity(num_steps),
+            ..Self::new()
+        }
+    }
+
+    /// Allow routines to nest up to `max_routine_depth` levels deep within
+    /// the circuits registered after this call, in place of
+    /// [`DEFAULT_MAX_ROUTINE_DEPTH`](ragu_circuits::DEFAULT_MAX_ROUTINE_DEPTH).
+    ///
+    /// The limit also applies to the internal circuits registered by
+    /// [`finalize`](Self::finalize), so it must not be lower than their own
+    /// nesting depth.
+    pub fn with_max_routine_depth(mut self, max_routine_depth: usize) -> Self {
+        self.native_registry = self
+            .native_registry
+            .with_max_routine_depth(max_routine_depth);
+        self.nested_registry = self
+            .nested_registry
+            .with_max_routine_depth(max_routine_depth);
+        self
+    }
+
     /// Register a new application-defined [`Step`] in this context. The
     /// provided [`Step`]'s [`INDEX`](Step::INDEX) must be the next sequential
     /// index that has not been inserted yet.
@@ -87,7 +128,8 @@ impl<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize>
     /// # Errors
     ///
     /// Returns an error if the step's index is not the next sequential index,
-    /// or if any of the step's header suffixes conflict with an
+    /// [`Error::ReservedStepIndex`] if it is the index of an internal step, or
+    /// an error if any of the step's header suffixes conflict with an
     /// already-registered header type.
     pub fn register<S: Step<C> + 'params>(mut self, step: S) -> Result<Self> {
         S::INDEX.assert_index(self.num_application_steps)?;
@@ -99,6 +141,8 @@ impl<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize>
         self.native_registry =
             self.native_registry
                 .register_circuit(Adapter::<C, S, R, HEADER_SIZE>::new(step))?;
+        self.output_suffixes
+            .push(<S::Output as Header<C::CircuitField>>::SUFFIX);
         self.num_application_steps += 1;
 
         Ok(self)
@@ -114,6 +158,8 @@ impl<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize>
     pub(crate) fn register_dummy_circuits(mut self, count: usize) -> Result<Self> {
         for _ in 0..count {
             self.native_registry = self.native_registry.register_circuit(())?;
+            self.output_suffixes
+                .push(<() as Header<C::CircuitField>>::SUFFIX);
             self.num_application_steps += 1;
         }
         Ok(self)
@@ -124,18 +170,32 @@ impl<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize>
     ///
     /// # Errors
     ///
-    /// Returns an error if internal circuit registration or registry
-    /// finalization fails.
+    /// Returns [`Error::InsufficientGenerators`] if `params` holds fewer
+    /// generators than [`R::num_coeffs()`](Rank::num_coeffs), or an error if
+    /// internal circuit registration or registry finalization fails.
     pub fn finalize(
         mut self,
         params: &'params C::Params,
     ) -> Result<Application<'params, C, R, HEADER_SIZE>> {
+        // Every polynomial is committed to with one generator per coefficient.
+        for available in [
+            C::host_generators(params).num_generators(),
+            C::nested_generators(params).num_generators(),
+        ] {
+            if available < R::num_coeffs() {
+                return Err(Error::InsufficientGenerators {
+                    required: R::num_coeffs(),
+                    available,
+                });
+            }
+        }
+
         // Build the native registry:
         // 1. Application circuits (already registered)
         // 2. Internal circuits and masks
         // 3. Internal steps
         let (total_circuits, log2_circuits) =
-            internal::native::total_circuit_counts(self.num_application_steps);
+            internal::native::total_circuit_counts(self.num_application_steps)?;
 
         // First, register internal circuits and masks
         self.native_registry = internal::native::register_all::<C, R, HEADER_SIZE>(
@@ -175,7 +235,9 @@ impl<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize>
             nested_registry: self.nested_registry.finalize()?,
             params,
             num_application_steps: self.num_application_steps,
+            output_suffixes: self.output_suffixes,
             seeded_trivial: OnceCell::new(),
+            internal_omega_j: internal::native::InternalCircuitValues::omega_j()?,
             _marker: PhantomData,
         })
     }
@@ -199,13 +261,25 @@ impl<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize>
 }
 
 /// The recursion context that is used to create and verify proof-carrying data.
+///
+/// Proofs are bound to the registry of the [`Application`] that created them:
+/// the registry key is derived from every registered circuit, and the indices
+/// of the internal circuits depend on the number of application steps. A proof
+/// therefore only verifies under an [`Application`] built from the same steps,
+/// and two finalized applications cannot be merged into one that verifies the
+/// proofs of both. Applications that must share a verifier should register all
+/// of their steps with a single [`ApplicationBuilder`].
 pub struct Application<'params, C: Cycle, R: Rank, const HEADER_SIZE: usize> {
     native_registry: Registry<'params, C::CircuitField, R>,
     nested_registry: Registry<'params, C::ScalarField, R>,
     params: &'params C::Params,
     num_application_steps: usize,
+    /// The [`Header::SUFFIX`] of each application step's output header.
+    output_suffixes: Vec<header::Suffix>,
     /// Cached seeded trivial proof for rerandomization.
     seeded_trivial: OnceCell<Proof<C, R>>,
+    /// Cached $\omega^j$ evaluation points of the internal circuits.
+    internal_omega_j: internal::native::InternalCircuitValues<C::CircuitField>,
     _marker: PhantomData<[(); HEADER_SIZE]>,
 }
 
@@ -215,13 +289,38 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
     /// This is the entry point for creating leaf nodes in a PCD tree.
     /// Internally creates minimal trivial proofs with `()` headers and fuses
     /// them with the provided step to produce a valid proof.
-    pub fn seed<'source, RNG: CryptoRng, S: Step<C, Left = (), Right = ()>>(
+    pub fn seed<'source, RNG: BlindSource, S: Step<C, Left = (), Right = ()>>(
+        &self,
+        rng: &mut RNG,
+        step: S,
+        witness: S::Witness<'source>,
+    ) -> Result<(Pcd<C, R, S::Output>, S::Aux<'source>)> {
+        self.seed_from_trivial(rng, step, witness, &self.trivial())
+    }
+
+    /// Like [`seed`](Application::seed), but reuses a trivial proof previously
+    /// returned by [`trivial`](Application::trivial) instead of constructing
+    /// a new one.
+    ///
+    /// The resulting proof will not verify if `trivial` was not obtained from
+    /// this [`Application`].
+    pub fn seed_from_trivial<'source, RNG: BlindSource, S: Step<C, Left = (), Right = ()>>(
         &self,
         rng: &mut RNG,
         step: S,
         witness: S::Witness<'source>,
+        trivial: &Proof<C, R>,
     ) -> Result<(Pcd<C, R, S::Output>, S::Aux<'source>)> {
-        self.fuse(rng, step, witness, self.trivial_pcd(), self.trivial_pcd())
+        self.fuse_parts(rng, step, witness, (trivial, ()), (trivial, ()))
+    }
+
+    /// Returns the trivial proof that [`seed`](Application::seed) fuses with
+    /// the step, for reuse with
+    /// [`seed_from_trivial`](Application::seed_from_trivial).
+    ///
+    /// The trivial proof is deterministic and not a valid proof by itself.
+    pub fn trivial(&self) -> Proof<C, R> {
+        self.trivial_proof()
     }
 
     /// Returns a seeded trivial proof for use in rerandomization.
@@ -232,17 +331,14 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
     ///
     /// The proof is lazily created on first use and cached; subsequent calls
     /// return the same (non-random) proof.
-    fn seeded_trivial_pcd<RNG: CryptoRng>(&self, rng: &mut RNG) -> Pcd<C, R, ()> {
-        self.seeded_trivial
-            .get_or_init(|| {
-                self.seed(rng, step::internal::trivial::Trivial::new(), ())
-                    .expect("seeded trivial seed should not fail")
-                    .0
-                    .into_parts()
-                    .0
-            })
-            .clone()
-            .carry(())
+    fn seeded_trivial_proof<RNG: BlindSource>(&self, rng: &mut RNG) -> &Proof<C, R> {
+        self.seeded_trivial.get_or_init(|| {
+            self.seed(rng, step::internal::trivial::Trivial::new(), ())
+                .expect("seeded trivial seed should not fail")
+                .0
+                .into_parts()
+                .0
+        })
     }
 
     /// Rerandomize proof-carrying data.
@@ -252,29 +348,253 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
     /// is valid for the same [`Header`] but reveals nothing else about the
     /// original proof. As a result, [`Application::verify`] should produce the
     /// same result on the provided `pcd` as it would the output of this method.
-    pub fn rerandomize<RNG: CryptoRng, H: Header<C::CircuitField>>(
+    ///
+    /// There is no cheaper path that only refreshes the randomness of the
+    /// existing proof. Its commitments are not blinded by a separate scalar
+    /// (see [`Proof`]), so new randomness changes the committed polynomials,
+    /// and every challenge of the proof is derived from those commitments.
+    /// Refreshing them would change every subsequent challenge and every
+    /// polynomial that depends on one, which amounts to proving again.
+    ///
+    /// This is also how a single [`Pcd`] is passed up a tree of steps
+    /// unchanged: the internal rerandomization step takes the proof as its
+    /// left child and a trivial proof as its right child, and outputs the
+    /// left child's header. No fuse preserves the circuit of the child
+    /// proof, so the result records the internal step as its circuit, but
+    /// it is accepted anywhere the original `pcd` was.
+    pub fn rerandomize<RNG: BlindSource, H: Header<C::CircuitField>>(
         &self,
         pcd: Pcd<C, R, H>,
         rng: &mut RNG,
     ) -> Result<Pcd<C, R, H>> {
         // Seed a trivial proof for rerandomization.
         // TODO: this is a temporary hack that allows the base case logic to be simple
-        let seeded_trivial = self.seeded_trivial_pcd(rng);
+        let seeded_trivial = self.seeded_trivial_proof(rng);
+        let (proof, data) = pcd.into_parts();
 
         // The Rerandomize step's witness() returns the left input's data as
         // output data, preserving it through rerandomization.
-        self.fuse(
+        self.fuse_parts(
             rng,
             step::internal::rerandomize::Rerandomize::new(),
             (),
-            pcd,
-            seeded_trivial,
+            (&proof, data),
+            (seeded_trivial, ()),
         )
         .map(|(pcd, ())| pcd)
     }
 
+    /// Reinterprets the data carried by `pcd` as `data` for a different
+    /// [`Header`] `H2`, without re-proving.
+    ///
+    /// The proof only attests to the padded `HEADER_SIZE` encoding of its
+    /// header (including the suffix), so it remains valid for `H2` exactly
+    /// when `H2` encodes `data` to the same field elements that `H1` encodes
+    /// the currently carried data to. Both are encoded under an emulator and
+    /// padded to this application's `HEADER_SIZE` before being compared.
+    ///
+    /// See [`Pcd::map_data`] to derive the new data from the old.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, along with the original `pcd`, if either header
+    /// fails to encode or the two encodings differ.
+    #[allow(clippy::result_large_err)]
+    pub fn recarry<H1: Header<C::CircuitField>, H2: Header<C::CircuitField>>(
+        &self,
+        pcd: Pcd<C, R, H1>,
+        data: H2::Data,
+    ) -> core::result::Result<Pcd<C, R, H2>, (Error, Pcd<C, R, H1>)> {
+        let from = step::internal::padded::encode::<_, H1, HEADER_SIZE>(pcd.data().clone());
+        let to = step::internal::padded::encode::<_, H2, HEADER_SIZE>(data.clone());
+        match (from, to) {
+            (Ok(from), Ok(to)) if from == to => Ok(pcd.into_parts().0.carry(data)),
+            (Err(err), _) | (_, Err(err)) => Err((err, pcd)),
+            _ => Err((
+                Error::InvalidWitness(
+                    "header encodings differ; cannot recarry without re-proving".into(),
+                ),
+                pcd,
+            )),
+        }
+    }
+
+    /// Returns a digest identifying the parameters of this [`Application`].
+    ///
+    /// The digest binds the native and nested registry digests (and thus
+    /// every registered circuit), the number of application steps, the
+    /// [`Header::SUFFIX`] of each step's output header, `HEADER_SIZE` and
+    /// the [`Rank`]. Parties can compare digests to confirm that they built
+    /// compatible applications before exchanging proofs.
+    pub fn digest(&self) -> [u8; 32] {
+        let mut hasher = blake2b_simd::Params::new()
+            .hash_length(32)
+            .personal(b"ragu_application")
+            .to_state();
+
+        hasher.update(self.native_registry.digest().to_repr().as_ref());
+        hasher.update(self.nested_registry.digest().to_repr().as_ref());
+        hasher.update(&(self.num_application_steps as u64).to_le_bytes());
+        for suffix in &self.output_suffixes {
+            hasher.update(&suffix.get().to_le_bytes());
+        }
+        hasher.update(&(HEADER_SIZE as u64).to_le_bytes());
+        hasher.update(&R::RANK.to_le_bytes());
+
+        let mut digest = [0u8; 32];
+        digest.copy_from_slice(hasher.finalize().as_bytes());
+        digest
+    }
+
+    /// Returns the [`CircuitIndex`] of the [`Step`] `S` within the native
+    /// [`Registry`].
+    ///
+    /// Application steps are placed after the internal circuits and internal
+    /// steps, so this is the application circuit index recorded by proofs
+    /// that `S` produces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if [`S::INDEX`](Step::INDEX) does not refer to a step
+    /// registered with this [`Application`], or if the step registered at
+    /// that index has a different output [`Header::SUFFIX`] than `S`.
+    pub fn circuit_index_of<S: Step<C>>(&self) -> Result<CircuitIndex> {
+        let index = S::INDEX.circuit_index(self.num_application_steps)?;
+        let registered = usize::from(index)
+            .checked_sub(step::NUM_INTERNAL_STEPS + internal::native::InternalCircuitIndex::NUM)
+            .and_then(|step| self.output_suffixes.get(step));
+
+        match registered {
+            Some(suffix) if *suffix != <S::Output as Header<C::CircuitField>>::SUFFIX => {
+                Err(Error::Initialization(
+                    "step does not match the step registered at its index".into(),
+                ))
+            }
+            _ => Ok(index),
+        }
+    }
+
     /// Returns a reference to the native [`Registry`].
     pub fn native_registry(&self) -> &Registry<'_, C::CircuitField, R> {
         &self.native_registry
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ragu_circuits::polynomials::{ProductionRank, sparse};
+    use ragu_pasta::Pasta;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+
+    #[test]
+    fn seed_from_trivial_matches_seed() -> Result<()> {
+        let pasta = Pasta::baked();
+        let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new().finalize(pasta)?;
+        let trivial = app.trivial();
+
+        let (fresh, ()) = app.seed(
+            &mut StdRng::seed_from_u64(1882),
+            step::internal::trivial::Trivial::new(),
+            (),
+        )?;
+        let (cached, ()) = app.seed_from_trivial(
+            &mut StdRng::seed_from_u64(1882),
+            step::internal::trivial::Trivial::new(),
+            (),
+            &trivial,
+        )?;
+        assert!(fresh.proof() == cached.proof());
+        assert!(app.verify(&cached, StdRng::seed_from_u64(1882))?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn digest_identifies_application() -> Result<()> {
+        let pasta = Pasta::baked();
+        let build = |num_steps| {
+            ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+                .register_dummy_circuits(num_steps)?
+                .finalize(pasta)
+        };
+
+        let app = build(2)?;
+        assert_eq!(app.digest(), build(2)?.digest());
+        assert_ne!(app.digest(), build(3)?.digest());
+
+        let wider = ApplicationBuilder::<Pasta, ProductionRank, 5>::new()
+            .register_dummy_circuits(2)?
+            .finalize(pasta)?;
+        assert_ne!(app.digest(), wider.digest());
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_capacity_matches_new() -> Result<()> {
+        const NUM_STEPS: usize = 10;
+        let pasta = Pasta::baked();
+
+        let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+            .register_dummy_circuits(NUM_STEPS)?
+            .finalize(pasta)?;
+        let reserved = ApplicationBuilder::<Pasta, ProductionRank, 4>::with_capacity(NUM_STEPS)
+            .register_dummy_circuits(NUM_STEPS)?
+            .finalize(pasta)?;
+
+        assert_eq!(reserved.num_application_steps, app.num_application_steps);
+        assert_eq!(
+            reserved.native_registry.num_circuits(),
+            app.native_registry.num_circuits()
+        );
+        assert_eq!(
+            reserved.native_registry.digest(),
+            app.native_registry.digest()
+        );
+        assert_eq!(
+            reserved.nested_registry.digest(),
+            app.nested_registry.digest()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn reblinding_without_reproving_fails_verification() -> Result<()> {
+        let pasta = Pasta::baked();
+        let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new().finalize(pasta)?;
+        let mut rng = StdRng::seed_from_u64(1907);
+
+        let (pcd, ()) = app.seed(&mut rng, step::internal::trivial::Trivial::new(), ())?;
+        assert!(app.verify(&pcd, &mut rng)?);
+
+        // Mixing fresh randomness into a committed polynomial without
+        // recomputing the challenges derived from it invalidates the proof.
+        let (mut proof, ()) = pcd.into_parts();
+        proof
+            .native_application_rx
+            .add_assign(&sparse::Polynomial::random(&mut rng));
+        assert!(!app.verify(&proof.carry::<()>(()), &mut rng)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn finalize_rejects_insufficient_generators() {
+        use ragu_circuits::polynomials::R;
+
+        // The baked parameters suffice for the production rank, but not for
+        // the next one.
+        let pasta = Pasta::baked();
+        assert!(Pasta::host_generators(pasta).num_generators() >= ProductionRank::num_coeffs());
+        assert!(Pasta::nested_generators(pasta).num_generators() >= ProductionRank::num_coeffs());
+
+        let result = ApplicationBuilder::<Pasta, R<14>, 4>::new().finalize(pasta);
+        assert!(matches!(
+            result,
+            Err(Error::InsufficientGenerators { required, .. }) if required == 1 << 14
+        ));
+    }
+}
@@ -0,0 +1,188 @@
+//! Read-only access to the internal components of a [`Proof`].
+//!
+//! This module is only available with the `internals` feature, which is
+//! intended for research and experimentation with the protocol. **Enabling
+//! `internals` voids any API stability guarantees**: the accessors exposed here
+//! mirror the current proof layout and may change or disappear in any release.
+
+use ragu_arithmetic::Cycle;
+use ragu_circuits::{
+    polynomials::{Rank, sparse},
+    registry::CircuitIndex,
+};
+
+use super::{Pcd, Proof};
+use crate::header::Header;
+
+/// Produces `pub fn $name(&self) -> &$ty` that borrows a proof field.
+macro_rules! ref_accessor {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Returns a reference to the `", stringify!($name), "` component.")]
+        pub fn $name(&self) -> &'a $ty {
+            &self.proof.$name
+        }
+    };
+}
+
+/// Produces `pub fn $name(&self) -> $ty` that copies a `Copy` proof field.
+macro_rules! value_accessor {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Returns the `", stringify!($name), "` value.")]
+        pub fn $name(&self) -> $ty {
+            self.proof.$name
+        }
+    };
+}
+
+/// Produces `pub fn $name(&self) -> $ty` that reads a cached proof field.
+macro_rules! cached_accessor {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Returns the cached `", stringify!($name), "` value.")]
+        pub fn $name(&self) -> $ty {
+            self.proof.$name.0
+        }
+    };
+}
+
+/// Produces `pub fn $name(&self) -> &$ty` that borrows a cached proof field.
+macro_rules! cached_ref_accessor {
+    ($name:ident, $ty:ty) => {
+        #[doc = concat!("Returns a reference to the cached `", stringify!($name), "` component.")]
+        pub fn $name(&self) -> &'a $ty {
+            &self.proof.$name.0
+        }
+    };
+}
+
+/// A read-only view over the internal components of a [`Proof`], obtained via
+/// [`Proof::internals`].
+pub struct Internals<'a, C: Cycle, R: Rank> {
+    proof: &'a Proof<C, R>,
+}
+
+impl<C: Cycle, R: Rank> Proof<C, R> {
+    /// Returns a read-only view over the internal components of this proof.
+    ///
+    /// See the [`internals`](crate::internals) module documentation regarding
+    /// API stability.
+    pub fn internals(&self) -> Internals<'_, C, R> {
+        Internals { proof: self }
+    }
+}
+
+impl<C: Cycle, R: Rank, H: Header<C::CircuitField>> Pcd<C, R, H> {
+    /// Returns a read-only view over the internal components of the
+    /// accompanying proof.
+    ///
+    /// See the [`internals`](crate::internals) module documentation regarding
+    /// API stability.
+    pub fn internals(&self) -> Internals<'_, C, R> {
+        self.proof.internals()
+    }
+}
+
+impl<'a, C: Cycle, R: Rank> Internals<'a, C, R> {
+    value_accessor!(bridge_alpha, C::ScalarField);
+
+    // Application metadata
+    value_accessor!(circuit_id, CircuitIndex);
+
+    /// Returns the encoded left child header.
+    pub fn left_header(&self) -> &'a [C::CircuitField] {
+        &self.proof.left_header
+    }
+
+    /// Returns the encoded right child header.
+    pub fn right_header(&self) -> &'a [C::CircuitField] {
+        &self.proof.right_header
+    }
+
+    // Native rx polynomials
+    ref_accessor!(native_application_rx, sparse::Polynomial<C::CircuitField, R>);
+    ref_accessor!(native_preamble_rx, sparse::Polynomial<C::CircuitField, R>);
+    ref_accessor!(native_inner_error_rx, sparse::Polynomial<C::CircuitField, R>);
+    ref_accessor!(native_outer_error_rx, sparse::Polynomial<C::CircuitField, R>);
+    ref_accessor!(native_a_poly, sparse::Polynomial<C::CircuitField, R>);
+    ref_accessor!(native_b_poly, sparse::Polynomial<C::CircuitField, R>);
+    ref_accessor!(native_query_rx, sparse::Polynomial<C::CircuitField, R>);
+    ref_accessor!(native_registry_xy_poly, sparse::Polynomial<C::CircuitField, R>);
+    ref_accessor!(native_eval_rx, sparse::Polynomial<C::CircuitField, R>);
+    ref_accessor!(native_p_poly, sparse::Polynomial<C::CircuitField, R>);
+    ref_accessor!(native_hashes_1_rx, sparse::Polynomial<C::CircuitField, R>);
+    ref_accessor!(native_hashes_2_rx, sparse::Polynomial<C::CircuitField, R>);
+    ref_accessor!(native_inner_collapse_rx, sparse::Polynomial<C::CircuitField, R>);
+    ref_accessor!(native_outer_collapse_rx, sparse::Polynomial<C::CircuitField, R>);
+    ref_accessor!(native_compute_v_rx, sparse::Polynomial<C::CircuitField, R>);
+
+    // Bridge rx polynomials
+    ref_accessor!(bridge_preamble_rx, sparse::Polynomial<C::ScalarField, R>);
+    ref_accessor!(bridge_s_prime_rx, sparse::Polynomial<C::ScalarField, R>);
+    ref_accessor!(bridge_inner_error_rx, sparse::Polynomial<C::ScalarField, R>);
+    ref_accessor!(bridge_f_rx, sparse::Polynomial<C::ScalarField, R>);
+    cached_ref_accessor!(bridge_outer_error_rx, sparse::Polynomial<C::ScalarField, R>);
+    cached_ref_accessor!(bridge_ab_rx, sparse::Polynomial<C::ScalarField, R>);
+    cached_ref_accessor!(bridge_query_rx, sparse::Polynomial<C::ScalarField, R>);
+    cached_ref_accessor!(bridge_eval_rx, sparse::Polynomial<C::ScalarField, R>);
+
+    // Nested endoscaling data
+
+    /// Returns the rx polynomials of every endoscaling step circuit.
+    pub fn nested_endoscaling_step_rxs(&self) -> &'a [sparse::Polynomial<C::ScalarField, R>] {
+        &self.proof.nested_endoscaling_step_rxs
+    }
+
+    ref_accessor!(nested_endoscalar_rx, sparse::Polynomial<C::ScalarField, R>);
+    ref_accessor!(nested_points_rx, sparse::Polynomial<C::ScalarField, R>);
+
+    /// Returns the commitment of the endoscaling step circuit at `step`, or
+    /// `None` if `step` is out of range.
+    pub fn nested_endoscaling_step_commitment(&self, step: usize) -> Option<C::NestedCurve> {
+        self.proof
+            .nested_endoscaling_step_commitments
+            .get(step)
+            .map(|c| c.0)
+    }
+
+    cached_accessor!(nested_endoscalar_commitment, C::NestedCurve);
+    cached_accessor!(nested_points_commitment, C::NestedCurve);
+
+    // Challenges
+    value_accessor!(w, C::CircuitField);
+    value_accessor!(y, C::CircuitField);
+    value_accessor!(z, C::CircuitField);
+    value_accessor!(mu, C::CircuitField);
+    value_accessor!(nu, C::CircuitField);
+    value_accessor!(mu_prime, C::CircuitField);
+    value_accessor!(nu_prime, C::CircuitField);
+    value_accessor!(x, C::CircuitField);
+    value_accessor!(alpha, C::CircuitField);
+    value_accessor!(u, C::CircuitField);
+    value_accessor!(pre_beta, C::CircuitField);
+
+    // Native commitments
+    cached_accessor!(native_application_commitment, C::HostCurve);
+    cached_accessor!(native_preamble_commitment, C::HostCurve);
+    cached_accessor!(native_inner_error_commitment, C::HostCurve);
+    cached_accessor!(native_outer_error_commitment, C::HostCurve);
+    cached_accessor!(native_a_commitment, C::HostCurve);
+    cached_accessor!(native_b_commitment, C::HostCurve);
+    cached_accessor!(native_query_commitment, C::HostCurve);
+    cached_accessor!(native_registry_xy_commitment, C::HostCurve);
+    cached_accessor!(native_eval_commitment, C::HostCurve);
+    cached_accessor!(native_p_commitment, C::HostCurve);
+    cached_accessor!(native_hashes_1_commitment, C::HostCurve);
+    cached_accessor!(native_hashes_2_commitment, C::HostCurve);
+    cached_accessor!(native_inner_collapse_commitment, C::HostCurve);
+    cached_accessor!(native_outer_collapse_commitment, C::HostCurve);
+    cached_accessor!(native_compute_v_commitment, C::HostCurve);
+
+    // Bridge commitments
+    value_accessor!(bridge_preamble_commitment, C::NestedCurve);
+    value_accessor!(bridge_s_prime_commitment, C::NestedCurve);
+    value_accessor!(bridge_inner_error_commitment, C::NestedCurve);
+    value_accessor!(bridge_f_commitment, C::NestedCurve);
+    cached_accessor!(bridge_outer_error_commitment, C::NestedCurve);
+    cached_accessor!(bridge_ab_commitment, C::NestedCurve);
+    cached_accessor!(bridge_query_commitment, C::NestedCurve);
+    cached_accessor!(bridge_eval_commitment, C::NestedCurve);
+}
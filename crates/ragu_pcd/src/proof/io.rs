@@ -0,0 +1,778 @@
+//! Serialization of [`Proof`]s to and from byte streams.
+//!
+//! The encoding begins with [`MAGIC`], [`VERSION`] and a byte selecting how
+//! curve points are encoded, followed by every field of the [`Proof`] in
+//! declaration order:
+//!
+//! * field elements are written in their canonical
+//!   [`PrimeField::Repr`](ff::PrimeField::Repr) encoding, of
+//!   [`Cycle::CIRCUIT_FIELD_BYTES`] or [`Cycle::SCALAR_FIELD_BYTES`] bytes;
+//! * curve points are written in their compressed
+//!   [`GroupEncoding`] encoding;
+//! * vectors are prefixed by their length, and sparse polynomials are written
+//!   as their number of coefficients followed by a length-prefixed list of
+//!   `(start, length, coefficients...)` blocks.
+//!
+//! Curve points are either written inline ([`INLINE_POINTS`]), or
+//! ([`POINT_TABLES`]) the distinct host and nested curve points are written
+//! once each, as two length-prefixed tables preceding the fields, and every
+//! point is written as its index into the table for its curve.
+//!
+//! All integers are little-endian `u32`s. Proofs are read incrementally, so a
+//! truncated stream is reported as soon as it is encountered.
+
+use alloc::vec::Vec;
+use std::io::{self, Read, Write};
+
+use ff::{Field, PrimeField};
+use pasta_curves::group::GroupEncoding;
+use ragu_arithmetic::Cycle;
+use ragu_circuits::{
+    polynomials::{Rank, sparse},
+    registry::CircuitIndex,
+};
+use ragu_core::{Error, Result};
+use ragu_primitives::vec::Len;
+
+use super::{Cached, Proof};
+use crate::internal::{endoscalar::NumStepsLen, nested::NUM_ENDOSCALING_POINTS};
+
+/// Identifies a serialized [`Proof`].
+const MAGIC: [u8; 4] = *b"ragu";
+
+/// The version of the proof encoding.
+const VERSION: u8 = 3;
+
+/// Curve points are written inline, as by [`Proof::write_to`].
+const INLINE_POINTS: u8 = 0;
+
+/// Curve points are written as indices into tables of distinct points, as by
+/// [`Proof::write_compressed_to`].
+const POINT_TABLES: u8 = 1;
+
+impl<C: Cycle, R: Rank> Proof<C, R> {
+    /// Writes this proof to `writer`.
+    ///
+    /// The proof can be read back with [`read_from`](Proof::read_from).
+    pub fn write_to(&self, writer: &mut impl Write) -> Result<()> {
+        write_header(writer, INLINE_POINTS)?;
+        self.write_body(writer, &mut Points::inline(), &mut Points::inline())
+    }
+
+    /// Writes this proof to `writer`, storing each distinct commitment only
+    /// once.
+    ///
+    /// Proofs often repeat commitments (a trivial proof commits to the same
+    /// polynomial many times), in which case this encoding is smaller than
+    /// that of [`write_to`](Proof::write_to). Both are read back by
+    /// [`read_from`](Proof::read_from).
+    pub fn write_compressed_to(&self, writer: &mut impl Write) -> Result<()> {
+        let mut host = Points::table();
+        let mut nested = Points::table();
+        let mut body = Vec::new();
+        self.write_body(&mut body, &mut host, &mut nested)?;
+
+        write_header(writer, POINT_TABLES)?;
+        host.write_table(writer)?;
+        nested.write_table(writer)?;
+        writer.write_all(&body).map_err(io_error)
+    }
+
+    /// Writes every field of this proof to `writer`, encoding curve points
+    /// with `host` and `nested`.
+    fn write_body(
+        &self,
+        writer: &mut impl Write,
+        host: &mut Points<C::HostCurve>,
+        nested: &mut Points<C::NestedCurve>,
+    ) -> Result<()> {
+        debug_assert_field_bytes::<C>();
+
+        // Exhaustive destructuring, so that new fields cannot be forgotten.
+        let Proof {
+            bridge_alpha,
+            circuit_id,
+            left_header,
+            right_header,
+            native_application_rx,
+            native_preamble_rx,
+            native_inner_error_rx,
+            native_outer_error_rx,
+            native_a_poly,
+            native_b_poly,
+            native_query_rx,
+            native_registry_xy_poly,
+            native_eval_rx,
+            native_p_poly,
+            native_hashes_1_rx,
+            native_hashes_2_rx,
+            native_inner_collapse_rx,
+            native_outer_collapse_rx,
+            native_compute_v_rx,
+            bridge_preamble_rx,
+            bridge_s_prime_rx,
+            bridge_inner_error_rx,
+            bridge_f_rx,
+            bridge_outer_error_rx,
+            bridge_ab_rx,
+            bridge_query_rx,
+            bridge_eval_rx,
+            nested_endoscaling_step_rxs,
+            nested_endoscalar_rx,
+            nested_points_rx,
+            nested_endoscaling_step_commitments,
+            nested_endoscalar_commitment,
+            nested_points_commitment,
+            w,
+            y,
+            z,
+            mu,
+            nu,
+            mu_prime,
+            nu_prime,
+            x,
+            alpha,
+            u,
+            pre_beta,
+            native_application_commitment,
+            native_preamble_commitment,
+            native_inner_error_commitment,
+            native_outer_error_commitment,
+            native_a_commitment,
+            native_b_commitment,
+            native_query_commitment,
+            native_registry_xy_commitment,
+            native_eval_commitment,
+            native_p_commitment,
+            native_hashes_1_commitment,
+            native_hashes_2_commitment,
+            native_inner_collapse_commitment,
+            native_outer_collapse_commitment,
+            native_compute_v_commitment,
+            bridge_preamble_commitment,
+            bridge_s_prime_commitment,
+            bridge_inner_error_commitment,
+            bridge_f_commitment,
+            bridge_outer_error_commitment,
+            bridge_ab_commitment,
+            bridge_query_commitment,
+            bridge_eval_commitment,
+        } = self;
+
+        write_field(writer, bridge_alpha)?;
+        write_u32(writer, usize::from(*circuit_id))?;
+        write_fields(writer, left_header)?;
+        write_fields(writer, right_header)?;
+        write_poly(writer, native_application_rx)?;
+        write_poly(writer, native_preamble_rx)?;
+        write_poly(writer, native_inner_error_rx)?;
+        write_poly(writer, native_outer_error_rx)?;
+        write_poly(writer, native_a_poly)?;
+        write_poly(writer, native_b_poly)?;
+        write_poly(writer, native_query_rx)?;
+        write_poly(writer, native_registry_xy_poly)?;
+        write_poly(writer, native_eval_rx)?;
+        write_poly(writer, native_p_poly)?;
+        write_poly(writer, native_hashes_1_rx)?;
+        write_poly(writer, native_hashes_2_rx)?;
+        write_poly(writer, native_inner_collapse_rx)?;
+        write_poly(writer, native_outer_collapse_rx)?;
+        write_poly(writer, native_compute_v_rx)?;
+        write_poly(writer, bridge_preamble_rx)?;
+        write_poly(writer, bridge_s_prime_rx)?;
+        write_poly(writer, bridge_inner_error_rx)?;
+        write_poly(writer, bridge_f_rx)?;
+        write_poly(writer, &bridge_outer_error_rx.0)?;
+        write_poly(writer, &bridge_ab_rx.0)?;
+        write_poly(writer, &bridge_query_rx.0)?;
+        write_poly(writer, &bridge_eval_rx.0)?;
+        write_polys(writer, nested_endoscaling_step_rxs)?;
+        write_poly(writer, nested_endoscalar_rx)?;
+        write_poly(writer, nested_points_rx)?;
+        nested.write_many(
+            writer,
+            nested_endoscaling_step_commitments.iter().map(|c| &c.0),
+        )?;
+        nested.write(writer, &nested_endoscalar_commitment.0)?;
+        nested.write(writer, &nested_points_commitment.0)?;
+        write_field(writer, w)?;
+        write_field(writer, y)?;
+        write_field(writer, z)?;
+        write_field(writer, mu)?;
+        write_field(writer, nu)?;
+        write_field(writer, mu_prime)?;
+        write_field(writer, nu_prime)?;
+        write_field(writer, x)?;
+        write_field(writer, alpha)?;
+        write_field(writer, u)?;
+        write_field(writer, pre_beta)?;
+        host.write(writer, &native_application_commitment.0)?;
+        host.write(writer, &native_preamble_commitment.0)?;
+        host.write(writer, &native_inner_error_commitment.0)?;
+        host.write(writer, &native_outer_error_commitment.0)?;
+        host.write(writer, &native_a_commitment.0)?;
+        host.write(writer, &native_b_commitment.0)?;
+        host.write(writer, &native_query_commitment.0)?;
+        host.write(writer, &native_registry_xy_commitment.0)?;
+        host.write(writer, &native_eval_commitment.0)?;
+        host.write(writer, &native_p_commitment.0)?;
+        host.write(writer, &native_hashes_1_commitment.0)?;
+        host.write(writer, &native_hashes_2_commitment.0)?;
+        host.write(writer, &native_inner_collapse_commitment.0)?;
+        host.write(writer, &native_outer_collapse_commitment.0)?;
+        host.write(writer, &native_compute_v_commitment.0)?;
+        nested.write(writer, bridge_preamble_commitment)?;
+        nested.write(writer, bridge_s_prime_commitment)?;
+        nested.write(writer, bridge_inner_error_commitment)?;
+        nested.write(writer, bridge_f_commitment)?;
+        nested.write(writer, &bridge_outer_error_commitment.0)?;
+        nested.write(writer, &bridge_ab_commitment.0)?;
+        nested.write(writer, &bridge_query_commitment.0)?;
+        nested.write(writer, &bridge_eval_commitment.0)?;
+
+        Ok(())
+    }
+
+    /// Reads a proof from `reader`, as written by
+    /// [`write_to`](Proof::write_to) or
+    /// [`write_compressed_to`](Proof::write_compressed_to).
+    ///
+    /// Returns [`Error::UnexpectedEof`] if the stream ends prematurely,
+    /// [`Error::MalformedEncoding`] if it does not begin with the expected
+    /// magic and version or does not decode to a well-formed proof, and
+    /// [`Error::PolynomialLengthMismatch`] if a polynomial was written for a
+    /// different [`Rank`]. No check is made that the proof is valid.
+    pub fn read_from(reader: &mut impl Read) -> Result<Self> {
+        debug_assert_field_bytes::<C>();
+
+        let mut magic = [0u8; MAGIC.len()];
+        reader.read_exact(&mut magic).map_err(io_error)?;
+        if magic != MAGIC {
+            return Err(Error::MalformedEncoding("invalid proof magic".into()));
+        }
+        let mut version = [0u8; 1];
+        reader.read_exact(&mut version).map_err(io_error)?;
+        if version[0] != VERSION {
+            return Err(Error::MalformedEncoding(
+                "unsupported proof encoding version".into(),
+            ));
+        }
+        let mut points = [0u8; 1];
+        reader.read_exact(&mut points).map_err(io_error)?;
+        let (host, nested) = match points[0] {
+            INLINE_POINTS => (Points::inline(), Points::inline()),
+            POINT_TABLES => (Points::read_table(reader)?, Points::read_table(reader)?),
+            _ => {
+                return Err(Error::MalformedEncoding(
+                    "unsupported curve point encoding".into(),
+                ));
+            }
+        };
+
+        let num_endoscaling_steps = NumStepsLen::<NUM_ENDOSCALING_POINTS>::len();
+
+        Ok(Proof {
+            bridge_alpha: read_field(reader)?,
+            circuit_id: CircuitIndex::from_u32(read_u32(reader)?),
+            left_header: read_fields(reader)?,
+            right_header: read_fields(reader)?,
+            native_application_rx: read_poly(reader)?,
+            native_preamble_rx: read_poly(reader)?,
+            native_inner_error_rx: read_poly(reader)?,
+            native_outer_error_rx: read_poly(reader)?,
+            native_a_poly: read_poly(reader)?,
+            native_b_poly: read_poly(reader)?,
+            native_query_rx: read_poly(reader)?,
+            native_registry_xy_poly: read_poly(reader)?,
+            native_eval_rx: read_poly(reader)?,
+            native_p_poly: read_poly(reader)?,
+            native_hashes_1_rx: read_poly(reader)?,
+            native_hashes_2_rx: read_poly(reader)?,
+            native_inner_collapse_rx: read_poly(reader)?,
+            native_outer_collapse_rx: read_poly(reader)?,
+            native_compute_v_rx: read_poly(reader)?,
+            bridge_preamble_rx: read_poly(reader)?,
+            bridge_s_prime_rx: read_poly(reader)?,
+            bridge_inner_error_rx: read_poly(reader)?,
+            bridge_f_rx: read_poly(reader)?,
+            bridge_outer_error_rx: Cached(read_poly(reader)?),
+            bridge_ab_rx: Cached(read_poly(reader)?),
+            bridge_query_rx: Cached(read_poly(reader)?),
+            bridge_eval_rx: Cached(read_poly(reader)?),
+            nested_endoscaling_step_rxs: read_polys(reader, num_endoscaling_steps)?,
+            nested_endoscalar_rx: read_poly(reader)?,
+            nested_points_rx: read_poly(reader)?,
+            nested_endoscaling_step_commitments: nested
+                .read_many(reader, num_endoscaling_steps)?
+                .into_iter()
+                .map(Cached)
+                .collect(),
+            nested_endoscalar_commitment: Cached(nested.read(reader)?),
+            nested_points_commitment: Cached(nested.read(reader)?),
+            w: read_field(reader)?,
+            y: read_field(reader)?,
+            z: read_field(reader)?,
+            mu: read_field(reader)?,
+            nu: read_field(reader)?,
+            mu_prime: read_field(reader)?,
+            nu_prime: read_field(reader)?,
+            x: read_field(reader)?,
+            alpha: read_field(reader)?,
+            u: read_field(reader)?,
+            pre_beta: read_field(reader)?,
+            native_application_commitment: Cached(host.read(reader)?),
+            native_preamble_commitment: Cached(host.read(reader)?),
+            native_inner_error_commitment: Cached(host.read(reader)?),
+            native_outer_error_commitment: Cached(host.read(reader)?),
+            native_a_commitment: Cached(host.read(reader)?),
+            native_b_commitment: Cached(host.read(reader)?),
+            native_query_commitment: Cached(host.read(reader)?),
+            native_registry_xy_commitment: Cached(host.read(reader)?),
+            native_eval_commitment: Cached(host.read(reader)?),
+            native_p_commitment: Cached(host.read(reader)?),
+            native_hashes_1_commitment: Cached(host.read(reader)?),
+            native_hashes_2_commitment: Cached(host.read(reader)?),
+            native_inner_collapse_commitment: Cached(host.read(reader)?),
+            native_outer_collapse_commitment: Cached(host.read(reader)?),
+            native_compute_v_commitment: Cached(host.read(reader)?),
+            bridge_preamble_commitment: nested.read(reader)?,
+            bridge_s_prime_commitment: nested.read(reader)?,
+            bridge_inner_error_commitment: nested.read(reader)?,
+            bridge_f_commitment: nested.read(reader)?,
+            bridge_outer_error_commitment: Cached(nested.read(reader)?),
+            bridge_ab_commitment: Cached(nested.read(reader)?),
+            bridge_query_commitment: Cached(nested.read(reader)?),
+            bridge_eval_commitment: Cached(nested.read(reader)?),
+        })
+    }
+
+    /// Decodes a proof from `bytes`, as written by
+    /// [`write_to`](Proof::write_to) or
+    /// [`write_compressed_to`](Proof::write_compressed_to).
+    ///
+    /// This is [`read_from`](Proof::read_from) over a byte slice that must be
+    /// consumed exactly; trailing bytes are rejected with
+    /// [`Error::MalformedEncoding`]. Decoding never panics on untrusted
+    /// input: every length, field element and curve point is validated and
+    /// reported as an [`Error`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+        let proof = Self::read_from(&mut reader)?;
+        if !reader.is_empty() {
+            return Err(Error::MalformedEncoding(
+                "trailing bytes after proof".into(),
+            ));
+        }
+        Ok(proof)
+    }
+}
+
+/// Encodes the curve points of one curve, either inline or as indices into a
+/// table of distinct points.
+struct Points<G> {
+    table: Option<Vec<G>>,
+}
+
+impl<G: GroupEncoding + Copy + PartialEq> Points<G> {
+    fn inline() -> Self {
+        Points { table: None }
+    }
+
+    fn table() -> Self {
+        Points {
+            table: Some(Vec::new()),
+        }
+    }
+
+    fn write_table(&self, writer: &mut impl Write) -> Result<()> {
+        write_points(writer, self.table.as_deref().unwrap_or(&[]).iter())
+    }
+
+    fn read_table(reader: &mut impl Read) -> Result<Self> {
+        let len = read_len(reader)?;
+        // Points are read one at a time, so a corrupt length cannot cause a
+        // large allocation before the stream runs out.
+        let table = (0..len)
+            .map(|_| read_point(reader))
+            .collect::<Result<_>>()?;
+        Ok(Points { table: Some(table) })
+    }
+
+    fn write(&mut self, writer: &mut impl Write, point: &G) -> Result<()> {
+        match &mut self.table {
+            None => write_point(writer, point),
+            Some(table) => {
+                let index = match table.iter().position(|p| p == point) {
+                    Some(index) => index,
+                    None => {
+                        table.push(*point);
+                        table.len() - 1
+                    }
+                };
+                write_u32(writer, index)
+            }
+        }
+    }
+
+    fn read(&self, reader: &mut impl Read) -> Result<G> {
+        match &self.table {
+            None => read_point(reader),
+            Some(table) => table
+                .get(read_len(reader)?)
+                .copied()
+                .ok_or_else(|| Error::MalformedEncoding("curve point index out of range".into())),
+        }
+    }
+
+    fn write_many<'a>(
+        &mut self,
+        writer: &mut impl Write,
+        points: impl ExactSizeIterator<Item = &'a G>,
+    ) -> Result<()>
+    where
+        G: 'a,
+    {
+        write_u32(writer, points.len())?;
+        points
+            .into_iter()
+            .try_for_each(|point| self.write(writer, point))
+    }
+
+    fn read_many(&self, reader: &mut impl Read, expected: usize) -> Result<Vec<G>> {
+        read_expected_len(reader, expected)?;
+        (0..expected).map(|_| self.read(reader)).collect()
+    }
+}
+
+/// Checks that the field widths declared by the [`Cycle`] match the encodings
+/// of its fields.
+fn debug_assert_field_bytes<C: Cycle>() {
+    debug_assert_eq!(
+        C::CIRCUIT_FIELD_BYTES,
+        C::CircuitField::ZERO.to_repr().as_ref().len()
+    );
+    debug_assert_eq!(
+        C::SCALAR_FIELD_BYTES,
+        C::ScalarField::ZERO.to_repr().as_ref().len()
+    );
+}
+
+fn write_header(writer: &mut impl Write, points: u8) -> Result<()> {
+    writer.write_all(&MAGIC).map_err(io_error)?;
+    writer.write_all(&[VERSION, points]).map_err(io_error)
+}
+
+fn io_error(err: io::Error) -> Error {
+    match err.kind() {
+        io::ErrorKind::UnexpectedEof => Error::UnexpectedEof,
+        _ => Error::MalformedEncoding(err.into()),
+    }
+}
+
+fn write_u32(writer: &mut impl Write, value: usize) -> Result<()> {
+    let value = u32::try_from(value)
+        .map_err(|_| Error::MalformedEncoding("length does not fit in a u32".into()))?;
+    writer.write_all(&value.to_le_bytes()).map_err(io_error)
+}
+
+fn read_u32(reader: &mut impl Read) -> Result<u32> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes).map_err(io_error)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_len(reader: &mut impl Read) -> Result<usize> {
+    read_u32(reader).map(|len| len as usize)
+}
+
+fn write_field<F: PrimeField>(writer: &mut impl Write, value: &F) -> Result<()> {
+    writer.write_all(value.to_repr().as_ref()).map_err(io_error)
+}
+
+fn read_field<F: PrimeField>(reader: &mut impl Read) -> Result<F> {
+    let mut repr = F::Repr::default();
+    reader.read_exact(repr.as_mut()).map_err(io_error)?;
+    Option::from(F::from_repr(repr))
+        .ok_or_else(|| Error::MalformedEncoding("non-canonical field element".into()))
+}
+
+fn write_fields<F: PrimeField>(writer: &mut impl Write, values: &[F]) -> Result<()> {
+    write_u32(writer, values.len())?;
+    values
+        .iter()
+        .try_for_each(|value| write_field(writer, value))
+}
+
+fn read_fields<F: PrimeField>(reader: &mut impl Read) -> Result<Vec<F>> {
+    let len = read_len(reader)?;
+    // The length is untrusted, so the vector is not preallocated.
+    (0..len).map(|_| read_field(reader)).collect()
+}
+
+fn write_point<G: GroupEncoding>(writer: &mut impl Write, point: &G) -> Result<()> {
+    writer
+        .write_all(point.to_bytes().as_ref())
+        .map_err(io_error)
+}
+
+fn read_point<G: GroupEncoding>(reader: &mut impl Read) -> Result<G> {
+    let mut repr = G::Repr::default();
+    reader.read_exact(repr.as_mut()).map_err(io_error)?;
+    Option::from(G::from_bytes(&repr))
+        .ok_or_else(|| Error::MalformedEncoding("invalid curve point".into()))
+}
+
+fn write_points<'a, G: GroupEncoding + 'a>(
+    writer: &mut impl Write,
+    points: impl ExactSizeIterator<Item = &'a G>,
+) -> Result<()> {
+    write_u32(writer, points.len())?;
+    for point in points {
+        write_point(writer, point)?;
+    }
+    Ok(())
+}
+
+fn write_poly<F: PrimeField, R: Rank>(
+    writer: &mut impl Write,
+    poly: &sparse::Polynomial<F, R>,
+) -> Result<()> {
+    write_u32(writer, poly.len_coeffs())?;
+    write_u32(writer, poly.blocks().len())?;
+    for (start, data) in poly.blocks() {
+        write_u32(writer, start)?;
+        write_fields(writer, data)?;
+    }
+    Ok(())
+}
+
+fn read_poly<F: PrimeField, R: Rank>(reader: &mut impl Read) -> Result<sparse::Polynomial<F, R>> {
+    let num_coeffs = read_len(reader)?;
+    if num_coeffs != R::num_coeffs() {
+        return Err(Error::PolynomialLengthMismatch {
+            expected: R::num_coeffs(),
+            actual: num_coeffs,
+        });
+    }
+
+    let num_blocks = read_len(reader)?;
+    let mut blocks = Vec::new();
+    let mut remaining = R::num_coeffs();
+    for _ in 0..num_blocks {
+        let start = read_len(reader)?;
+        let len = read_len(reader)?;
+        // Reject oversized blocks before reading their coefficients.
+        remaining = remaining
+            .checked_sub(len)
+            .ok_or_else(|| Error::MalformedEncoding("polynomial exceeds capacity".into()))?;
+        let data = (0..len)
+            .map(|_| read_field(reader))
+            .collect::<Result<Vec<F>>>()?;
+        blocks.push((start, data));
+    }
+    sparse::Polynomial::try_from_blocks(blocks)
+}
+
+fn write_polys<F: PrimeField, R: Rank>(
+    writer: &mut impl Write,
+    polys: &[sparse::Polynomial<F, R>],
+) -> Result<()> {
+    write_u32(writer, polys.len())?;
+    polys.iter().try_for_each(|poly| write_poly(writer, poly))
+}
+
+fn read_polys<F: PrimeField, R: Rank>(
+    reader: &mut impl Read,
+    expected: usize,
+) -> Result<Vec<sparse::Polynomial<F, R>>> {
+    read_expected_len(reader, expected)?;
+    (0..expected).map(|_| read_poly(reader)).collect()
+}
+
+fn read_expected_len(reader: &mut impl Read, expected: usize) -> Result<()> {
+    let actual = read_len(reader)?;
+    if actual != expected {
+        return Err(Error::VectorLengthMismatch { expected, actual });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use proptest::prelude::*;
+    use ragu_circuits::polynomials::{ProductionRank, TestRank};
+    use ragu_pasta::{Fp, Pasta};
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+    use crate::{ApplicationBuilder, step::internal::trivial::Trivial};
+
+    fn proof() -> Result<Proof<Pasta, ProductionRank>> {
+        let pasta = Pasta::baked();
+        let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new().finalize(pasta)?;
+        let (pcd, ()) = app.seed(&mut StdRng::seed_from_u64(1861), Trivial::new(), ())?;
+        Ok(pcd.into_parts().0)
+    }
+
+    #[test]
+    fn roundtrip() -> Result<()> {
+        let proof = proof()?;
+        let mut bytes = Vec::new();
+        proof.write_to(&mut bytes)?;
+
+        let mut cursor = Cursor::new(&bytes);
+        assert!(Proof::read_from(&mut cursor)? == proof);
+        assert_eq!(cursor.position() as usize, bytes.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn compressed_roundtrip() -> Result<()> {
+        let proof = proof()?;
+        let mut bytes = Vec::new();
+        proof.write_compressed_to(&mut bytes)?;
+
+        let mut cursor = Cursor::new(&bytes);
+        assert!(Proof::read_from(&mut cursor)? == proof);
+        assert_eq!(cursor.position() as usize, bytes.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn compressed_trivial_proof_is_smaller() -> Result<()> {
+        let pasta = Pasta::baked();
+        let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new().finalize(pasta)?;
+        let trivial = app.trivial();
+
+        let mut bytes = Vec::new();
+        trivial.write_to(&mut bytes)?;
+        let mut compressed = Vec::new();
+        trivial.write_compressed_to(&mut compressed)?;
+
+        // The trivial proof commits to the same polynomial over and over.
+        assert!(compressed.len() < bytes.len());
+        assert!(Proof::from_bytes(&compressed)? == trivial);
+
+        Ok(())
+    }
+
+    #[test]
+    fn compressed_index_out_of_range_fails() -> Result<()> {
+        let mut bytes = Vec::new();
+        proof()?.write_compressed_to(&mut bytes)?;
+
+        // Point the last commitment past the end of the nested table.
+        let len = bytes.len();
+        bytes[len - 4..].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            Proof::<Pasta, ProductionRank>::from_bytes(&bytes),
+            Err(Error::MalformedEncoding(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn truncated_input_fails() -> Result<()> {
+        let mut bytes = Vec::new();
+        proof()?.write_to(&mut bytes)?;
+
+        for len in [0, MAGIC.len(), bytes.len() / 2, bytes.len() - 1] {
+            assert!(
+                matches!(
+                    Proof::<Pasta, ProductionRank>::read_from(&mut Cursor::new(&bytes[..len])),
+                    Err(Error::UnexpectedEof)
+                ),
+                "truncated proof of length {len} should fail to decode"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_magic_or_version_fails() -> Result<()> {
+        let mut bytes = Vec::new();
+        proof()?.write_to(&mut bytes)?;
+
+        for index in [0, MAGIC.len()] {
+            let mut corrupted = bytes.clone();
+            corrupted[index] ^= 1;
+            assert!(matches!(
+                Proof::<Pasta, ProductionRank>::read_from(&mut Cursor::new(&corrupted)),
+                Err(Error::MalformedEncoding(_))
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn polynomial_length_mismatch_fails() -> Result<()> {
+        let mut rng = StdRng::seed_from_u64(1923);
+        let poly = sparse::Polynomial::<Fp, TestRank>::random(&mut rng);
+        let mut bytes = Vec::new();
+        write_poly(&mut bytes, &poly)?;
+        assert!(read_poly::<Fp, TestRank>(&mut Cursor::new(&bytes))? == poly);
+
+        // A polynomial written at a different rank is rejected up front,
+        // rather than being misread.
+        assert!(matches!(
+            read_poly::<Fp, ProductionRank>(&mut Cursor::new(&bytes)),
+            Err(Error::PolynomialLengthMismatch { expected, actual })
+                if expected == ProductionRank::num_coeffs() && actual == TestRank::num_coeffs()
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_bytes_rejects_trailing_bytes() -> Result<()> {
+        let proof = proof()?;
+        let mut bytes = Vec::new();
+        proof.write_to(&mut bytes)?;
+        assert!(Proof::from_bytes(&bytes)? == proof);
+
+        bytes.push(0);
+        assert!(matches!(
+            Proof::<Pasta, ProductionRank>::from_bytes(&bytes),
+            Err(Error::MalformedEncoding(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_bytes_never_panics_on_corruption() -> Result<()> {
+        let mut bytes = Vec::new();
+        proof()?.write_to(&mut bytes)?;
+
+        // Flip every bit of a spread of bytes across the encoding, covering
+        // length prefixes, field elements and curve points alike.
+        for index in (0..bytes.len()).step_by(bytes.len().div_ceil(512)) {
+            for bit in 0..8 {
+                let mut corrupted = bytes.clone();
+                corrupted[index] ^= 1 << bit;
+                let _ = Proof::<Pasta, ProductionRank>::from_bytes(&corrupted);
+            }
+        }
+
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn from_bytes_never_panics(body in proptest::collection::vec(any::<u8>(), 0..4096)) {
+            // Prefix the magic and version so that decoding proceeds past the
+            // header.
+            let mut bytes = MAGIC.to_vec();
+            bytes.push(VERSION);
+            bytes.extend(body);
+            prop_assert!(Proof::<Pasta, ProductionRank>::from_bytes(&bytes).is_err());
+        }
+    }
+}
@@ -7,17 +7,39 @@
 #![allow(dead_code)]
 
 pub(crate) mod builder;
+#[cfg(feature = "internals")]
+pub mod internals;
+/// Without the `internals` feature, neither the module nor the accessors on
+/// [`Proof`] exist:
+///
+/// ```rust,compile_fail
+/// use ragu_pcd::internals;
+/// ```
+///
+/// ```rust,compile_fail
+/// # use ragu_arithmetic::Cycle;
+/// # use ragu_circuits::polynomials::Rank;
+/// # use ragu_pcd::Proof;
+/// fn peek<C: Cycle, R: Rank>(proof: &Proof<C, R>) {
+///     let _ = proof.internals();
+/// }
+/// ```
+#[cfg(all(doctest, not(feature = "internals")))]
+struct InternalsRequireFeature;
+#[cfg(any(feature = "std", test))]
+mod io;
 
 use alloc::{vec, vec::Vec};
 
 pub(crate) use builder::ProofBuilder;
 use ff::Field;
-use ragu_arithmetic::Cycle;
+use ragu_arithmetic::{CurveAffine, Cycle};
 use ragu_circuits::{
     polynomials::{Rank, sparse},
     registry::CircuitIndex,
 };
-use ragu_primitives::vec::Len;
+use ragu_core::{Error, Result, drivers::emulator::Emulator, maybe::Maybe};
+use ragu_primitives::{GadgetExt, vec::Len};
 
 use crate::{
     header::Header,
@@ -50,6 +72,64 @@ impl<C: Cycle, R: Rank, H: Header<C::CircuitField>> Pcd<C, R, H> {
         &self.data
     }
 
+    /// Returns `true` if both children of this proof were trivial proofs, as
+    /// is the case for proofs created by [`Application::seed`](crate::Application::seed).
+    ///
+    /// This mirrors the in-circuit base case check: a child is trivial if the
+    /// suffix of the header recorded for it is the trivial `()` header suffix.
+    pub fn is_base_case(&self) -> bool {
+        let trivial_suffix = C::CircuitField::from(<() as Header<C::CircuitField>>::SUFFIX.get());
+        [self.proof.left_header(), self.proof.right_header()]
+            .iter()
+            .all(|header| header.last() == Some(&trivial_suffix))
+    }
+
+    /// Returns `true` if `other` carries data with the same header encoding as
+    /// this proof-carrying data, ignoring the proofs themselves.
+    ///
+    /// The data is compared by its serialized [`Header::encode`]ing rather
+    /// than by [`PartialEq`] on [`Header::Data`], so distinct values that
+    /// encode identically compare equal. Both sides share the header `H`, so
+    /// the suffix and padding are identical and are not serialized. Returns
+    /// `false` if either header fails to encode.
+    pub fn same_header(&self, other: &Pcd<C, R, H>) -> bool {
+        match (
+            encode_data::<_, H>(&self.data),
+            encode_data::<_, H>(&other.data),
+        ) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    /// Transforms the carried data into data for a different [`Header`]
+    /// `H2`, keeping the proof unchanged.
+    ///
+    /// Unlike [`Proof::carry`], this checks that the proof remains valid for
+    /// the new data: `H2` must have the same [`Header::SUFFIX`] as `H` and
+    /// must [`encode`](Header::encode) the mapped data to the same field
+    /// elements as `H` encodes the current data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either header fails to encode, or if the
+    /// encodings differ.
+    pub fn map_data<H2: Header<C::CircuitField>>(
+        self,
+        f: impl FnOnce(H::Data) -> H2::Data,
+    ) -> Result<Pcd<C, R, H2>> {
+        let from = encode_data::<_, H>(&self.data)?;
+        let data = f(self.data);
+        let to = encode_data::<_, H2>(&data)?;
+        if H::SUFFIX != H2::SUFFIX || from != to {
+            return Err(Error::InvalidWitness(
+                "header encodings differ; cannot map data without re-proving".into(),
+            ));
+        }
+
+        Ok(self.proof.carry(data))
+    }
+
     /// Returns a reference to the recursive proof.
     pub(crate) fn proof(&self) -> &Proof<C, R> {
         &self.proof
@@ -62,6 +142,21 @@ impl<C: Cycle, R: Rank, H: Header<C::CircuitField>> Pcd<C, R, H> {
     }
 }
 
+/// Serializes `data` using [`Header::encode`], without the padding or suffix.
+fn encode_data<F: Field, H: Header<F>>(data: &H::Data) -> Result<Vec<F>> {
+    Emulator::emulate_wireless(data.clone(), |dr, data| {
+        let gadget = H::encode(dr, data)?;
+
+        let mut elements = Vec::new();
+        gadget.write(dr, &mut elements)?;
+
+        Ok(elements
+            .into_iter()
+            .map(|e| *e.value().take())
+            .collect::<Vec<_>>())
+    })
+}
+
 impl<C: Cycle, R: Rank, H: Header<C::CircuitField>> Clone for Pcd<C, R, H> {
     fn clone(&self) -> Self {
         Pcd {
@@ -77,6 +172,12 @@ impl<C: Cycle, R: Rank, H: Header<C::CircuitField>> Clone for Pcd<C, R, H> {
 /// primary data; commitment fields are `Cached` values derivable from
 /// polynomials. Four bridge polynomials (outer_error, ab, query, eval) are
 /// also `Cached`, derivable from `bridge_alpha` and native commitments.
+///
+/// Commitments are not blinded by a separate scalar; the randomness that hides
+/// the witness lives in the polynomials' own coefficients, which are part of
+/// the proof. A proof that has not been
+/// [rerandomized](crate::Application::rerandomize) should therefore be handled
+/// with the same care as its witness.
 #[derive(Clone)]
 pub struct Proof<C: Cycle, R: Rank> {
     /// Shared alpha source for deriving cached bridge polynomial alphas.
@@ -169,6 +270,153 @@ pub struct Proof<C: Cycle, R: Rank> {
     bridge_eval_commitment: Cached<C::NestedCurve>,
 }
 
+/// Proofs are compared field by field, including their polynomials, cached
+/// commitments and challenges.
+impl<C: Cycle, R: Rank> PartialEq for Proof<C, R> {
+    fn eq(&self, other: &Self) -> bool {
+        // Exhaustive destructuring, so that new fields cannot be forgotten.
+        let Proof {
+            bridge_alpha,
+            circuit_id,
+            left_header,
+            right_header,
+            native_application_rx,
+            native_preamble_rx,
+            native_inner_error_rx,
+            native_outer_error_rx,
+            native_a_poly,
+            native_b_poly,
+            native_query_rx,
+            native_registry_xy_poly,
+            native_eval_rx,
+            native_p_poly,
+            native_hashes_1_rx,
+            native_hashes_2_rx,
+            native_inner_collapse_rx,
+            native_outer_collapse_rx,
+            native_compute_v_rx,
+            bridge_preamble_rx,
+            bridge_s_prime_rx,
+            bridge_inner_error_rx,
+            bridge_f_rx,
+            bridge_outer_error_rx,
+            bridge_ab_rx,
+            bridge_query_rx,
+            bridge_eval_rx,
+            nested_endoscaling_step_rxs,
+            nested_endoscalar_rx,
+            nested_points_rx,
+            nested_endoscaling_step_commitments,
+            nested_endoscalar_commitment,
+            nested_points_commitment,
+            w,
+            y,
+            z,
+            mu,
+            nu,
+            mu_prime,
+            nu_prime,
+            x,
+            alpha,
+            u,
+            pre_beta,
+            native_application_commitment,
+            native_preamble_commitment,
+            native_inner_error_commitment,
+            native_outer_error_commitment,
+            native_a_commitment,
+            native_b_commitment,
+            native_query_commitment,
+            native_registry_xy_commitment,
+            native_eval_commitment,
+            native_p_commitment,
+            native_hashes_1_commitment,
+            native_hashes_2_commitment,
+            native_inner_collapse_commitment,
+            native_outer_collapse_commitment,
+            native_compute_v_commitment,
+            bridge_preamble_commitment,
+            bridge_s_prime_commitment,
+            bridge_inner_error_commitment,
+            bridge_f_commitment,
+            bridge_outer_error_commitment,
+            bridge_ab_commitment,
+            bridge_query_commitment,
+            bridge_eval_commitment,
+        } = self;
+
+        bridge_alpha == &other.bridge_alpha
+            && circuit_id == &other.circuit_id
+            && left_header == &other.left_header
+            && right_header == &other.right_header
+            && native_application_rx == &other.native_application_rx
+            && native_preamble_rx == &other.native_preamble_rx
+            && native_inner_error_rx == &other.native_inner_error_rx
+            && native_outer_error_rx == &other.native_outer_error_rx
+            && native_a_poly == &other.native_a_poly
+            && native_b_poly == &other.native_b_poly
+            && native_query_rx == &other.native_query_rx
+            && native_registry_xy_poly == &other.native_registry_xy_poly
+            && native_eval_rx == &other.native_eval_rx
+            && native_p_poly == &other.native_p_poly
+            && native_hashes_1_rx == &other.native_hashes_1_rx
+            && native_hashes_2_rx == &other.native_hashes_2_rx
+            && native_inner_collapse_rx == &other.native_inner_collapse_rx
+            && native_outer_collapse_rx == &other.native_outer_collapse_rx
+            && native_compute_v_rx == &other.native_compute_v_rx
+            && bridge_preamble_rx == &other.bridge_preamble_rx
+            && bridge_s_prime_rx == &other.bridge_s_prime_rx
+            && bridge_inner_error_rx == &other.bridge_inner_error_rx
+            && bridge_f_rx == &other.bridge_f_rx
+            && bridge_outer_error_rx == &other.bridge_outer_error_rx
+            && bridge_ab_rx == &other.bridge_ab_rx
+            && bridge_query_rx == &other.bridge_query_rx
+            && bridge_eval_rx == &other.bridge_eval_rx
+            && nested_endoscaling_step_rxs == &other.nested_endoscaling_step_rxs
+            && nested_endoscalar_rx == &other.nested_endoscalar_rx
+            && nested_points_rx == &other.nested_points_rx
+            && nested_endoscaling_step_commitments == &other.nested_endoscaling_step_commitments
+            && nested_endoscalar_commitment == &other.nested_endoscalar_commitment
+            && nested_points_commitment == &other.nested_points_commitment
+            && w == &other.w
+            && y == &other.y
+            && z == &other.z
+            && mu == &other.mu
+            && nu == &other.nu
+            && mu_prime == &other.mu_prime
+            && nu_prime == &other.nu_prime
+            && x == &other.x
+            && alpha == &other.alpha
+            && u == &other.u
+            && pre_beta == &other.pre_beta
+            && native_application_commitment == &other.native_application_commitment
+            && native_preamble_commitment == &other.native_preamble_commitment
+            && native_inner_error_commitment == &other.native_inner_error_commitment
+            && native_outer_error_commitment == &other.native_outer_error_commitment
+            && native_a_commitment == &other.native_a_commitment
+            && native_b_commitment == &other.native_b_commitment
+            && native_query_commitment == &other.native_query_commitment
+            && native_registry_xy_commitment == &other.native_registry_xy_commitment
+            && native_eval_commitment == &other.native_eval_commitment
+            && native_p_commitment == &other.native_p_commitment
+            && native_hashes_1_commitment == &other.native_hashes_1_commitment
+            && native_hashes_2_commitment == &other.native_hashes_2_commitment
+            && native_inner_collapse_commitment == &other.native_inner_collapse_commitment
+            && native_outer_collapse_commitment == &other.native_outer_collapse_commitment
+            && native_compute_v_commitment == &other.native_compute_v_commitment
+            && bridge_preamble_commitment == &other.bridge_preamble_commitment
+            && bridge_s_prime_commitment == &other.bridge_s_prime_commitment
+            && bridge_inner_error_commitment == &other.bridge_inner_error_commitment
+            && bridge_f_commitment == &other.bridge_f_commitment
+            && bridge_outer_error_commitment == &other.bridge_outer_error_commitment
+            && bridge_ab_commitment == &other.bridge_ab_commitment
+            && bridge_query_commitment == &other.bridge_query_commitment
+            && bridge_eval_commitment == &other.bridge_eval_commitment
+    }
+}
+
+impl<C: Cycle, R: Rank> Eq for Proof<C, R> {}
+
 impl<C: Cycle, R: Rank> core::ops::Index<RxIndex> for Proof<C, R> {
     type Output = sparse::Polynomial<C::CircuitField, R>;
     fn index(&self, idx: RxIndex) -> &sparse::Polynomial<C::CircuitField, R> {
@@ -226,7 +474,132 @@ impl<C: Cycle, R: Rank> Proof<C, R> {
         Pcd { proof: self, data }
     }
 
+    /// Checks that every commitment of this proof is a valid curve point
+    /// other than the identity.
+    ///
+    /// Commitments are witnessed as affine points by the circuits that fuse
+    /// and verify proofs, and the identity has no affine coordinates, so a
+    /// proof with an identity commitment can never be accepted. This is a
+    /// cheap sanity check to perform before verification, such as after
+    /// reading a proof from an untrusted source.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::DegenerateCommitment`] naming the first offending
+    /// commitment.
+    pub fn validate_commitments(&self) -> Result<()> {
+        // Exhaustive destructuring, so that new commitments cannot be forgotten.
+        let Proof {
+            bridge_alpha: _,
+            circuit_id: _,
+            left_header: _,
+            right_header: _,
+            native_application_rx: _,
+            native_preamble_rx: _,
+            native_inner_error_rx: _,
+            native_outer_error_rx: _,
+            native_a_poly: _,
+            native_b_poly: _,
+            native_query_rx: _,
+            native_registry_xy_poly: _,
+            native_eval_rx: _,
+            native_p_poly: _,
+            native_hashes_1_rx: _,
+            native_hashes_2_rx: _,
+            native_inner_collapse_rx: _,
+            native_outer_collapse_rx: _,
+            native_compute_v_rx: _,
+            bridge_preamble_rx: _,
+            bridge_s_prime_rx: _,
+            bridge_inner_error_rx: _,
+            bridge_f_rx: _,
+            bridge_outer_error_rx: _,
+            bridge_ab_rx: _,
+            bridge_query_rx: _,
+            bridge_eval_rx: _,
+            nested_endoscaling_step_rxs: _,
+            nested_endoscalar_rx: _,
+            nested_points_rx: _,
+            nested_endoscaling_step_commitments,
+            nested_endoscalar_commitment,
+            nested_points_commitment,
+            w: _,
+            y: _,
+            z: _,
+            mu: _,
+            nu: _,
+            mu_prime: _,
+            nu_prime: _,
+            x: _,
+            alpha: _,
+            u: _,
+            pre_beta: _,
+            native_application_commitment,
+            native_preamble_commitment,
+            native_inner_error_commitment,
+            native_outer_error_commitment,
+            native_a_commitment,
+            native_b_commitment,
+            native_query_commitment,
+            native_registry_xy_commitment,
+            native_eval_commitment,
+            native_p_commitment,
+            native_hashes_1_commitment,
+            native_hashes_2_commitment,
+            native_inner_collapse_commitment,
+            native_outer_collapse_commitment,
+            native_compute_v_commitment,
+            bridge_preamble_commitment,
+            bridge_s_prime_commitment,
+            bridge_inner_error_commitment,
+            bridge_f_commitment,
+            bridge_outer_error_commitment,
+            bridge_ab_commitment,
+            bridge_query_commitment,
+            bridge_eval_commitment,
+        } = self;
+
+        check_commitment("native_application", &native_application_commitment.0)?;
+        check_commitment("native_preamble", &native_preamble_commitment.0)?;
+        check_commitment("native_inner_error", &native_inner_error_commitment.0)?;
+        check_commitment("native_outer_error", &native_outer_error_commitment.0)?;
+        check_commitment("native_a", &native_a_commitment.0)?;
+        check_commitment("native_b", &native_b_commitment.0)?;
+        check_commitment("native_query", &native_query_commitment.0)?;
+        check_commitment("native_registry_xy", &native_registry_xy_commitment.0)?;
+        check_commitment("native_eval", &native_eval_commitment.0)?;
+        check_commitment("native_p", &native_p_commitment.0)?;
+        check_commitment("native_hashes_1", &native_hashes_1_commitment.0)?;
+        check_commitment("native_hashes_2", &native_hashes_2_commitment.0)?;
+        check_commitment("native_inner_collapse", &native_inner_collapse_commitment.0)?;
+        check_commitment("native_outer_collapse", &native_outer_collapse_commitment.0)?;
+        check_commitment("native_compute_v", &native_compute_v_commitment.0)?;
+        for commitment in nested_endoscaling_step_commitments {
+            check_commitment("nested_endoscaling_step", &commitment.0)?;
+        }
+        check_commitment("nested_endoscalar", &nested_endoscalar_commitment.0)?;
+        check_commitment("nested_points", &nested_points_commitment.0)?;
+        check_commitment("bridge_preamble", bridge_preamble_commitment)?;
+        check_commitment("bridge_s_prime", bridge_s_prime_commitment)?;
+        check_commitment("bridge_inner_error", bridge_inner_error_commitment)?;
+        check_commitment("bridge_f", bridge_f_commitment)?;
+        check_commitment("bridge_outer_error", &bridge_outer_error_commitment.0)?;
+        check_commitment("bridge_ab", &bridge_ab_commitment.0)?;
+        check_commitment("bridge_query", &bridge_query_commitment.0)?;
+        check_commitment("bridge_eval", &bridge_eval_commitment.0)?;
+
+        Ok(())
+    }
+
     /// Returns the revdot product $c = \text{revdot}(A, B)$.
+    ///
+    /// This is not stored in the proof, so there is no claimed $c$ that could
+    /// be inconsistent with the $A$ and $B$ polynomials, and the `raw_c` claim
+    /// that [`Application::verify`] builds from it holds by construction.
+    /// Tampering with $A$ or $B$ is instead caught by the other revdot claims
+    /// that `verify` checks.
+    ///
+    /// [`Application::verify`]: crate::Application::verify
     pub(crate) fn c(&self) -> C::CircuitField {
         self.native_a_poly.revdot(&self.native_b_poly)
     }
@@ -380,11 +753,15 @@ impl<C: Cycle, R: Rank> Proof<C, R> {
     }
 }
 
-impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> crate::Application<'_, C, R, HEADER_SIZE> {
-    pub(crate) fn trivial_pcd(&self) -> Pcd<C, R, ()> {
-        self.trivial_proof().carry(())
+/// Rejects `point` if it is not on the curve or is the identity.
+fn check_commitment<G: CurveAffine>(commitment: &'static str, point: &G) -> Result<()> {
+    if bool::from(!point.is_on_curve() | point.coordinates().is_none()) {
+        return Err(Error::DegenerateCommitment { commitment });
     }
+    Ok(())
+}
 
+impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> crate::Application<'_, C, R, HEADER_SIZE> {
     pub(crate) fn trivial_proof(&self) -> Proof<C, R> {
         let ones_host = {
             let mut view = sparse::View::<_, R, _>::trace();
@@ -466,3 +843,72 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> crate::Application<'_, C, R, H
         builder.build().expect("trivial proof construction failed")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pasta_curves::group::prime::PrimeCurveAffine;
+    use ragu_circuits::polynomials::ProductionRank;
+    use ragu_pasta::Pasta;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use crate::{ApplicationBuilder, step::internal::trivial::Trivial};
+
+    use super::*;
+
+    #[test]
+    fn proof_eq() -> ragu_core::Result<()> {
+        let pasta = Pasta::baked();
+        let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new().finalize(pasta)?;
+        let mut rng = StdRng::seed_from_u64(1860);
+
+        let (pcd, ()) = app.seed(&mut rng, Trivial::new(), ())?;
+        let (proof, ()) = pcd.into_parts();
+        assert!(proof == proof.clone());
+
+        let mut tweaked = proof.clone();
+        tweaked
+            .native_application_rx
+            .add_assign(&sparse::Polynomial::from_coeffs(vec![
+                <Pasta as Cycle>::CircuitField::ONE,
+            ]));
+        assert!(proof != tweaked);
+
+        let mut tweaked = proof.clone();
+        tweaked.z += <Pasta as Cycle>::CircuitField::ONE;
+        assert!(proof != tweaked);
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_commitments_rejects_identity() -> Result<()> {
+        let pasta = Pasta::baked();
+        let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new().finalize(pasta)?;
+        let mut rng = StdRng::seed_from_u64(1913);
+
+        let (pcd, ()) = app.seed(&mut rng, Trivial::new(), ())?;
+        let (proof, ()) = pcd.into_parts();
+        proof.validate_commitments()?;
+        app.trivial().validate_commitments()?;
+
+        let mut tweaked = proof.clone();
+        tweaked.bridge_f_commitment = <Pasta as Cycle>::NestedCurve::identity();
+        assert!(matches!(
+            tweaked.validate_commitments(),
+            Err(Error::DegenerateCommitment {
+                commitment: "bridge_f"
+            })
+        ));
+
+        let mut tweaked = proof;
+        tweaked.native_p_commitment = Cached(<Pasta as Cycle>::HostCurve::identity());
+        assert!(matches!(
+            tweaked.validate_commitments(),
+            Err(Error::DegenerateCommitment {
+                commitment: "native_p"
+            })
+        ));
+
+        Ok(())
+    }
+}
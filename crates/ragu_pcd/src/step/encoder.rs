@@ -126,10 +126,12 @@ impl<'dr, D: Driver<'dr, F: PrimeField>, H: Header<D::F>, const HEADER_SIZE: usi
         let gadget = H::encode(&mut emulator, witness)?;
         let gadget = padded::for_header::<H, HEADER_SIZE, _>(&mut emulator, gadget)?;
 
-        let mut raw = Vec::with_capacity(HEADER_SIZE);
-        gadget.write(&mut emulator, &mut Pipe::new(dr, &mut raw))?;
+        let mut pipe = Pipe::new(dr, Vec::<Element<'dr, D>>::with_capacity(HEADER_SIZE));
+        gadget.write(&mut emulator, &mut pipe)?;
 
-        Ok(Encoded(EncodedInner::Uniform(FixedVec::try_from(raw)?)))
+        Ok(Encoded(EncodedInner::Uniform(FixedVec::try_from(
+            pipe.into_inner(),
+        )?)))
     }
 }
 
@@ -11,7 +11,7 @@ use ragu_core::{
 };
 use ragu_primitives::{
     Element,
-    vec::{CollectFixed, ConstLen, FixedVec, Len},
+    vec::{CollectFixed, ConstLen, FixedVec, Len, StaticLen},
 };
 
 use super::super::Step;
@@ -22,10 +22,14 @@ pub struct TripleConstLen<const N: usize>;
 
 impl<const N: usize> Len for TripleConstLen<N> {
     fn len() -> usize {
-        N * 3
+        Self::LEN
     }
 }
 
+impl<const N: usize> StaticLen for TripleConstLen<N> {
+    const LEN: usize = N * 3;
+}
+
 pub(crate) struct Adapter<C, S, R, const HEADER_SIZE: usize> {
     step: S,
     _marker: PhantomData<(C, R)>,
@@ -38,6 +42,10 @@ impl<C: Cycle, S: Step<C>, R: Rank, const HEADER_SIZE: usize> Adapter<C, S, R, H
             _marker: PhantomData,
         }
     }
+
+    pub fn into_step(self) -> S {
+        self.step
+    }
 }
 
 impl<C: Cycle, S: Step<C>, R: Rank, const HEADER_SIZE: usize> Circuit<C::CircuitField>
@@ -1,10 +1,12 @@
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 
 use ff::{Field, PrimeField};
 use ragu_core::{
     Result,
-    drivers::Driver,
+    drivers::{Driver, emulator::Emulator},
     gadgets::{Bound, Gadget, GadgetKind, Kind},
+    maybe::Maybe,
 };
 use ragu_primitives::{
     Element, GadgetExt, WithSuffix,
@@ -48,6 +50,22 @@ pub(crate) fn for_header<
     })
 }
 
+/// Serializes `data` into the padded `HEADER_SIZE` encoding of header `H`,
+/// including the header suffix.
+pub(crate) fn encode<F: PrimeField, H: Header<F>, const HEADER_SIZE: usize>(
+    data: H::Data,
+) -> Result<Vec<F>> {
+    Emulator::emulate_wireless(data, |dr, data| {
+        let gadget = H::encode(dr, data)?;
+        let padded = for_header::<H, HEADER_SIZE, _>(dr, gadget)?;
+
+        let mut elements = Vec::with_capacity(HEADER_SIZE);
+        padded.write(dr, &mut elements)?;
+
+        Ok(elements.into_iter().map(|e| *e.value().take()).collect())
+    })
+}
+
 /// Inner gadget that writes the header gadget followed by zero padding up to
 /// `HEADER_SIZE - 1` elements (reserving space for the suffix).
 #[derive(Gadget)]
@@ -5,14 +5,17 @@ pub(crate) mod internal;
 
 pub use encoder::Encoded;
 use ragu_arithmetic::Cycle;
-use ragu_circuits::registry::CircuitIndex;
+use ragu_circuits::{polynomials::Rank, registry::CircuitIndex};
 use ragu_core::{
     Result,
     drivers::{Driver, DriverValue},
 };
 
 use super::header::Header;
-use crate::internal::native::InternalCircuitIndex;
+use crate::{
+    Proof,
+    internal::native::{InternalCircuitIndex, MAX_CIRCUITS, total_circuit_counts},
+};
 
 #[derive(Copy, Clone)]
 #[repr(usize)]
@@ -39,11 +42,24 @@ pub(crate) const NUM_INTERNAL_STEPS: usize = 2;
 /// All steps added to an application have a unique index and must be inserted
 /// sequentially so that their location (and other metadata) can be identified
 /// during proof generation and at other times.
+///
+/// Application steps are numbered from `0` in a range separate from the
+/// internal steps of Ragu, so no value passed to [`Index::new`] is reserved;
+/// any value up to [`Index::MAX_USER`] can be used. Internal steps are
+/// placed before all application steps in the registry, after the internal
+/// circuits.
 pub struct Index {
     index: StepIndex,
 }
 
 impl Index {
+    /// The largest application step index that can be addressed by a
+    /// [`CircuitIndex`].
+    ///
+    /// In practice the number of steps is bounded much further by the
+    /// [`Rank`] of the application, which limits the number of circuits.
+    pub const MAX_USER: usize = MAX_CIRCUITS - NUM_INTERNAL_STEPS - InternalCircuitIndex::NUM - 1;
+
     /// Creates a new application-defined [`Step`] index.
     pub const fn new(value: usize) -> Self {
         Index {
@@ -51,6 +67,11 @@ impl Index {
         }
     }
 
+    /// Returns `true` if this is the index of an internal step of Ragu.
+    pub const fn is_internal(&self) -> bool {
+        matches!(self.index, StepIndex::Internal(_))
+    }
+
     /// Returns the circuit index for this step.
     ///
     /// Circuits are registered in the following order: internal circuits,
@@ -58,14 +79,22 @@ impl Index {
     ///
     /// Pass the known number of application steps to validate and compute the
     /// final index of this step. Returns an error if an application step index
-    /// exceeds the number of registered steps.
+    /// exceeds the number of registered steps, or
+    /// [`Error::CircuitIndexOverflow`](ragu_core::Error::CircuitIndexOverflow)
+    /// if the computed index does not fit within the registry domain.
     pub(crate) fn circuit_index(&self, num_application_steps: usize) -> Result<CircuitIndex> {
-        match self.index {
+        let (_, log2_circuits) = total_circuit_counts(num_application_steps)?;
+        let domain_size = 1usize
+            .checked_shl(log2_circuits)
+            .ok_or(ragu_core::Error::CircuitIndexOverflow { limit: usize::MAX })?;
+        let overflow = ragu_core::Error::CircuitIndexOverflow { limit: domain_size };
+
+        let index = match self.index {
             StepIndex::Internal(i) => {
                 // Internal steps come after internal circuits
-                Ok(CircuitIndex::from_u32(
-                    InternalCircuitIndex::NUM as u32 + i as u32,
-                ))
+                InternalCircuitIndex::NUM
+                    .checked_add(i as usize)
+                    .ok_or(overflow)?
             }
             StepIndex::Application(i) => {
                 if i >= num_application_steps {
@@ -74,11 +103,17 @@ impl Index {
                         ));
                 }
 
-                Ok(CircuitIndex::new(
-                    NUM_INTERNAL_STEPS + InternalCircuitIndex::NUM + i,
-                ))
+                (NUM_INTERNAL_STEPS + InternalCircuitIndex::NUM)
+                    .checked_add(i)
+                    .ok_or(overflow)?
             }
+        };
+
+        if index >= domain_size {
+            return Err(ragu_core::Error::CircuitIndexOverflow { limit: domain_size });
         }
+
+        Ok(CircuitIndex::new(index))
     }
 
     /// Creates a new internal-defined [`Step`] index. Only called internally by
@@ -90,11 +125,9 @@ impl Index {
     }
 
     /// Called during application step registration to assert the appropriate
-    /// next sequential index.
-    ///
-    /// ## Panics
-    ///
-    /// Panics if called on an internal step.
+    /// next sequential index. This also prevents two steps from being placed
+    /// at the same [`CircuitIndex`], and internal steps from being registered
+    /// as application steps.
     pub(crate) fn assert_index(&self, expect_id: usize) -> Result<()> {
         match self.index {
             StepIndex::Application(i) => {
@@ -106,7 +139,7 @@ impl Index {
 
                 Ok(())
             }
-            StepIndex::Internal(_) => panic!("step should be application-defined"),
+            StepIndex::Internal(_) => Err(ragu_core::Error::ReservedStepIndex),
         }
     }
 }
@@ -141,17 +174,126 @@ fn test_index_map() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_index_overflow() {
+    // An absurd number of application steps must produce a guarded error
+    // rather than wrapping around.
+    assert!(matches!(
+        Index::new(0).circuit_index(usize::MAX),
+        Err(ragu_core::Error::CircuitIndexOverflow { .. })
+    ));
+    assert!(matches!(
+        Index::new(usize::MAX - 1).circuit_index(usize::MAX),
+        Err(ragu_core::Error::CircuitIndexOverflow { .. })
+    ));
+    assert!(matches!(
+        Index::internal(InternalStepIndex::Trivial).circuit_index(usize::MAX),
+        Err(ragu_core::Error::CircuitIndexOverflow { .. })
+    ));
+    assert!(matches!(
+        total_circuit_counts(usize::MAX),
+        Err(ragu_core::Error::CircuitIndexOverflow { .. })
+    ));
+}
+
+#[test]
+fn test_index_placement_is_unique() -> Result<()> {
+    use alloc::vec::Vec;
+
+    let num_application_steps = 10;
+    let mut placed = Vec::new();
+    let mut place = |index: CircuitIndex| {
+        assert!(!placed.contains(&index), "duplicate placement of {index:?}");
+        placed.push(index);
+    };
+
+    // Internal circuits, internal steps and application steps must never
+    // share a position in the registry.
+    for i in 0..InternalCircuitIndex::NUM {
+        place(CircuitIndex::new(i));
+    }
+    for index in [InternalStepIndex::Rerandomize, InternalStepIndex::Trivial] {
+        place(Index::internal(index).circuit_index(num_application_steps)?);
+    }
+    for i in 0..num_application_steps {
+        place(Index::new(i).circuit_index(num_application_steps)?);
+    }
+
+    // A repeated index is rejected at registration.
+    assert!(matches!(
+        Index::new(0).assert_index(1),
+        Err(ragu_core::Error::Initialization(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_reserved_index() -> Result<()> {
+    use ragu_circuits::polynomials::ProductionRank;
+    use ragu_pasta::Pasta;
+
+    assert!(!Index::new(0).is_internal());
+    assert!(Index::internal(InternalStepIndex::Trivial).is_internal());
+
+    // The largest application index is addressable, and the next is not.
+    assert_eq!(
+        Index::new(Index::MAX_USER).circuit_index(Index::MAX_USER + 1)?,
+        CircuitIndex::new(MAX_CIRCUITS - 1)
+    );
+    assert!(
+        Index::new(Index::MAX_USER + 1)
+            .circuit_index(Index::MAX_USER + 2)
+            .is_err()
+    );
+
+    // Internal steps cannot be registered as application steps.
+    let result = crate::ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(internal::trivial::Trivial::new());
+    assert!(matches!(result, Err(ragu_core::Error::ReservedStepIndex)));
+
+    Ok(())
+}
+
 /// Represents a node in the computational graph (or the proof-carrying data
 /// tree) that represents the merging of two pieces of proof-carrying data.
 ///
 /// See the [Writing Circuits](https://tachyon.z.cash/ragu/guide/writing_circuits.html)
 /// guide for usage patterns and examples.
+///
+/// ## Binding external data
+///
+/// Steps have no separate input for externally-computed commitments. The
+/// application commitment is not absorbed into the transcript directly; it
+/// reaches the transcript through the nested preamble stage, whose layout is
+/// fixed and re-derived in-circuit by the internal hashes circuits. Absorbing
+/// additional points in [`Application::fuse`](crate::Application::fuse) alone
+/// would therefore desynchronize the prover's challenges from the circuits'.
+///
+/// To bind external data to a proof today, encode a digest of that data in
+/// the [`Output`](Step::Output) header. [`Application::verify`](crate::Application::verify)
+/// recomputes the header encoding from the carried data, so substituting
+/// different data causes verification to fail.
+///
+/// The same applies to committing to a step's witness: hash the witness
+/// in-circuit (for example with a [`Sponge`](ragu_primitives::poseidon::Sponge))
+/// and output the digest as part of the header. Ragu does not absorb witness
+/// digests into the transcript itself: witnesses have no canonical encoding,
+/// and a digest absorbed outside the step circuit would not be checked when
+/// the proof is later fused.
 pub trait Step<C: Cycle>: Sized + Send + Sync {
     /// Each unique [`Step`] implementation within a provided context must have
     /// a unique index.
     const INDEX: Index;
 
     /// The witness data needed to construct a proof for this step.
+    ///
+    /// The witness is moved, never cloned, from
+    /// [`Application::fuse`](crate::Application::fuse) into
+    /// [`witness`](Step::witness), so large witnesses can be borrowed rather
+    /// than owned: with `type Witness<'source> = &'source BigData`, the data
+    /// only needs to outlive the call to `fuse`. [`Aux`](Step::Aux) shares
+    /// the `'source` lifetime, so it may also borrow from the witness.
     type Witness<'source>: Send;
 
     /// The "left" header expected during this step.
@@ -167,6 +309,40 @@ pub trait Step<C: Cycle>: Sized + Send + Sync {
     /// used to pipeline witness data to future steps.
     type Aux<'source>: Send;
 
+    /// Checks the witness before any proving work is done for this step.
+    ///
+    /// [`Application::fuse`](crate::Application::fuse) calls this before
+    /// synthesizing the step, so that malformed witnesses can be rejected
+    /// with a meaningful error rather than failing deep in synthesis. The
+    /// default implementation accepts every witness.
+    fn validate_witness(&self, _witness: &Self::Witness<'_>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Whether this step may fuse a proof with itself.
+    ///
+    /// Depending on the application, fusing the same proof as both children
+    /// (as [`Application::fuse_ref`](crate::Application::fuse_ref) allows)
+    /// may be legitimate or a sign of a replayed child. When this returns
+    /// `false`, [`Application::fuse`](crate::Application::fuse) rejects
+    /// children with identical commitments with
+    /// [`Error::SelfFuseNotAllowed`](ragu_core::Error::SelfFuseNotAllowed).
+    /// The default implementation returns `true`.
+    fn allow_self_fuse(&self) -> bool {
+        true
+    }
+
+    /// Inspects the proof and auxiliary data produced by this step.
+    ///
+    /// [`Application::fuse`](crate::Application::fuse) calls this once the
+    /// proof has been built, before returning it, so that steps can log or
+    /// validate the result or derive data for downstream systems. The proof
+    /// is only borrowed and cannot be altered; returning an error causes
+    /// `fuse` to fail. The default implementation does nothing.
+    fn post_fuse<R: Rank>(&self, _proof: &Proof<C, R>, _aux: &Self::Aux<'_>) -> Result<()> {
+        Ok(())
+    }
+
     /// The main synthesis method that checks the validity of this merging step.
     ///
     /// Returns the encoded headers (left, right, output), the data to be
@@ -1,24 +1,36 @@
-//! This module provides the [`Application::verify`] method implementation.
+//! This module provides the [`Application::verify`] method implementation, along
+//! with the [`Pcd::verify_with`] convenience wrapper.
 
+use alloc::{borrow::Cow, vec::Vec};
 use core::iter::once;
 
-use ff::Field;
+use ff::{Field, PrimeField};
+use maybe_rayon::prelude::*;
 use ragu_arithmetic::Cycle;
 use ragu_circuits::{
+    CircuitExt,
     polynomials::{Rank, sparse},
     registry::CircuitIndex,
 };
 use ragu_core::{Result, drivers::emulator::Emulator, maybe::Maybe};
-use ragu_primitives::Element;
+use ragu_primitives::{Element, GadgetExt, Point, vec::CollectFixed};
 use rand::CryptoRng;
 
 use crate::{
-    Application, Pcd, Proof,
+    Application, Pcd, Proof, RAGU_TAG,
     header::Header,
     internal::{
         claims,
-        native::{claims as native_claims, stages::preamble::ProofInputs},
+        native::{
+            claims as native_claims,
+            stages::preamble::{ChildHeaders, ProofInputs},
+        },
         nested::claims as nested_claims,
+        transcript::Transcript,
+    },
+    step::{
+        Step,
+        internal::{adapter::Adapter, padded},
     },
 };
 
@@ -29,6 +41,21 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
     /// any check fails (e.g., invalid circuit ID, header size mismatch,
     /// corrupted commitments or evaluations), or `Err` if an internal
     /// computation error occurs.
+    ///
+    /// The Fiat-Shamir transcript is recomputed inside the internal circuits,
+    /// which are fixed when the [`Application`] is finalized, so no
+    /// caller-provided prefix can be absorbed into it. Context that a proof
+    /// must be bound to, such as the transcript of an enclosing protocol,
+    /// belongs in the [`Header`] data instead: a step can take the context as
+    /// its witness and output it (or a digest of it) in its header, which
+    /// `verify` then checks against the proof.
+    ///
+    /// Proofs are verified in uncompressed (split-accumulation) form, in which
+    /// the proof carries its polynomials and their claims are checked directly
+    /// by polynomial arithmetic. No multi-scalar multiplication is performed,
+    /// so there is no opening check for an alternative MSM implementation to
+    /// accelerate; the MSM-dominated decision procedure belongs to compressed
+    /// proofs, which are not yet implemented.
     pub fn verify<RNG: CryptoRng, H: Header<C::CircuitField>>(
         &self,
         pcd: &Pcd<C, R, H>,
@@ -39,21 +66,7 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
         let y = C::CircuitField::random(&mut rng);
         let z = C::CircuitField::random(&mut rng);
 
-        // Validate that the application circuit_id is within the registry domain.
-        // (Internal circuit IDs are constants and don't need this check.)
-        if !self
-            .native_registry
-            .circuit_in_domain(pcd.proof().circuit_id())
-        {
-            return Ok(false);
-        }
-
-        // Validate that the `left_header` and `right_header` lengths match
-        // `HEADER_SIZE`. Alternatively, the `Proof` structure could be
-        // parameterized on the `HEADER_SIZE`, but this appeared to be simpler.
-        if pcd.proof().left_header().len() != HEADER_SIZE
-            || pcd.proof().right_header().len() != HEADER_SIZE
-        {
+        if !self.is_well_formed(pcd.proof()) {
             return Ok(false);
         }
 
@@ -91,12 +104,14 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
                 unified_ky,
             };
 
-            native::ky_values(&ky_source)
-                .zip(builder.a.iter().zip(builder.b.iter()))
-                .all(|(ky, (a, b))| a.revdot(b) == ky)
+            check_revdot_claims(&builder.a, &builder.b, native::ky_values(&ky_source))
         };
 
-        // Check all nested revdot claims.
+        // Check all nested revdot claims. These include a circuit check for
+        // each endoscaling step, which constrain the accumulation of the
+        // child commitments into the P commitment. The accumulated points
+        // (the children's commitments and the s' stage commitments) are not
+        // retained in the proof, so this cannot be recomputed out of circuit.
         let nested_revdot_claims = {
             let nested_source = nested::SingleProofSource { proof: pcd.proof() };
             let y_nested = C::ScalarField::random(&mut rng);
@@ -106,9 +121,11 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
             nested_claims::build(&nested_source, &mut nested_builder)?;
 
             let ky_source = nested::SingleProofKySource::<C::ScalarField>::new();
-            nested::ky_values(&ky_source)
-                .zip(nested_builder.a.iter().zip(nested_builder.b.iter()))
-                .all(|(ky, (a, b))| a.revdot(b) == ky)
+            check_revdot_claims(
+                &nested_builder.a,
+                &nested_builder.b,
+                nested::ky_values(&ky_source),
+            )
         };
 
         // Check registry_xy polynomial evaluation at the sampled w.
@@ -127,6 +144,173 @@ impl<C: Cycle, R: Rank, const HEADER_SIZE: usize> Application<'_, C, R, HEADER_S
 
         Ok(native_revdot_claims && nested_revdot_claims && registry_xy_claim)
     }
+
+    /// Checks that some [`Pcd`] is structurally well-formed for the provided
+    /// [`Header`], without checking any of the claims it makes.
+    ///
+    /// This performs only the inexpensive checks of
+    /// [`verify`](Application::verify): that the proof refers to a circuit in
+    /// this [`Application`], that its headers have the expected size, and
+    /// that the carried data can be encoded by `H`. It also re-derives the
+    /// Fiat-Shamir challenges from the proof's bridge commitments and checks
+    /// that they match the challenges the proof carries.
+    ///
+    /// **This is not a soundness check.** A proof that passes
+    /// `verify_structural` may still be invalid; it is intended only as a fast
+    /// sanity check during development.
+    pub fn verify_structural<H: Header<C::CircuitField>>(
+        &self,
+        pcd: &Pcd<C, R, H>,
+    ) -> Result<bool> {
+        if !self.is_well_formed(pcd.proof()) {
+            return Ok(false);
+        }
+
+        if crate::step::internal::padded::encode::<_, H, HEADER_SIZE>(pcd.data().clone()).is_err() {
+            return Ok(false);
+        }
+
+        self.challenges_match(pcd.proof())
+    }
+
+    /// Replays the transcript of [`fuse`](Application::fuse) over the bridge
+    /// commitments of `proof`, and checks that the squeezed challenges equal
+    /// those stored in the proof.
+    fn challenges_match(&self, proof: &Proof<C, R>) -> Result<bool> {
+        let mut dr = Emulator::execute();
+        let mut transcript = Transcript::new(&mut dr, C::circuit_poseidon(self.params), RAGU_TAG)?;
+        Point::constant(&mut dr, proof.bridge_preamble_commitment())?
+            .write(&mut dr, &mut transcript)?;
+        let w = transcript.challenge(&mut dr)?;
+
+        Point::constant(&mut dr, proof.bridge_s_prime_commitment())?
+            .write(&mut dr, &mut transcript)?;
+        let [y, z] = transcript.challenges(&mut dr)?;
+
+        Point::constant(&mut dr, proof.bridge_inner_error_commitment())?
+            .write(&mut dr, &mut transcript)?;
+        let [mu, nu] = transcript.challenges(&mut dr)?;
+
+        Point::constant(&mut dr, proof.bridge_outer_error_commitment())?
+            .write(&mut dr, &mut transcript)?;
+        let [mu_prime, nu_prime] = transcript.challenges(&mut dr)?;
+
+        Point::constant(&mut dr, proof.bridge_ab_commitment())?.write(&mut dr, &mut transcript)?;
+        let x = transcript.challenge(&mut dr)?;
+
+        Point::constant(&mut dr, proof.bridge_query_commitment())?
+            .write(&mut dr, &mut transcript)?;
+        let alpha = transcript.challenge(&mut dr)?;
+
+        Point::constant(&mut dr, proof.bridge_f_commitment())?.write(&mut dr, &mut transcript)?;
+        let u = transcript.challenge(&mut dr)?;
+
+        Point::constant(&mut dr, proof.bridge_eval_commitment())?
+            .write(&mut dr, &mut transcript)?;
+        let pre_beta = transcript.challenge(&mut dr)?;
+
+        Ok([
+            (w, proof.w()),
+            (y, proof.y()),
+            (z, proof.z()),
+            (mu, proof.mu()),
+            (nu, proof.nu()),
+            (mu_prime, proof.mu_prime()),
+            (nu_prime, proof.nu_prime()),
+            (x, proof.x()),
+            (alpha, proof.alpha()),
+            (u, proof.u()),
+            (pre_beta, proof.pre_beta()),
+        ]
+        .into_iter()
+        .all(|(derived, stored)| *derived.value().take() == stored))
+    }
+
+    /// Checks only the application circuit of `step`, without any of the
+    /// recursion machinery of [`fuse`](Application::fuse).
+    ///
+    /// The step is traced on the provided witness and child header data, and
+    /// the resulting $r(X)$ is checked against the step's circuit in the
+    /// registry with the revdot identity at random $y, z$. This isolates
+    /// failures in [`Step::witness`] from failures elsewhere in the protocol
+    /// and is intended as a debugging aid; it does not produce or check a
+    /// proof.
+    ///
+    /// Returns an error if the step was not registered with this
+    /// [`Application`] or if tracing the step fails.
+    pub fn verify_application_circuit<'source, RNG: CryptoRng, S: Step<C>>(
+        &self,
+        step: S,
+        witness: S::Witness<'source>,
+        left: <S::Left as Header<C::CircuitField>>::Data,
+        right: <S::Right as Header<C::CircuitField>>::Data,
+        mut rng: RNG,
+    ) -> Result<bool> {
+        let circuit_id = self.circuit_index_of::<S>()?;
+        let (trace, aux) = Adapter::<C, S, R, HEADER_SIZE>::new(step)
+            .trace((left, right, witness))?
+            .into_parts();
+        let ((left_header, right_header), output_data, _) = aux;
+        let output_header = padded::encode::<_, S::Output, HEADER_SIZE>(output_data)?;
+        let rx = self
+            .native_registry
+            .assemble(&trace, circuit_id, &mut rng)?;
+
+        let y = C::CircuitField::random(&mut rng);
+        let z = C::CircuitField::random(&mut rng);
+
+        let ky = {
+            let mut dr = Emulator::execute();
+            let mut header = |values: &[C::CircuitField]| {
+                values
+                    .iter()
+                    .map(|v| Element::constant(&mut dr, *v))
+                    .collect_fixed()
+            };
+            let children = ChildHeaders {
+                left: header(&left_header)?,
+                right: header(&right_header)?,
+            };
+            let output_header = header(&output_header)?;
+            let y = Element::constant(&mut dr, y);
+            *children
+                .application_ky(&mut dr, &output_header, &y)?
+                .value()
+                .take()
+        };
+
+        let mut builder = claims::Builder::new(&self.native_registry, y, z);
+        builder.circuit_impl(circuit_id, &rx);
+
+        Ok(builder.claims().all(|(a, b)| a.revdot(b) == ky))
+    }
+
+    fn is_well_formed(&self, proof: &Proof<C, R>) -> bool {
+        // Validate that the application circuit_id is within the registry domain.
+        // (Internal circuit IDs are constants and don't need this check.)
+        if !self.native_registry.circuit_in_domain(proof.circuit_id()) {
+            return false;
+        }
+
+        // Validate that the `left_header` and `right_header` lengths match
+        // `HEADER_SIZE`. Alternatively, the `Proof` structure could be
+        // parameterized on the `HEADER_SIZE`, but this appeared to be simpler.
+        proof.left_header().len() == HEADER_SIZE && proof.right_header().len() == HEADER_SIZE
+    }
+}
+
+impl<C: Cycle, R: Rank, H: Header<C::CircuitField>> Pcd<C, R, H> {
+    /// Verifies this [`Pcd`] using the provided [`Application`].
+    ///
+    /// This is a convenience wrapper that delegates to
+    /// [`Application::verify`], and produces identical results.
+    pub fn verify_with<RNG: CryptoRng, const HEADER_SIZE: usize>(
+        &self,
+        app: &Application<'_, C, R, HEADER_SIZE>,
+        rng: RNG,
+    ) -> Result<bool> {
+        app.verify(self, rng)
+    }
 }
 
 mod native {
@@ -237,15 +421,35 @@ mod nested {
     }
 }
 
+/// Checks that `revdot(a[i], b[i]) == ky[i]` holds for every accumulated
+/// claim.
+///
+/// The claims are independent, so with the `multicore` feature they are
+/// checked in parallel. The result does not depend on the order in which the
+/// claims are evaluated. As with [`Iterator::zip`], claims beyond the shortest
+/// of the three inputs are ignored.
+fn check_revdot_claims<F: PrimeField, R: Rank>(
+    a: &[Cow<'_, sparse::Polynomial<F, R>>],
+    b: &[Cow<'_, sparse::Polynomial<F, R>>],
+    ky: impl Iterator<Item = F>,
+) -> bool {
+    let ky: Vec<F> = ky.take(a.len()).collect();
+
+    a.par_iter()
+        .zip(b.par_iter())
+        .zip(ky.par_iter())
+        .all(|((a, b), ky)| a.revdot(b) == *ky)
+}
+
 #[cfg(test)]
 mod tests {
     use ff::Field;
     use ragu_circuits::{polynomials::ProductionRank, registry::CircuitIndex};
-    use ragu_pasta::Pasta;
+    use ragu_pasta::{Fp, Pasta};
     use rand::{SeedableRng, rngs::StdRng};
 
     use super::*;
-    use crate::ApplicationBuilder;
+    use crate::{ApplicationBuilder, step::internal::trivial};
 
     type TestR = ProductionRank;
     const HEADER_SIZE: usize = 4;
@@ -304,4 +508,99 @@ mod tests {
         let result = app.verify(&pcd, &mut rng).expect("verify should not error");
         assert!(!result, "verify should reject wrong right_header size");
     }
+
+    #[test]
+    fn verify_structural_is_not_a_soundness_check() {
+        let app = create_test_app();
+        let mut rng = StdRng::seed_from_u64(1856);
+
+        let (pcd, ()) = app
+            .seed(&mut rng, crate::step::internal::trivial::Trivial::new(), ())
+            .expect("seed should succeed");
+        assert!(app.verify_structural(&pcd).unwrap());
+        assert!(app.verify(&pcd, &mut rng).unwrap());
+
+        // Corrupt the registry_xy polynomial, leaving the structure intact.
+        let (mut proof, ()) = pcd.into_parts();
+        proof.native_registry_xy_poly = sparse::Polynomial::new();
+        let pcd = proof.carry::<()>(());
+
+        assert!(app.verify_structural(&pcd).unwrap());
+        assert!(!app.verify(&pcd, &mut rng).unwrap());
+
+        // Structural defects are still caught.
+        let (mut proof, ()) = pcd.into_parts();
+        let mut tampered = proof.clone();
+        proof.left_header.pop();
+        assert!(!app.verify_structural(&proof.carry::<()>(())).unwrap());
+
+        // So are challenges that do not follow from the bridge commitments.
+        tampered.x += Fp::ONE;
+        assert!(!app.verify_structural(&tampered.carry::<()>(())).unwrap());
+    }
+
+    #[test]
+    fn verify_rejects_tampered_ab() -> Result<()> {
+        let app = create_test_app();
+        let mut rng = StdRng::seed_from_u64(1871);
+
+        let (pcd, ()) = app.seed(&mut rng, trivial::Trivial::new(), ())?;
+        assert!(app.verify(&pcd, &mut rng)?);
+        let (proof, ()) = pcd.into_parts();
+
+        // Changing B changes the derived c = revdot(A, B).
+        let mut tampered = proof.clone();
+        tampered
+            .native_b_poly
+            .add_assign(&sparse::Polynomial::from_coeffs(alloc::vec![Fp::ONE]));
+        assert_ne!(tampered.c(), proof.c());
+        assert!(!app.verify(&tampered.carry::<()>(()), &mut rng)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn verify_rejects_tampered_endoscaling_step() -> Result<()> {
+        let app = create_test_app();
+        let mut rng = StdRng::seed_from_u64(1884);
+
+        let (pcd, ()) = app.seed(&mut rng, trivial::Trivial::new(), ())?;
+        let (mut proof, ()) = pcd.into_parts();
+        proof.nested_endoscaling_step_rxs[0].add_assign(&sparse::Polynomial::from_coeffs(
+            alloc::vec![<Pasta as Cycle>::ScalarField::ONE],
+        ));
+
+        assert!(!app.verify(&proof.carry::<()>(()), &mut rng)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_revdot_claims_matches_serial() {
+        let mut rng = StdRng::seed_from_u64(1899);
+
+        let a: Vec<_> = (0..8)
+            .map(|_| Cow::Owned(sparse::Polynomial::<Fp, TestR>::random(&mut rng)))
+            .collect();
+        let b: Vec<_> = (0..8)
+            .map(|_| Cow::Owned(sparse::Polynomial::<Fp, TestR>::random(&mut rng)))
+            .collect();
+        let ky: Vec<Fp> = a.iter().zip(b.iter()).map(|(a, b)| a.revdot(b)).collect();
+
+        assert!(check_revdot_claims(&a, &b, ky.iter().copied()));
+
+        // A single failing claim is detected regardless of its position.
+        for i in 0..ky.len() {
+            let mut tampered = ky.clone();
+            tampered[i] += Fp::ONE;
+            assert!(!check_revdot_claims(&a, &b, tampered.into_iter()));
+        }
+
+        // Excess `ky` values are ignored, as they are by `Iterator::zip`.
+        assert!(check_revdot_claims(
+            &a,
+            &b,
+            ky.iter().copied().chain(core::iter::repeat(Fp::ONE))
+        ));
+    }
 }
@@ -0,0 +1,55 @@
+use ragu_arithmetic::Cycle;
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_core::Result;
+use ragu_pasta::{Fp, Pasta};
+use ragu_pcd::{ApplicationBuilder, BlindSource};
+use ragu_testing::pcd::simple::WitnessValue;
+use rand::{SeedableRng, rngs::StdRng};
+
+// Entropy source that hands out generators seeded from a fixed sequence, in
+// place of e.g. a hardware RNG.
+struct FixedSeeds {
+    seeds: Vec<[u8; 32]>,
+    used: usize,
+}
+
+impl FixedSeeds {
+    fn new(count: u8) -> Self {
+        FixedSeeds {
+            seeds: (0..count).map(|i| [i; 32]).collect(),
+            used: 0,
+        }
+    }
+}
+
+impl BlindSource for FixedSeeds {
+    type Rng<'a> = StdRng;
+
+    fn rng(&mut self) -> StdRng {
+        let seed = self.seeds[self.used];
+        self.used += 1;
+        StdRng::from_seed(seed)
+    }
+}
+
+#[test]
+fn custom_blind_source() -> Result<()> {
+    let pasta = Pasta::baked();
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessValue)?
+        .finalize(pasta)?;
+
+    let mut blinds = FixedSeeds::new(3);
+
+    let (pcd, ()) = app.seed(&mut blinds, WitnessValue, Fp::from(7u64))?;
+    assert_eq!(blinds.used, 1);
+    assert!(app.verify(&pcd, StdRng::seed_from_u64(1850))?);
+
+    // Seeds the cached trivial proof, then fuses.
+    let pcd = app.rerandomize(pcd, &mut blinds)?;
+    assert_eq!(blinds.used, 3);
+    assert!(app.verify(&pcd, StdRng::seed_from_u64(1851))?);
+    assert_eq!(pcd.data(), &Fp::from(7u64));
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_core::{
+    Result,
+    drivers::{Driver, DriverValue},
+    maybe::Maybe,
+};
+use ragu_pasta::{Fp, Pasta};
+use ragu_pcd::{
+    ApplicationBuilder,
+    step::{Encoded, Index, Step},
+};
+use ragu_testing::pcd::simple::Value;
+use rand::{SeedableRng, rngs::StdRng};
+
+static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+// A large witness that counts how often it is cloned.
+struct BigData {
+    values: Vec<u64>,
+}
+
+impl Clone for BigData {
+    fn clone(&self) -> Self {
+        CLONES.fetch_add(1, Ordering::SeqCst);
+        BigData {
+            values: self.values.clone(),
+        }
+    }
+}
+
+// Local: borrows its witness and hands the same borrow back as its aux.
+struct SumBlock;
+
+impl Step<Pasta> for SumBlock {
+    const INDEX: Index = Index::new(0);
+    type Witness<'source> = &'source BigData;
+    type Aux<'source> = &'source BigData;
+    type Left = ();
+    type Right = ();
+    type Output = Value;
+
+    fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = Fp>, const HEADER_SIZE: usize>(
+        &self,
+        dr: &mut D,
+        witness: DriverValue<D, &'source BigData>,
+        left: DriverValue<D, ()>,
+        right: DriverValue<D, ()>,
+    ) -> Result<(
+        (
+            Encoded<'dr, D, Self::Left, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Right, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Output, HEADER_SIZE>,
+        ),
+        DriverValue<D, Fp>,
+        DriverValue<D, Self::Aux<'source>>,
+    )> {
+        let sum = witness
+            .as_ref()
+            .map(|data| Fp::from(data.values.iter().sum::<u64>()));
+        let left = Encoded::new(dr, left)?;
+        let right = Encoded::new(dr, right)?;
+        let output = Encoded::new(dr, sum.clone())?;
+        Ok(((left, right, output), sum, witness))
+    }
+}
+
+#[test]
+fn borrowed_witness_is_not_cloned() {
+    let pasta = Pasta::baked();
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(SumBlock)
+        .unwrap()
+        .finalize(pasta)
+        .unwrap();
+
+    let mut rng = StdRng::seed_from_u64(1929);
+
+    let data = BigData {
+        values: (0..1 << 16).collect(),
+    };
+    let (pcd, aux) = app.seed(&mut rng, SumBlock, &data).unwrap();
+
+    assert_eq!(CLONES.load(Ordering::SeqCst), 0);
+    assert!(core::ptr::eq(aux, &data));
+    assert_eq!(pcd.data(), &Fp::from((0..1u64 << 16).sum::<u64>()));
+    assert!(app.verify(&pcd, &mut rng).unwrap());
+}
@@ -0,0 +1,141 @@
+//! Recursion depth is not tracked by Ragu itself; applications that need it
+//! carry it in their header and enforce it in their steps, as done here with
+//! a [`Value`] header holding the depth of the tree rooted at the proof.
+
+use ff::Field;
+use ragu_arithmetic::Cycle;
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_core::{
+    Result,
+    drivers::{Driver, DriverValue},
+    maybe::Maybe,
+};
+use ragu_pasta::{Fp, Pasta};
+use ragu_pcd::{
+    ApplicationBuilder,
+    header::Header,
+    step::{Encoded, Index, Step},
+};
+use ragu_primitives::Element;
+use ragu_testing::pcd::simple::Value;
+use rand::{SeedableRng, rngs::StdRng};
+
+// Local: unlike WitnessValue, fixes a leaf's depth to zero in-circuit.
+struct Leaf;
+impl Step<Pasta> for Leaf {
+    const INDEX: Index = Index::new(0);
+    type Witness<'source> = ();
+    type Aux<'source> = ();
+    type Left = ();
+    type Right = ();
+    type Output = Value;
+    fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = Fp>, const HEADER_SIZE: usize>(
+        &self,
+        dr: &mut D,
+        _: DriverValue<D, Self::Witness<'source>>,
+        left: DriverValue<D, ()>,
+        right: DriverValue<D, ()>,
+    ) -> Result<(
+        (
+            Encoded<'dr, D, Self::Left, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Right, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Output, HEADER_SIZE>,
+        ),
+        DriverValue<D, <Self::Output as Header<Fp>>::Data>,
+        DriverValue<D, Self::Aux<'source>>,
+    )> {
+        let left = Encoded::new(dr, left)?;
+        let right = Encoded::new(dr, right)?;
+        let output = Encoded::from_gadget(Element::zero(dr));
+        Ok(((left, right, output), D::just(|| Fp::ZERO), D::unit()))
+    }
+}
+
+// Local: joins two subtrees of equal depth, one level higher.
+struct Node;
+impl Step<Pasta> for Node {
+    const INDEX: Index = Index::new(1);
+    type Witness<'source> = ();
+    type Aux<'source> = ();
+    type Left = Value;
+    type Right = Value;
+    type Output = Value;
+    fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = Fp>, const HEADER_SIZE: usize>(
+        &self,
+        dr: &mut D,
+        _: DriverValue<D, Self::Witness<'source>>,
+        left: DriverValue<D, Fp>,
+        right: DriverValue<D, Fp>,
+    ) -> Result<(
+        (
+            Encoded<'dr, D, Self::Left, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Right, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Output, HEADER_SIZE>,
+        ),
+        DriverValue<D, <Self::Output as Header<Fp>>::Data>,
+        DriverValue<D, Self::Aux<'source>>,
+    )> {
+        let left = Encoded::new(dr, left)?;
+        let right = Encoded::new(dr, right)?;
+        left.as_gadget()
+            .sub(dr, right.as_gadget())
+            .enforce_zero(dr)?;
+
+        let depth = left.as_gadget().add(dr, &Element::one());
+        let data = depth.value().map(|v| *v);
+        Ok(((left, right, Encoded::from_gadget(depth)), data, D::unit()))
+    }
+}
+
+#[test]
+fn balanced_tree_depth() -> Result<()> {
+    let pasta = Pasta::baked();
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(Leaf)?
+        .register(Node)?
+        .finalize(pasta)?;
+
+    let mut rng = StdRng::seed_from_u64(1853);
+
+    let mut level = Vec::new();
+    for _ in 0..4 {
+        level.push(app.seed(&mut rng, Leaf, ())?.0);
+    }
+    while level.len() > 1 {
+        let mut next = Vec::new();
+        let mut pcds = level.into_iter();
+        while let (Some(left), Some(right)) = (pcds.next(), pcds.next()) {
+            next.push(app.fuse(&mut rng, Node, (), left, right)?.0);
+        }
+        level = next;
+    }
+
+    let root = level.pop().unwrap();
+    assert!(app.verify(&root, &mut rng)?);
+    assert_eq!(root.data(), &Fp::from(2u64));
+
+    Ok(())
+}
+
+#[test]
+fn unbalanced_join_is_rejected() -> Result<()> {
+    let pasta = Pasta::baked();
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(Leaf)?
+        .register(Node)?
+        .finalize(pasta)?;
+
+    let mut rng = StdRng::seed_from_u64(1854);
+
+    let (a, _) = app.seed(&mut rng, Leaf, ())?;
+    let (b, _) = app.seed(&mut rng, Leaf, ())?;
+    let (c, _) = app.seed(&mut rng, Leaf, ())?;
+    let (ab, _) = app.fuse(&mut rng, Node, (), a, b)?;
+
+    // Depths 1 and 0 cannot be joined by `Node`. Fusing does not check the
+    // step's constraints, so the proof is produced but fails verification.
+    let (pcd, _) = app.fuse(&mut rng, Node, (), ab, c)?;
+    assert!(!app.verify(&pcd, &mut rng)?);
+
+    Ok(())
+}
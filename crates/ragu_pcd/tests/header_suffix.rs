@@ -0,0 +1,127 @@
+use ragu_arithmetic::Cycle;
+use ragu_circuits::{polynomials::ProductionRank, registry::CircuitIndex};
+use ragu_core::{
+    Error, Result,
+    drivers::{Driver, DriverValue},
+};
+use ragu_pasta::{Fp, Pasta};
+use ragu_pcd::{
+    ApplicationBuilder,
+    header::Header,
+    step::{Encoded, Index, Step},
+};
+use ragu_testing::pcd::nontrivial::{Hash2, InternalNode, LeafNode, WitnessLeaf};
+use rand::{SeedableRng, rngs::StdRng};
+
+// Local: no shared step shares WitnessLeaf's index with a different output header.
+struct ProduceInternal;
+
+impl Step<Pasta> for ProduceInternal {
+    const INDEX: Index = Index::new(0);
+    type Witness<'source> = Fp;
+    type Aux<'source> = ();
+    type Left = ();
+    type Right = ();
+    type Output = InternalNode;
+
+    fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = Fp>, const HEADER_SIZE: usize>(
+        &self,
+        dr: &mut D,
+        witness: DriverValue<D, Self::Witness<'source>>,
+        left: DriverValue<D, ()>,
+        right: DriverValue<D, ()>,
+    ) -> Result<(
+        (
+            Encoded<'dr, D, Self::Left, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Right, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Output, HEADER_SIZE>,
+        ),
+        DriverValue<D, <Self::Output as Header<Fp>>::Data>,
+        DriverValue<D, Self::Aux<'source>>,
+    )> {
+        let left = Encoded::new(dr, left)?;
+        let right = Encoded::new(dr, right)?;
+        let output = Encoded::new(dr, witness.clone())?;
+        Ok(((left, right, output), witness, D::unit()))
+    }
+}
+
+#[test]
+fn fuse_rejects_mismatched_child_suffix() -> Result<()> {
+    let pasta = Pasta::baked();
+    let poseidon_params = Pasta::circuit_poseidon(pasta);
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessLeaf { poseidon_params })?
+        .register(Hash2 { poseidon_params })?
+        .finalize(pasta)?;
+    let mut rng = StdRng::seed_from_u64(1880);
+
+    let (a, ()) = app.seed(&mut rng, WitnessLeaf { poseidon_params }, Fp::from(1u64))?;
+    let (b, ()) = app.seed(&mut rng, WitnessLeaf { poseidon_params }, Fp::from(2u64))?;
+    let (node, ()) = app.fuse(&mut rng, Hash2 { poseidon_params }, (), a, b)?;
+    let (leaf, ()) = app.seed(&mut rng, WitnessLeaf { poseidon_params }, Fp::from(3u64))?;
+
+    // Carry the InternalNode proof as if it had produced a LeafNode.
+    let (proof, data) = node.into_parts();
+    let node = proof.carry::<LeafNode>(data);
+
+    assert!(matches!(
+        app.fuse(&mut rng, Hash2 { poseidon_params }, (), node, leaf),
+        Err(Error::HeaderSuffixMismatch)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn fuse_rejects_mismatched_rerandomized_child_suffix() -> Result<()> {
+    let pasta = Pasta::baked();
+    let poseidon_params = Pasta::circuit_poseidon(pasta);
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessLeaf { poseidon_params })?
+        .register(Hash2 { poseidon_params })?
+        .finalize(pasta)?;
+    let mut rng = StdRng::seed_from_u64(1881);
+
+    let (a, ()) = app.seed(&mut rng, WitnessLeaf { poseidon_params }, Fp::from(1u64))?;
+    let (b, ()) = app.seed(&mut rng, WitnessLeaf { poseidon_params }, Fp::from(2u64))?;
+    let (node, ()) = app.fuse(&mut rng, Hash2 { poseidon_params }, (), a, b)?;
+    let node = app.rerandomize(node, &mut rng)?;
+    let (leaf, ()) = app.seed(&mut rng, WitnessLeaf { poseidon_params }, Fp::from(3u64))?;
+
+    // The rerandomized proof still carries InternalNode's suffix.
+    let (proof, data) = node.into_parts();
+    let node = proof.carry::<LeafNode>(data);
+
+    assert!(matches!(
+        app.fuse(&mut rng, Hash2 { poseidon_params }, (), node, leaf),
+        Err(Error::HeaderSuffixMismatch)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn circuit_index_of_registered_steps() -> Result<()> {
+    let pasta = Pasta::baked();
+    let poseidon_params = Pasta::circuit_poseidon(pasta);
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessLeaf { poseidon_params })?
+        .finalize(pasta)?;
+
+    let index = app.circuit_index_of::<WitnessLeaf<'_, Pasta>>()?;
+    assert!(app.circuit_index_of::<Hash2<'_, Pasta>>().is_err());
+    assert!(app.circuit_index_of::<ProduceInternal>().is_err());
+
+    // Application steps are indexed consecutively in registration order.
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessLeaf { poseidon_params })?
+        .register(Hash2 { poseidon_params })?
+        .finalize(pasta)?;
+    assert_eq!(
+        app.circuit_index_of::<Hash2<'_, Pasta>>()?,
+        CircuitIndex::new(usize::from(index) + 1)
+    );
+
+    Ok(())
+}
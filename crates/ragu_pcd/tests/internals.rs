@@ -0,0 +1,59 @@
+//! The accessors exercised here are only reachable when the `internals` feature
+//! is enabled; without it, this file compiles to nothing.
+
+#![cfg(feature = "internals")]
+
+use ragu_arithmetic::Cycle;
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_core::Result;
+use ragu_pasta::{Fp, Pasta};
+use ragu_pcd::{ApplicationBuilder, internals::Internals};
+use ragu_testing::pcd::nontrivial::WitnessLeaf;
+use rand::{SeedableRng, rngs::StdRng};
+
+#[test]
+fn internals_accessors_are_reachable() -> Result<()> {
+    let pasta = Pasta::baked();
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessLeaf {
+            poseidon_params: Pasta::circuit_poseidon(pasta),
+        })?
+        .finalize(pasta)?;
+
+    let mut rng = StdRng::seed_from_u64(1234);
+
+    let (leaf, _) = app.seed(
+        &mut rng,
+        WitnessLeaf {
+            poseidon_params: Pasta::circuit_poseidon(pasta),
+        },
+        Fp::from(42u64),
+    )?;
+
+    let internals: Internals<'_, Pasta, ProductionRank> = leaf.internals();
+    assert_eq!(internals.left_header().len(), 4);
+    assert_eq!(internals.right_header().len(), 4);
+
+    let num_steps = internals.nested_endoscaling_step_rxs().len();
+    assert!(num_steps > 0);
+    assert!(
+        internals
+            .nested_endoscaling_step_commitment(num_steps - 1)
+            .is_some()
+    );
+    assert!(
+        internals
+            .nested_endoscaling_step_commitment(num_steps)
+            .is_none()
+    );
+
+    // Cached commitments are derived from the primary polynomials.
+    assert_eq!(
+        internals
+            .native_application_rx()
+            .commit_to_affine(Pasta::host_generators(pasta)),
+        internals.native_application_commitment()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use std::sync::{Arc, Mutex};
+
+use ff::Field;
+use ragu_circuits::polynomials::{ProductionRank, Rank};
+use ragu_core::{
+    Error, Result,
+    drivers::{Driver, DriverValue},
+};
+use ragu_pasta::{Fp, Pasta};
+use ragu_pcd::{
+    ApplicationBuilder, Proof,
+    header::Header,
+    step::{Encoded, Index, Step},
+};
+use ragu_testing::pcd::simple::Value;
+use rand::{SeedableRng, rngs::StdRng};
+
+// Local: like WitnessValue, but returns its witness as aux and records every
+// aux it is shown in post_fuse.
+#[derive(Clone, Default)]
+struct Recording {
+    seen: Arc<Mutex<Vec<Fp>>>,
+}
+
+impl Step<Pasta> for Recording {
+    const INDEX: Index = Index::new(0);
+    type Witness<'source> = Fp;
+    type Aux<'source> = Fp;
+    type Left = ();
+    type Right = ();
+    type Output = Value;
+
+    fn post_fuse<R: Rank>(&self, _proof: &Proof<Pasta, R>, aux: &Fp) -> Result<()> {
+        if *aux == Fp::ZERO {
+            return Err(Error::InvalidWitness("zero aux".into()));
+        }
+        self.seen.lock().unwrap().push(*aux);
+        Ok(())
+    }
+
+    fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = Fp>, const HEADER_SIZE: usize>(
+        &self,
+        dr: &mut D,
+        witness: DriverValue<D, Self::Witness<'source>>,
+        left: DriverValue<D, ()>,
+        right: DriverValue<D, ()>,
+    ) -> Result<(
+        (
+            Encoded<'dr, D, Self::Left, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Right, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Output, HEADER_SIZE>,
+        ),
+        DriverValue<D, <Self::Output as Header<Fp>>::Data>,
+        DriverValue<D, Self::Aux<'source>>,
+    )> {
+        let left = Encoded::new(dr, left)?;
+        let right = Encoded::new(dr, right)?;
+        let output = Encoded::new(dr, witness.clone())?;
+        Ok(((left, right, output), witness.clone(), witness))
+    }
+}
+
+#[test]
+fn post_fuse_sees_returned_aux() -> Result<()> {
+    let pasta = Pasta::baked();
+    let step = Recording::default();
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(step.clone())?
+        .finalize(pasta)?;
+    let mut rng = StdRng::seed_from_u64(1891);
+
+    let (pcd, aux) = app.seed(&mut rng, step.clone(), Fp::from(7u64))?;
+    assert!(app.verify(&pcd, &mut rng)?);
+    assert_eq!(*step.seen.lock().unwrap(), vec![aux]);
+
+    // Errors from the hook are surfaced by fuse.
+    assert!(matches!(
+        app.seed(&mut rng, step.clone(), Fp::ZERO),
+        Err(Error::InvalidWitness(_))
+    ));
+    assert_eq!(step.seen.lock().unwrap().len(), 1);
+
+    Ok(())
+}
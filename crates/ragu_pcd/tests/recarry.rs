@@ -0,0 +1,181 @@
+use ragu_arithmetic::Cycle;
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_core::{
+    Result,
+    drivers::{Driver, DriverValue},
+    gadgets::{Bound, Kind},
+    maybe::Maybe,
+};
+use ragu_pasta::{Fp, Pasta};
+use ragu_pcd::{
+    ApplicationBuilder,
+    header::{Header, Suffix},
+};
+use ragu_primitives::Element;
+use ragu_testing::pcd::simple::{Value, WitnessValue};
+use rand::{SeedableRng, rngs::StdRng};
+
+// Structurally identical to Value, with a different data type
+struct HeaderB;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Label(Fp);
+
+impl Header<Fp> for HeaderB {
+    const SUFFIX: Suffix = Suffix::new(0);
+    type Data = Label;
+    type Output = Kind![Fp; Element<'_, _>];
+    fn encode<'dr, D: Driver<'dr, F = Fp>>(
+        dr: &mut D,
+        witness: DriverValue<D, Self::Data>,
+    ) -> Result<Bound<'dr, D, Self::Output>> {
+        Element::alloc(dr, witness.map(|label| label.0))
+    }
+}
+
+// Same encoding as Value, but a different suffix
+struct HeaderC;
+
+impl Header<Fp> for HeaderC {
+    const SUFFIX: Suffix = Suffix::new(1);
+    type Data = Fp;
+    type Output = Kind![Fp; Element<'_, _>];
+    fn encode<'dr, D: Driver<'dr, F = Fp>>(
+        dr: &mut D,
+        witness: DriverValue<D, Self::Data>,
+    ) -> Result<Bound<'dr, D, Self::Output>> {
+        Element::alloc(dr, witness)
+    }
+}
+
+#[test]
+fn recarry_between_identical_encodings() {
+    let pasta = Pasta::baked();
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessValue)
+        .unwrap()
+        .finalize(pasta)
+        .unwrap();
+
+    let mut rng = StdRng::seed_from_u64(1844);
+
+    let value = Fp::from(42u64);
+    let (pcd, _) = app.seed(&mut rng, WitnessValue, value).unwrap();
+    assert!(app.verify(&pcd, &mut rng).unwrap());
+
+    let Ok(pcd) = app.recarry::<Value, HeaderB>(pcd, Label(value)) else {
+        panic!("encodings match");
+    };
+    assert_eq!(pcd.data(), &Label(value));
+    assert!(app.verify(&pcd, &mut rng).unwrap());
+
+    // ... and back again.
+    let Ok(pcd) = app.recarry::<HeaderB, Value>(pcd, value) else {
+        panic!("encodings match");
+    };
+    assert!(app.verify(&pcd, &mut rng).unwrap());
+}
+
+#[test]
+fn recarry_rejects_mismatched_encodings() {
+    let pasta = Pasta::baked();
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessValue)
+        .unwrap()
+        .finalize(pasta)
+        .unwrap();
+
+    let mut rng = StdRng::seed_from_u64(1845);
+
+    let value = Fp::from(42u64);
+    let (pcd, _) = app.seed(&mut rng, WitnessValue, value).unwrap();
+
+    // Different data under a structurally identical header. The original
+    // proof-carrying data is handed back intact.
+    let Err((_, pcd)) = app.recarry::<Value, HeaderB>(pcd, Label(value + Fp::from(1u64))) else {
+        panic!("encodings differ");
+    };
+    assert_eq!(pcd.data(), &value);
+    assert!(app.verify(&pcd, &mut rng).unwrap());
+
+    // Same data under a header with a different suffix.
+    let Err((_, pcd)) = app.recarry::<Value, HeaderC>(pcd, value) else {
+        panic!("suffixes differ");
+    };
+    assert_eq!(pcd.data(), &value);
+}
+
+#[test]
+fn same_header_compares_encodings() {
+    let pasta = Pasta::baked();
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessValue)
+        .unwrap()
+        .finalize(pasta)
+        .unwrap();
+
+    let mut rng = StdRng::seed_from_u64(1897);
+
+    let value = Fp::from(42u64);
+    let (pcd, _) = app.seed(&mut rng, WitnessValue, value).unwrap();
+    let (same, _) = app.seed(&mut rng, WitnessValue, value).unwrap();
+    let (different, _) = app
+        .seed(&mut rng, WitnessValue, value + Fp::from(1u64))
+        .unwrap();
+
+    // Independently generated proofs carrying the same data.
+    assert!(pcd.same_header(&same));
+    assert!(same.same_header(&pcd));
+
+    assert!(!pcd.same_header(&different));
+}
+
+#[test]
+fn map_data_between_identical_encodings() {
+    let pasta = Pasta::baked();
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessValue)
+        .unwrap()
+        .finalize(pasta)
+        .unwrap();
+
+    let mut rng = StdRng::seed_from_u64(1920);
+
+    let value = Fp::from(42u64);
+    let (pcd, _) = app.seed(&mut rng, WitnessValue, value).unwrap();
+
+    let pcd = pcd.map_data::<HeaderB>(Label).unwrap();
+    assert_eq!(pcd.data(), &Label(value));
+    assert!(app.verify(&pcd, &mut rng).unwrap());
+
+    // ... and back again.
+    let pcd = pcd.map_data::<Value>(|label| label.0).unwrap();
+    assert_eq!(pcd.data(), &value);
+    assert!(app.verify(&pcd, &mut rng).unwrap());
+}
+
+#[test]
+fn map_data_rejects_mismatched_encodings() {
+    let pasta = Pasta::baked();
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessValue)
+        .unwrap()
+        .finalize(pasta)
+        .unwrap();
+
+    let mut rng = StdRng::seed_from_u64(1921);
+
+    let value = Fp::from(42u64);
+    let (pcd, _) = app.seed(&mut rng, WitnessValue, value).unwrap();
+
+    // The mapped data encodes differently under a structurally identical
+    // header.
+    assert!(
+        pcd.clone()
+            .map_data::<HeaderB>(|data| Label(data + Fp::from(1u64)))
+            .is_err()
+    );
+
+    // Same data under a header with a different suffix.
+    assert!(pcd.map_data::<HeaderC>(|data| data).is_err());
+}
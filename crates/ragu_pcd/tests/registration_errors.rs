@@ -179,3 +179,35 @@ fn register_steps_duplicate_suffix_should_fail() {
         .register(Step1Dup)
         .unwrap();
 }
+
+#[test]
+fn register_steps_duplicate_index_should_fail() {
+    let result = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(Step0)
+        .unwrap()
+        .register(Step0);
+    assert!(matches!(result, Err(ragu_core::Error::Initialization(_))));
+}
+
+#[test]
+fn max_routine_depth_applies_to_registration() {
+    let pasta = Pasta::baked();
+
+    // The internal circuits nest routines, so no limit at all rejects them.
+    let result = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .with_max_routine_depth(0)
+        .register(Step0)
+        .unwrap()
+        .finalize(pasta);
+    assert!(matches!(
+        result,
+        Err(ragu_core::Error::RoutineRecursionLimit { limit: 0 })
+    ));
+
+    ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .with_max_routine_depth(ragu_circuits::DEFAULT_MAX_ROUTINE_DEPTH + 1)
+        .register(Step0)
+        .unwrap()
+        .finalize(pasta)
+        .unwrap();
+}
@@ -153,10 +153,12 @@ fn rerandomization_flow() {
 
     let (seeded, _) = app.seed(&mut rng, Step0, ()).unwrap();
     assert!(app.verify(&seeded, &mut rng).unwrap());
+    assert!(seeded.verify_with(&app, &mut rng).unwrap());
 
     // Rerandomize
     let seeded = app.rerandomize(seeded, &mut rng).unwrap();
     assert!(app.verify(&seeded, &mut rng).unwrap());
+    assert!(seeded.verify_with(&app, &mut rng).unwrap());
 
     let (fused, _) = app
         .fuse(&mut rng, Step1, (), seeded.clone(), seeded)
@@ -165,6 +167,7 @@ fn rerandomization_flow() {
 
     let fused = app.rerandomize(fused, &mut rng).unwrap();
     assert!(app.verify(&fused, &mut rng).unwrap());
+    assert!(fused.verify_with(&app, &mut rng).unwrap());
 }
 
 #[test]
@@ -212,6 +215,7 @@ fn rerandomization_preserves_header_data() {
 
     let rerandomized = app.rerandomize(original.clone(), &mut rng).unwrap();
     assert!(app.verify(&rerandomized, &mut rng).unwrap());
+    assert!(rerandomized.verify_with(&app, &mut rng).unwrap());
 
     // Header data should be preserved (non-unit comparison)
     assert_eq!(
@@ -254,3 +258,52 @@ fn rerandomized_fused_proof_verifies() {
         "rerandomized fused proof should verify"
     );
 }
+
+#[test]
+fn base_case_detection() {
+    let pasta = Pasta::baked();
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(Step0)
+        .unwrap()
+        .register(Step1)
+        .unwrap()
+        .finalize(pasta)
+        .unwrap();
+
+    let mut rng = StdRng::seed_from_u64(2468);
+
+    let (left, _) = app.seed(&mut rng, Step0, ()).unwrap();
+    let (right, _) = app.seed(&mut rng, Step0, ()).unwrap();
+    assert!(left.is_base_case(), "seeded proof should be a base case");
+    assert!(right.is_base_case(), "seeded proof should be a base case");
+
+    let (fused, _) = app.fuse(&mut rng, Step1, (), left, right).unwrap();
+    assert!(
+        !fused.is_base_case(),
+        "fused proof should not be a base case"
+    );
+}
+
+#[test]
+fn fuse_ref_with_same_child_verifies() {
+    let pasta = Pasta::baked();
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(Step0)
+        .unwrap()
+        .register(Step1)
+        .unwrap()
+        .finalize(pasta)
+        .unwrap();
+
+    let mut rng = StdRng::seed_from_u64(1893);
+
+    let (seeded, _) = app.seed(&mut rng, Step0, ()).unwrap();
+
+    // The same child is borrowed as both inputs, and remains usable after.
+    let (fused, _) = app.fuse_ref(&mut rng, Step1, (), &seeded, &seeded).unwrap();
+    assert!(app.verify(&fused, &mut rng).unwrap());
+    assert!(app.verify(&seeded, &mut rng).unwrap());
+
+    let fused = app.rerandomize(fused, &mut rng).unwrap();
+    assert!(app.verify(&fused, &mut rng).unwrap());
+}
@@ -0,0 +1,81 @@
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_core::{
+    Error, Result,
+    drivers::{Driver, DriverValue},
+    maybe::Maybe,
+};
+use ragu_pasta::{Fp, Pasta};
+use ragu_pcd::{
+    ApplicationBuilder,
+    step::{Encoded, Index, Step},
+};
+use ragu_testing::pcd::simple::{Value, WitnessValue};
+use rand::{SeedableRng, rngs::StdRng};
+
+// Local: sums its children and opts out of self-fusing via allow_self_fuse.
+struct Sum;
+
+impl Step<Pasta> for Sum {
+    const INDEX: Index = Index::new(1);
+    type Witness<'source> = ();
+    type Aux<'source> = ();
+    type Left = Value;
+    type Right = Value;
+    type Output = Value;
+
+    fn allow_self_fuse(&self) -> bool {
+        false
+    }
+
+    fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = Fp>, const HEADER_SIZE: usize>(
+        &self,
+        dr: &mut D,
+        _: DriverValue<D, ()>,
+        left: DriverValue<D, Fp>,
+        right: DriverValue<D, Fp>,
+    ) -> Result<(
+        (
+            Encoded<'dr, D, Self::Left, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Right, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Output, HEADER_SIZE>,
+        ),
+        DriverValue<D, Fp>,
+        DriverValue<D, Self::Aux<'source>>,
+    )> {
+        let sum = D::just(|| *left.snag() + *right.snag());
+        let left = Encoded::new(dr, left)?;
+        let right = Encoded::new(dr, right)?;
+        let output = Encoded::new(dr, sum.clone())?;
+        Ok(((left, right, output), sum, D::unit()))
+    }
+}
+
+#[test]
+fn self_fuse_rejected_when_disallowed() -> Result<()> {
+    let pasta = Pasta::baked();
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessValue)?
+        .register(Sum)?
+        .finalize(pasta)?;
+
+    let mut rng = StdRng::seed_from_u64(1930);
+
+    let (leaf, ()) = app.seed(&mut rng, WitnessValue, Fp::from(1u64))?;
+    let (other, ()) = app.seed(&mut rng, WitnessValue, Fp::from(1u64))?;
+
+    assert!(matches!(
+        app.fuse_ref(&mut rng, Sum, (), &leaf, &leaf),
+        Err(Error::SelfFuseNotAllowed)
+    ));
+    assert!(matches!(
+        app.fuse(&mut rng, Sum, (), leaf.clone(), leaf.clone()),
+        Err(Error::SelfFuseNotAllowed)
+    ));
+
+    // Independently generated proofs of the same data are distinct.
+    let (node, ()) = app.fuse(&mut rng, Sum, (), leaf, other)?;
+    assert_eq!(node.data(), &Fp::from(2u64));
+    assert!(app.verify(&node, &mut rng)?);
+
+    Ok(())
+}
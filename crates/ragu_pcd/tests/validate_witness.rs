@@ -0,0 +1,90 @@
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_core::{
+    Error, Result,
+    drivers::{Driver, DriverValue},
+    maybe::Maybe,
+};
+use ragu_pasta::{Fp, Pasta};
+use ragu_pcd::{
+    ApplicationBuilder,
+    step::{Encoded, Index, Step},
+};
+use ragu_testing::pcd::simple::Value;
+use rand::{SeedableRng, rngs::StdRng};
+
+#[derive(Debug)]
+struct TooLarge(u64);
+
+impl core::fmt::Display for TooLarge {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "witness {} exceeds 100", self.0)
+    }
+}
+
+impl core::error::Error for TooLarge {}
+
+// Local: overrides validate_witness to bound its witness by 100.
+struct Bounded;
+
+impl Step<Pasta> for Bounded {
+    const INDEX: Index = Index::new(0);
+    type Witness<'source> = u64;
+    type Aux<'source> = ();
+    type Left = ();
+    type Right = ();
+    type Output = Value;
+
+    fn validate_witness(&self, witness: &u64) -> Result<()> {
+        if *witness > 100 {
+            return Err(Error::InvalidWitness(Box::new(TooLarge(*witness))));
+        }
+        Ok(())
+    }
+
+    fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = Fp>, const HEADER_SIZE: usize>(
+        &self,
+        dr: &mut D,
+        witness: DriverValue<D, u64>,
+        left: DriverValue<D, ()>,
+        right: DriverValue<D, ()>,
+    ) -> Result<(
+        (
+            Encoded<'dr, D, Self::Left, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Right, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Output, HEADER_SIZE>,
+        ),
+        DriverValue<D, Fp>,
+        DriverValue<D, Self::Aux<'source>>,
+    )> {
+        let value = witness.map(|w| {
+            assert!(w <= 100, "invalid witness reached synthesis");
+            Fp::from(w)
+        });
+        let left = Encoded::new(dr, left)?;
+        let right = Encoded::new(dr, right)?;
+        let output = Encoded::new(dr, value.clone())?;
+        Ok(((left, right, output), value, D::unit()))
+    }
+}
+
+#[test]
+fn validate_witness_short_circuits_fuse() {
+    let pasta = Pasta::baked();
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(Bounded)
+        .unwrap()
+        .finalize(pasta)
+        .unwrap();
+
+    let mut rng = StdRng::seed_from_u64(1863);
+
+    let (pcd, ()) = app.seed(&mut rng, Bounded, 42).unwrap();
+    assert!(app.verify(&pcd, &mut rng).unwrap());
+
+    match app.seed(&mut rng, Bounded, 101) {
+        Err(Error::InvalidWitness(err)) => {
+            assert_eq!(err.to_string(), "witness 101 exceeds 100");
+        }
+        _ => panic!("expected the witness to be rejected"),
+    }
+}
@@ -0,0 +1,125 @@
+use ff::Field;
+use ragu_arithmetic::Cycle;
+use ragu_circuits::polynomials::ProductionRank;
+use ragu_core::{
+    Result,
+    drivers::{Driver, DriverValue},
+};
+use ragu_pasta::{Fp, Pasta};
+use ragu_pcd::{
+    ApplicationBuilder,
+    step::{Encoded, Index, Step},
+};
+use ragu_primitives::Element;
+use ragu_testing::pcd::{
+    nontrivial::{LeafNode, WitnessLeaf},
+    simple::WitnessValue,
+};
+use rand::{SeedableRng, rngs::StdRng};
+
+// Local: no shared step constrains its witness beyond allocating it.
+struct RequireZero;
+
+impl Step<Pasta> for RequireZero {
+    const INDEX: Index = Index::new(1);
+    type Witness<'source> = Fp;
+    type Aux<'source> = ();
+    type Left = ();
+    type Right = ();
+    type Output = ();
+
+    fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = Fp>, const HEADER_SIZE: usize>(
+        &self,
+        dr: &mut D,
+        witness: DriverValue<D, Self::Witness<'source>>,
+        left: DriverValue<D, ()>,
+        right: DriverValue<D, ()>,
+    ) -> Result<(
+        (
+            Encoded<'dr, D, Self::Left, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Right, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Output, HEADER_SIZE>,
+        ),
+        DriverValue<D, ()>,
+        DriverValue<D, Self::Aux<'source>>,
+    )> {
+        Element::alloc(dr, witness)?.enforce_zero(dr)?;
+
+        let left = Encoded::new(dr, left)?;
+        let right = Encoded::new(dr, right)?;
+        let output = Encoded::from_gadget(());
+        Ok(((left, right, output), D::unit(), D::unit()))
+    }
+}
+
+#[test]
+fn verify_rejects_swapped_witness_digest() -> Result<()> {
+    let pasta = Pasta::baked();
+    let poseidon_params = Pasta::circuit_poseidon(pasta);
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessLeaf { poseidon_params })?
+        .finalize(pasta)?;
+    let mut rng = StdRng::seed_from_u64(1865);
+
+    let (pcd, ()) = app.seed(&mut rng, WitnessLeaf { poseidon_params }, Fp::from(42u64))?;
+    assert!(app.verify(&pcd, &mut rng)?);
+    let (other, ()) = app.seed(&mut rng, WitnessLeaf { poseidon_params }, Fp::from(43u64))?;
+    assert!(app.verify(&other, &mut rng)?);
+    assert_ne!(pcd.data(), other.data());
+
+    // The digest of a different witness does not match the proof.
+    let (proof, _) = pcd.into_parts();
+    let swapped = proof.carry::<LeafNode>(*other.data());
+    assert!(!app.verify(&swapped, &mut rng)?);
+
+    Ok(())
+}
+
+#[test]
+fn proofs_are_bound_to_their_application() -> Result<()> {
+    let pasta = Pasta::baked();
+    let poseidon_params = Pasta::circuit_poseidon(pasta);
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessValue)?
+        .finalize(pasta)?;
+    let other = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessLeaf { poseidon_params })?
+        .finalize(pasta)?;
+    let mut rng = StdRng::seed_from_u64(1869);
+
+    let (pcd, ()) = app.seed(&mut rng, WitnessValue, Fp::from(42u64))?;
+    assert!(app.verify(&pcd, &mut rng)?);
+    assert!(!matches!(other.verify(&pcd, &mut rng), Ok(true)));
+
+    let (pcd, ()) = other.seed(&mut rng, WitnessLeaf { poseidon_params }, Fp::from(42u64))?;
+    assert!(other.verify(&pcd, &mut rng)?);
+    assert!(!matches!(app.verify(&pcd, &mut rng), Ok(true)));
+
+    Ok(())
+}
+
+#[test]
+fn verify_application_circuit_isolates_step() -> Result<()> {
+    let pasta = Pasta::baked();
+    let poseidon_params = Pasta::circuit_poseidon(pasta);
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new()
+        .register(WitnessLeaf { poseidon_params })?
+        .register(RequireZero)?
+        .finalize(pasta)?;
+    let mut rng = StdRng::seed_from_u64(1887);
+
+    let leaf = WitnessLeaf { poseidon_params };
+    assert!(app.verify_application_circuit(leaf, Fp::from(42u64), (), (), &mut rng)?);
+    assert!(app.verify_application_circuit(RequireZero, Fp::ZERO, (), (), &mut rng)?);
+    assert!(!app.verify_application_circuit(RequireZero, Fp::ONE, (), (), &mut rng)?);
+
+    // Unregistered steps are rejected.
+    let app = ApplicationBuilder::<Pasta, ProductionRank, 4>::new().finalize(pasta)?;
+    let leaf = WitnessLeaf { poseidon_params };
+    assert!(
+        app.verify_application_circuit(leaf, Fp::from(42u64), (), (), &mut rng)
+            .is_err()
+    );
+
+    Ok(())
+}
@@ -152,7 +152,7 @@ pub(crate) fn is_zero<'dr, D: Driver<'dr>>(
     // complete. By construction, `is_zero` is boolean constrained for all
     // satisfying assignments of these two constraints.
 
-    let is_zero = x.value().map(|v| *v == D::F::ZERO);
+    let is_zero = x.value().map(|v| bool::from(v.is_zero()));
 
     // Constraint 1: x * is_zero = 0.
     let (x_wire, is_zero_wire, zero_product) = dr.mul(|| {
@@ -346,6 +346,25 @@ fn test_conditional_enforce_equal() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_bool_conversions() {
+    use ragu_core::maybe::Always;
+
+    type F = ragu_pasta::Fp;
+
+    for (bit, expected) in [(false, F::ZERO), (true, F::ONE)] {
+        let bit = Always::constant(bit);
+
+        assert_eq!(bit.fe::<bool, F>().take(), expected);
+
+        let coeff = bit.coeff::<bool, F>().take();
+        assert_eq!(coeff.value(), expected);
+        // Arbitrary coefficients do not reveal the bit through shortcuts.
+        #[cfg(feature = "ct")]
+        assert!(matches!(coeff, Coeff::Arbitrary(_)));
+    }
+}
+
 #[test]
 fn test_multipack() -> Result<()> {
     use alloc::vec::Vec;
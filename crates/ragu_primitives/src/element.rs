@@ -72,6 +72,24 @@ impl<'dr, D: Driver<'dr>> Element<'dr, D> {
         })
     }
 
+    /// Allocates an array of elements with the provided witness assignments,
+    /// in order.
+    ///
+    /// This costs `N` allocations.
+    pub fn alloc_batch<const N: usize>(
+        dr: &mut D,
+        assignments: DriverValue<D, [D::F; N]>,
+    ) -> Result<[Self; N]> {
+        let mut elements = Vec::with_capacity(N);
+        for i in 0..N {
+            elements.push(Element::alloc(dr, assignments.as_ref().map(|a| a[i]))?);
+        }
+        match elements.try_into() {
+            Ok(elements) => Ok(elements),
+            Err(_) => unreachable!("exactly N elements were allocated"),
+        }
+    }
+
     /// Allocates an element $a$ with the provided witness assignment and
     /// squares it in a single step. Returns $(a, a^2)$.
     ///
@@ -138,6 +156,17 @@ impl<'dr, D: Driver<'dr>> Element<'dr, D> {
         self.value.as_ref()
     }
 
+    /// Returns a copy of the value of this element if the driver carries
+    /// witness values, or `None` if it does not (such as a wireless
+    /// [`Emulator::counter`](ragu_core::drivers::emulator::Emulator::counter)).
+    ///
+    /// This is intended for debugging, as it compiles under any driver.
+    pub fn debug_value(&self) -> Option<D::F> {
+        let mut value = None;
+        let _ = self.value.as_ref().map(|v| value = Some(*v));
+        value
+    }
+
     /// Returns the wire associated with this element.
     pub fn wire(&self) -> &D::Wire {
         &self.wire
@@ -172,6 +201,27 @@ impl<'dr, D: Driver<'dr>> Element<'dr, D> {
         self.mul(dr, self)
     }
 
+    /// Raises this element to the constant power `exp`.
+    ///
+    /// Uses left-to-right square-and-multiply, costing one multiplication per
+    /// bit of `exp` after the leading one and one per additional set bit. As
+    /// such, $\mathtt{self}^{2^k}$ costs exactly $k$ multiplications, and
+    /// `exp` of zero or one costs none.
+    pub fn pow_const(&self, dr: &mut D, exp: u64) -> Result<Self> {
+        if exp == 0 {
+            return Ok(Element::one());
+        }
+
+        let mut result = self.clone();
+        for bit in (0..exp.ilog2()).rev() {
+            result = result.square(dr)?;
+            if (exp >> bit) & 1 == 1 {
+                result = result.mul(dr, self)?;
+            }
+        }
+        Ok(result)
+    }
+
     /// Enforces that this element equals zero.
     pub fn enforce_zero(&self, dr: &mut D) -> Result<()> {
         dr.enforce_zero(|lc| lc.add(&self.wire))
@@ -318,7 +368,8 @@ impl<'dr, D: Driver<'dr>> Element<'dr, D> {
     ///
     /// Horner's method is used to evaluate the weighted sum, effectively
     /// scaling the first element by the highest power of `scale_factor` and the
-    /// last element by nothing at all.
+    /// last element by nothing at all. The iterator is consumed as it is
+    /// folded, using one gate for each element after the first.
     pub fn fold<E: Borrow<Element<'dr, D>>>(
         dr: &mut D,
         elements: impl IntoIterator<Item = E>,
@@ -346,10 +397,59 @@ impl<'dr, D: Driver<'dr>> Element<'dr, D> {
         Ok(())
     }
 
+    /// Decomposes `self` into its `bits` least significant bits, in little
+    /// endian order, and constrains that they recompose to `self`.
+    ///
+    /// The recomposition constraint is unsatisfiable unless `self` is less
+    /// than $2^{\mathtt{bits}}$, so this doubles as a range check. Assumes the
+    /// field's canonical representation is little endian.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` exceeds the field's
+    /// [`CAPACITY`](ff::PrimeField::CAPACITY), since the recomposition could
+    /// otherwise wrap around the modulus.
+    pub fn to_bits(&self, dr: &mut D, bits: usize) -> Result<Vec<Boolean<'dr, D>>>
+    where
+        D::F: ff::PrimeField,
+    {
+        assert!(
+            bits <= D::F::CAPACITY as usize,
+            "cannot decompose into more bits than the field's capacity"
+        );
+
+        let repr = self.value().map(|v| v.to_repr());
+        let decomposition = (0..bits)
+            .map(|i| {
+                Boolean::alloc(
+                    dr,
+                    repr.as_ref()
+                        .map(|repr| (repr.as_ref()[i / 8] >> (i % 8)) & 1 == 1),
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let recomposed = crate::boolean::multipack(dr, &decomposition)?;
+        let recomposed = Element::sum(dr, &recomposed);
+        recomposed.sub(dr, self).enforce_zero(dr)?;
+
+        Ok(decomposition)
+    }
+
+    /// Constrains that `self` is less than $2^{\mathtt{bits}}$.
+    ///
+    /// See [`Element::to_bits`], which this uses to decompose `self`.
+    pub fn range_check(&self, dr: &mut D, bits: usize) -> Result<()>
+    where
+        D::F: ff::PrimeField,
+    {
+        self.to_bits(dr, bits).map(|_| ())
+    }
+
     /// Sums an iterator of elements.
     ///
     /// This is more efficient than [`Element::fold`] with scale=1 because it
-    /// avoids gates.
+    /// avoids gates. The iterator is consumed as it is summed.
     pub fn sum<E: Borrow<Element<'dr, D>>>(
         dr: &mut D,
         elements: impl IntoIterator<Item = E>,
@@ -358,6 +458,28 @@ impl<'dr, D: Driver<'dr>> Element<'dr, D> {
             .into_iter()
             .fold(Element::zero(dr), |acc, elem| acc.add(dr, elem.borrow()))
     }
+
+    /// Computes the linear combination $\sum_i c_i \cdot e_i$ of the provided
+    /// `(coeff, element)` terms.
+    ///
+    /// The constant coefficients are folded into a single linear combination,
+    /// so no multiplication gates are used. See also [`multiadd`] for the same
+    /// computation over separate slices.
+    pub fn linear_combination(dr: &mut D, terms: &[(D::F, &Element<'dr, D>)]) -> Self {
+        let value = D::just(|| {
+            terms
+                .iter()
+                .map(|(coeff, elem)| *elem.value.snag() * coeff)
+                .sum()
+        });
+        let wire = dr.add(|lc| {
+            terms.iter().fold(lc, |lc, (coeff, elem)| {
+                lc.add_term(&elem.wire, Coeff::Arbitrary(*coeff))
+            })
+        });
+
+        Element { value, wire }
+    }
 }
 
 impl<F: Field> Write<F> for Kind![F; @Element<'_, _>] {
@@ -563,6 +685,214 @@ mod proptests {
     }
 }
 
+#[test]
+fn test_scale_by_constant() -> Result<()> {
+    type F = ragu_pasta::Fp;
+    type Simulator = crate::Simulator<F>;
+
+    let a = F::from(4578u64);
+    let c = F::from(372u64);
+
+    let sim = Simulator::simulate(a, |dr, witness| {
+        let a = Element::alloc(dr, witness)?;
+        dr.reset();
+
+        let product = a.scale(dr, Coeff::Arbitrary(c));
+        assert_eq!(*product.value().take(), *a.value().take() * c);
+
+        Ok(())
+    })?;
+    assert_eq!(sim.num_gates(), 0);
+
+    // The general multiplication by a constant element uses a gate.
+    let sim = Simulator::simulate(a, |dr, witness| {
+        let a = Element::alloc(dr, witness)?;
+        dr.reset();
+
+        let c = Element::constant(dr, c);
+        let product = a.mul(dr, &c)?;
+        assert_eq!(
+            *product.value().take(),
+            *a.value().take() * c.value().take()
+        );
+
+        Ok(())
+    })?;
+    assert_eq!(sim.num_gates(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_linear_combination() -> Result<()> {
+    type F = ragu_pasta::Fp;
+    type Simulator = crate::Simulator<F>;
+
+    let values = [F::from(3u64), F::from(5u64), F::from(7u64)];
+    let coeffs = [F::from(2u64), -F::ONE, F::from(11u64)];
+
+    let sim = Simulator::simulate(values, |dr, witness| {
+        let elements = Element::alloc_batch(dr, witness)?;
+        dr.reset();
+
+        let terms = [
+            (coeffs[0], &elements[0]),
+            (coeffs[1], &elements[1]),
+            (coeffs[2], &elements[2]),
+        ];
+        let combination = Element::linear_combination(dr, &terms);
+        assert_eq!(*combination.value().take(), F::from(2 * 3 - 5 + 11 * 7u64));
+
+        // The empty combination is zero.
+        let empty = Element::linear_combination(dr, &[]);
+        assert_eq!(*empty.value().take(), F::ZERO);
+
+        // The result is constrained to the combination.
+        let expected = multiadd(dr, &elements, &coeffs);
+        dr.enforce_equal(combination.wire(), expected.wire())?;
+
+        Ok(())
+    })?;
+    assert_eq!(sim.num_gates(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_debug_value() -> Result<()> {
+    use ragu_core::drivers::emulator::Emulator;
+
+    type F = ragu_pasta::Fp;
+
+    let value = Emulator::emulate_wireless((), |dr, _| {
+        let element = Element::constant(dr, F::from(7u64));
+        Ok(element.debug_value())
+    })?;
+    assert_eq!(value, Some(F::from(7u64)));
+
+    let mut dr = Emulator::counter();
+    let element = Element::constant(&mut dr, F::from(7u64));
+    assert_eq!(element.debug_value(), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_alloc_batch() -> Result<()> {
+    use ragu_core::drivers::emulator::Emulator;
+
+    type F = ragu_pasta::Fp;
+
+    let values = [F::from(1u64), F::from(22u64), F::from(333u64)];
+
+    let (batch, individual) = Emulator::emulate_wireless(values, |dr, witness| {
+        let batch = Element::alloc_batch(dr, witness.clone())?;
+        let individual = [
+            Element::alloc(dr, witness.as_ref().map(|v| v[0]))?,
+            Element::alloc(dr, witness.as_ref().map(|v| v[1]))?,
+            Element::alloc(dr, witness.as_ref().map(|v| v[2]))?,
+        ];
+
+        Ok((
+            batch.map(|e| *e.value().take()),
+            individual.map(|e| *e.value().take()),
+        ))
+    })?;
+    assert_eq!(batch, values);
+    assert_eq!(batch, individual);
+
+    let sim = crate::Simulator::<F>::simulate(values, |dr, witness| {
+        Element::alloc_batch(dr, witness)?;
+        Ok(())
+    })?;
+    assert_eq!(sim.num_allocations(), values.len());
+    assert_eq!(sim.num_gates(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_pow_const() -> Result<()> {
+    use ragu_core::drivers::emulator::Emulator;
+
+    type F = ragu_pasta::Fp;
+    type Simulator = crate::Simulator<F>;
+
+    let a = F::from(7u64);
+
+    let sim = Simulator::simulate(a, |dr, witness| {
+        let a = Element::alloc(dr, witness)?;
+        dr.reset();
+
+        a.pow_const(dr, 8)?;
+
+        Ok(())
+    })?;
+    assert_eq!(sim.num_gates(), 3);
+
+    for exp in [0, 1, 2, 3, 8, 13, 64] {
+        let (actual, expected) = Emulator::emulate_wireless(a, |dr, witness| {
+            let a = Element::alloc(dr, witness)?;
+            let expected = (0..exp).try_fold(Element::one(), |acc, _| acc.mul(dr, &a))?;
+            let actual = a.pow_const(dr, exp)?;
+            Ok((*actual.value().take(), *expected.value().take()))
+        })?;
+        assert_eq!(actual, expected, "exponent {exp}");
+        assert_eq!(actual, a.pow_vartime([exp]));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_sum_and_fold_gates() -> Result<()> {
+    use ragu_core::maybe::Always;
+
+    type F = ragu_pasta::Fp;
+    type Simulator = crate::Simulator<F>;
+
+    const N: usize = 16;
+    let values: Vec<F> = (1..=N as u64).map(F::from).collect();
+    let scale = F::from(3u64);
+
+    let sim = Simulator::simulate(values.clone(), |dr, witness| {
+        let elements = witness
+            .take()
+            .into_iter()
+            .map(|v| Element::alloc(dr, Always::constant(v)))
+            .collect::<Result<Vec<_>>>()?;
+        dr.reset();
+
+        let sum = Element::sum(dr, &elements);
+        assert_eq!(*sum.value().take(), values.iter().sum::<F>());
+
+        Ok(())
+    })?;
+    assert_eq!(sim.num_gates(), 0);
+    assert_eq!(sim.num_allocations(), 0);
+
+    let sim = Simulator::simulate(values.clone(), |dr, witness| {
+        let elements = witness
+            .take()
+            .into_iter()
+            .map(|v| Element::alloc(dr, Always::constant(v)))
+            .collect::<Result<Vec<_>>>()?;
+        let scale = Element::alloc(dr, Always::constant(scale))?;
+        dr.reset();
+
+        let folded = Element::fold(dr, &elements, &scale)?;
+        let expected = values
+            .iter()
+            .fold(F::ZERO, |acc, v| acc * scale.value().take() + v);
+        assert_eq!(*folded.value().take(), expected);
+
+        Ok(())
+    })?;
+    assert_eq!(sim.num_gates(), N - 1);
+
+    Ok(())
+}
+
 #[test]
 fn test_div_nonzero() -> Result<()> {
     type F = ragu_pasta::Fp;
@@ -624,3 +954,54 @@ fn test_invert() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_range_check() -> Result<()> {
+    type F = ragu_pasta::Fp;
+    type Simulator = crate::Simulator<F>;
+
+    let check = |value: F, bits: usize| {
+        Simulator::simulate(value, |dr, witness| {
+            let value = Element::alloc(dr, witness)?;
+            value.range_check(dr, bits)
+        })
+        .map(|_| ())
+    };
+
+    check(F::ZERO, 0)?;
+    check(F::from(5u64), 3)?;
+    check(F::from(255u64), 8)?;
+    check(F::from(1u64 << 40), 41)?;
+
+    assert!(check(F::ONE, 0).is_err());
+    assert!(check(F::from(8u64), 3).is_err());
+    assert!(check(F::from(256u64), 8).is_err());
+    assert!(check(-F::ONE, 64).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_to_bits() -> Result<()> {
+    type F = ragu_pasta::Fp;
+    type Simulator = crate::Simulator<F>;
+
+    let sim = Simulator::simulate(F::from(0b1011_0010u64), |dr, witness| {
+        let value = Element::alloc(dr, witness)?;
+        dr.reset();
+        let bits = value.to_bits(dr, 10)?;
+        let bits: Vec<bool> = bits.iter().map(|b| b.value().take()).collect();
+        assert_eq!(
+            bits,
+            [
+                false, true, false, false, true, true, false, true, false, false
+            ]
+        );
+        Ok(())
+    })?;
+
+    assert_eq!(sim.num_allocations(), 0);
+    assert_eq!(sim.num_gates(), 10);
+
+    Ok(())
+}
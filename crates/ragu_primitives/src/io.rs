@@ -13,6 +13,7 @@
 //! [pack](crate::boolean::multipack) many of them into far fewer [`Element`]s.
 
 mod pipe;
+mod tee;
 
 use ff::Field;
 pub use pipe::Pipe;
@@ -21,6 +22,7 @@ use ragu_core::{
     drivers::Driver,
     gadgets::{Bound, GadgetKind},
 };
+pub use tee::Tee;
 
 use crate::Element;
 
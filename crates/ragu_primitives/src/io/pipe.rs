@@ -1,4 +1,5 @@
-use core::marker::PhantomData;
+use alloc::vec::Vec;
+use core::{marker::PhantomData, mem};
 
 use ragu_core::{Result, drivers::Driver, maybe::Maybe};
 
@@ -24,6 +25,29 @@ impl<'a, 'dr, D: Driver<'dr>, B: Buffer<'dr, D>> Pipe<'a, 'dr, D, B> {
             _marker: PhantomData,
         }
     }
+
+    /// Consumes this pipe, returning the underlying buffer.
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+}
+
+impl<'dr, D: Driver<'dr>> Pipe<'_, 'dr, D, Vec<Element<'dr, D>>> {
+    /// Returns the number of [`Element`]s collected by this pipe.
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns `true` if no [`Element`]s have been collected by this pipe.
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Removes and returns the [`Element`]s collected so far, leaving this
+    /// pipe empty so that it can be reused.
+    pub fn drain(&mut self) -> Vec<Element<'dr, D>> {
+        mem::take(&mut self.buf)
+    }
 }
 
 impl<'dr, S: Driver<'dr, F = D::F>, D: Driver<'dr>, B: Buffer<'dr, D>> Buffer<'dr, S>
@@ -72,3 +96,45 @@ fn test_pipe_between_wireless_emulators() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_pipe_collects_multiple_gadgets() -> Result<()> {
+    use ragu_core::{
+        drivers::emulator::{Emulator, Wireless},
+        maybe::Always,
+    };
+    use ragu_pasta::Fp;
+
+    use crate::GadgetExt;
+
+    let mut source_dr: Emulator<Wireless<Always<()>, Fp>> = Emulator::execute();
+    let pair = [
+        Element::alloc(&mut source_dr, Always::constant(Fp::from(1u64)))?,
+        Element::alloc(&mut source_dr, Always::constant(Fp::from(2u64)))?,
+    ];
+    let single = Element::alloc(&mut source_dr, Always::constant(Fp::from(3u64)))?;
+
+    let mut dest_dr: Emulator<Wireless<Always<()>, Fp>> = Emulator::execute();
+    let mut pipe = Pipe::new(&mut dest_dr, Vec::new());
+    assert!(pipe.is_empty());
+
+    pair.write(&mut source_dr, &mut pipe)?;
+    assert_eq!(pipe.len(), 2);
+    single.write(&mut source_dr, &mut pipe)?;
+    assert_eq!(pipe.len(), 3);
+
+    // Draining leaves the pipe empty and ready for reuse.
+    let drained = pipe.drain();
+    assert_eq!(drained.len(), 3);
+    assert!(pipe.is_empty());
+
+    single.write(&mut source_dr, &mut pipe)?;
+    let collected = pipe.into_inner();
+    assert_eq!(collected.len(), 1);
+
+    for (elem, expected) in drained.iter().chain(&collected).zip([1u64, 2, 3, 3]) {
+        assert_eq!(*elem.value().take(), Fp::from(expected));
+    }
+
+    Ok(())
+}
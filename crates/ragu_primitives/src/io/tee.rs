@@ -0,0 +1,76 @@
+use ragu_core::{Result, drivers::Driver};
+
+use crate::{Element, io::Buffer};
+
+/// Implementation of [`Buffer`] that forwards every written [`Element`] to two
+/// underlying buffers, in order.
+///
+/// This is useful for feeding the same stream of elements to, for example, a
+/// transcript and a serialized copy at the same time.
+pub struct Tee<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A, B> Tee<A, B> {
+    /// Creates a new tee that forwards writes to `a` and then to `b`.
+    pub fn new(a: A, b: B) -> Self {
+        Tee { a, b }
+    }
+
+    /// Consumes the tee and returns the two underlying buffers.
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+}
+
+impl<'dr, D: Driver<'dr>, A: Buffer<'dr, D>, B: Buffer<'dr, D>> Buffer<'dr, D> for Tee<A, B> {
+    fn write(&mut self, dr: &mut D, value: &Element<'dr, D>) -> Result<()> {
+        self.a.write(dr, value)?;
+        self.b.write(dr, value)
+    }
+}
+
+#[test]
+fn test_tee_into_pipes() -> Result<()> {
+    use alloc::vec::Vec;
+
+    use ragu_core::{
+        drivers::emulator::{Emulator, Wireless},
+        maybe::{Always, Maybe, MaybeKind},
+    };
+    use ragu_pasta::Fp;
+
+    use crate::io::Pipe;
+
+    let mut source_dr: Emulator<Wireless<Always<()>, Fp>> = Emulator::execute();
+    let values = [Fp::from(7u64), Fp::from(11u64), Fp::from(13u64)];
+    let source_elements: Vec<Element<'_, _>> = values
+        .iter()
+        .map(|&v| Element::alloc(&mut source_dr, Always::maybe_just(|| v)))
+        .collect::<Result<_>>()?;
+
+    let mut left_dr: Emulator<Wireless<Always<()>, Fp>> = Emulator::execute();
+    let mut right_dr: Emulator<Wireless<Always<()>, Fp>> = Emulator::execute();
+    let mut left_buffer: Vec<Element<'_, _>> = Vec::new();
+    let mut right_buffer: Vec<Element<'_, _>> = Vec::new();
+
+    {
+        let mut tee = Tee::new(
+            Pipe::new(&mut left_dr, &mut left_buffer),
+            Pipe::new(&mut right_dr, &mut right_buffer),
+        );
+        for elem in &source_elements {
+            tee.write(&mut source_dr, elem)?;
+        }
+    }
+
+    assert_eq!(left_buffer.len(), values.len());
+    assert_eq!(right_buffer.len(), values.len());
+    for ((left, right), &expected) in left_buffer.iter().zip(&right_buffer).zip(values.iter()) {
+        assert_eq!(*left.value().take(), expected);
+        assert_eq!(*right.value().take(), expected);
+    }
+
+    Ok(())
+}
@@ -3,6 +3,32 @@
 //! This crate contains low level gadgets and algorithms for the Ragu project.
 //! This API is re-exported (as necessary) in other crates and so this crate is
 //! only intended to be used internally by Ragu.
+//!
+//! ## Constant time
+//!
+//! Witness values are computed using the constant-time arithmetic of the
+//! underlying [`Field`](ff::Field): inversions (as in [`Element::invert`] and
+//! [`Element::is_zero`]) go through [`Field::invert`](ff::Field::invert) and
+//! zero checks through [`Field::is_zero`](ff::Field::is_zero). The exceptions
+//! are:
+//!
+//! * [`Element::invert`] and [`Element::div_nonzero`] return an error as soon
+//!   as they encounter a zero witness, which is not a valid assignment;
+//! * booleans are converted to [`Coeff::One`](ragu_arithmetic::Coeff::One) or
+//!   [`Coeff::Zero`](ragu_arithmetic::Coeff::Zero) so that drivers can skip
+//!   multiplications by them, unless the `ct` feature is enabled.
+//!
+//! The drivers that compute witnesses (the `Emulator` in `ragu_core` and the
+//! trace `Evaluator` in `ragu_circuits`) branch only on the circuit structure,
+//! on routine predictions, and on the variant of each
+//! [`Coeff`](ragu_arithmetic::Coeff) they are given; none of the routines in
+//! Ragu predict differently depending on witness values. With the `ct`
+//! feature enabled, witness-dependent coefficients are always
+//! [`Coeff::Arbitrary`](ragu_arithmetic::Coeff::Arbitrary), so neither driver
+//! branches on witness data.
+//!
+//! These are best-effort guarantees: they do not extend to user-defined
+//! routines or to the timing of the field arithmetic itself.
 
 #![no_std]
 #![allow(clippy::type_complexity)]
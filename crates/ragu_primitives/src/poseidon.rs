@@ -8,7 +8,7 @@ use alloc::{vec, vec::Vec};
 use core::{marker::PhantomData, panic};
 
 use ff::Field;
-use ragu_arithmetic::Coeff;
+use ragu_arithmetic::{Coeff, CurveAffine};
 use ragu_core::{
     Result,
     drivers::{Driver, DriverValue},
@@ -17,13 +17,16 @@ use ragu_core::{
 };
 
 use crate::{
-    Element,
+    Element, GadgetExt, Point,
     consistent::Consistent,
     io::{Buffer, Write},
     multiadd,
-    vec::{FixedVec, Len},
+    vec::{FixedVec, Len, StaticLen},
 };
 
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_vectors;
+
 /// Error type for sponge save operations.
 #[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
@@ -36,21 +39,60 @@ pub enum SaveError {
     NothingAbsorbed,
 }
 
-/// A type-level length marker for the Poseidon state size (`P::T`).
+/// A permutation-based hash that can drive a [`Sponge`].
+///
+/// Every [`PoseidonPermutation`](ragu_arithmetic::PoseidonPermutation) is a
+/// [`SpongeHash`], so [`Sponge`] defaults to Poseidon. Other
+/// arithmetization-friendly permutations (such as Rescue) can be used by
+/// implementing this trait directly.
+pub trait SpongeHash<F: Field>: Send + Sync + 'static {
+    /// The size of the state.
+    const T: usize;
+
+    /// The rate, which caps the number of elements that can be squeezed or
+    /// absorbed before a permutation is applied. Must be smaller than `T`.
+    const RATE: usize;
+
+    /// Applies the permutation to `state` in circuit.
+    fn permute<'dr, D: Driver<'dr, F = F>>(
+        &self,
+        dr: &mut D,
+        state: SpongeState<'dr, D, Self>,
+    ) -> Result<SpongeState<'dr, D, Self>>
+    where
+        Self: Sized;
+}
+
+impl<F: Field, P: ragu_arithmetic::PoseidonPermutation<F>> SpongeHash<F> for P {
+    const T: usize = <P as ragu_arithmetic::PoseidonPermutation<F>>::T;
+    const RATE: usize = <P as ragu_arithmetic::PoseidonPermutation<F>>::RATE;
+
+    fn permute<'dr, D: Driver<'dr, F = F>>(
+        &self,
+        dr: &mut D,
+        state: SpongeState<'dr, D, Self>,
+    ) -> Result<SpongeState<'dr, D, Self>> {
+        dr.routine(Permutation::from(self), state)
+    }
+}
+
+/// A type-level length marker for the sponge state size (`P::T`).
 ///
 /// This type implements [`Len`] and is used to parameterize [`FixedVec`]
 /// containers holding sponge state elements.
-pub struct PoseidonStateLen<F: Field, P: ragu_arithmetic::PoseidonPermutation<F>>(
-    PhantomData<(F, P)>,
-);
+pub struct PoseidonStateLen<F: Field, P: SpongeHash<F>>(PhantomData<(F, P)>);
 
-impl<F: Field, P: ragu_arithmetic::PoseidonPermutation<F>> Len for PoseidonStateLen<F, P> {
+impl<F: Field, P: SpongeHash<F>> Len for PoseidonStateLen<F, P> {
     fn len() -> usize {
-        P::T
+        Self::LEN
     }
 }
 
-enum Mode<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> {
+impl<F: Field, P: SpongeHash<F>> StaticLen for PoseidonStateLen<F, P> {
+    const LEN: usize = P::T;
+}
+
+enum Mode<'dr, D: Driver<'dr>, P: SpongeHash<D::F>> {
     Squeeze {
         values: Vec<Element<'dr, D>>,
         state: SpongeState<'dr, D, P>,
@@ -61,7 +103,7 @@ enum Mode<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> {
     },
 }
 
-impl<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> Clone for Mode<'dr, D, P> {
+impl<'dr, D: Driver<'dr>, P: SpongeHash<D::F>> Clone for Mode<'dr, D, P> {
     fn clone(&self) -> Self {
         match self {
             Mode::Squeeze { values, state } => Mode::Squeeze {
@@ -76,15 +118,14 @@ impl<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> Clone f
     }
 }
 
-/// The [Poseidon](https://eprint.iacr.org/2019/458) sponge function.
-pub struct Sponge<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> {
+/// The [Poseidon](https://eprint.iacr.org/2019/458) sponge function, or any
+/// other [`SpongeHash`].
+pub struct Sponge<'dr, D: Driver<'dr>, P: SpongeHash<D::F>> {
     mode: Mode<'dr, D, P>,
     params: &'dr P,
 }
 
-impl<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> Clone
-    for Sponge<'dr, D, P>
-{
+impl<'dr, D: Driver<'dr>, P: SpongeHash<D::F>> Clone for Sponge<'dr, D, P> {
     fn clone(&self) -> Self {
         Sponge {
             mode: self.mode.clone(),
@@ -93,15 +134,13 @@ impl<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> Clone
     }
 }
 
-impl<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> Buffer<'dr, D>
-    for Sponge<'dr, D, P>
-{
+impl<'dr, D: Driver<'dr>, P: SpongeHash<D::F>> Buffer<'dr, D> for Sponge<'dr, D, P> {
     fn write(&mut self, dr: &mut D, value: &Element<'dr, D>) -> Result<()> {
         self.absorb(dr, value)
     }
 }
 
-impl<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> Sponge<'dr, D, P> {
+impl<'dr, D: Driver<'dr>, P: SpongeHash<D::F>> Sponge<'dr, D, P> {
     /// Initialize the sponge in absorb mode with a fixed initial state.
     pub fn new(dr: &mut D, params: &'dr P) -> Self {
         Sponge {
@@ -120,7 +159,7 @@ impl<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> Sponge<
     fn permute(&mut self, dr: &mut D) -> Result<()> {
         match &mut self.mode {
             Mode::Squeeze { values, state } => {
-                *state = dr.routine(Permutation::from(self.params), state.clone())?;
+                *state = self.params.permute(dr, state.clone())?;
                 *values = state.get_rate();
             }
             Mode::Absorb { values, state } => {
@@ -128,7 +167,7 @@ impl<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> Sponge<
                     *state = state.add(dr, v);
                 }
                 values.clear();
-                *state = dr.routine(Permutation::from(self.params), state.clone())?;
+                *state = self.params.permute(dr, state.clone())?;
             }
         }
 
@@ -191,6 +230,20 @@ impl<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> Sponge<
         self.squeeze(dr)
     }
 
+    /// Squeeze `N` values from the sponge.
+    ///
+    /// The output is identical to `N` sequential calls to
+    /// [`squeeze`](Self::squeeze), in the same order.
+    pub fn squeeze_n<const N: usize>(&mut self, dr: &mut D) -> Result<[Element<'dr, D>; N]> {
+        let mut out = Vec::with_capacity(N);
+        for _ in 0..N {
+            out.push(self.squeeze(dr)?);
+        }
+        Ok(out
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("exactly N values were squeezed")))
+    }
+
     /// Absorb a value into the sponge.
     pub fn absorb(&mut self, dr: &mut D, value: &Element<'dr, D>) -> Result<()> {
         match &mut self.mode {
@@ -222,6 +275,21 @@ impl<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> Sponge<
         Ok(())
     }
 
+    /// Absorb a curve point into the sponge.
+    ///
+    /// The $x$ coordinate is absorbed first, followed by the $y$ coordinate,
+    /// which is the order in which a [`Point`] is written to any [`Buffer`].
+    /// The point at infinity has no affine coordinates and cannot be
+    /// represented by a [`Point`] ([`Point::alloc`] and [`Point::constant`]
+    /// reject it), so it is never absorbed.
+    pub fn absorb_point<C: CurveAffine<Base = D::F>>(
+        &mut self,
+        dr: &mut D,
+        point: &Point<'dr, D, C>,
+    ) -> Result<()> {
+        point.write(dr, self)
+    }
+
     /// Save the internal [`SpongeState`].
     ///
     /// This method requires the [`Sponge`] to have absorbed elements that are
@@ -274,19 +342,19 @@ impl<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> Sponge<
     }
 }
 
-/// The raw state of a Poseidon sponge permutation.
+/// The raw state of a sponge permutation.
 ///
 /// This type holds `P::T` field elements representing the internal state
 /// of the sponge. It can be used to save and resume sponge progress via
 /// [`Sponge::save_state`] and [`Sponge::resume`], or passed to
 /// `Transcript::resume_from_state`.
 #[derive(Gadget, Write, Consistent)]
-pub struct SpongeState<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> {
+pub struct SpongeState<'dr, D: Driver<'dr>, P: SpongeHash<D::F>> {
     #[ragu(gadget)]
     values: FixedVec<Element<'dr, D>, PoseidonStateLen<D::F, P>>,
 }
 
-impl<'dr, D: Driver<'dr>, P: ragu_arithmetic::PoseidonPermutation<D::F>> SpongeState<'dr, D, P> {
+impl<'dr, D: Driver<'dr>, P: SpongeHash<D::F>> SpongeState<'dr, D, P> {
     /// Create a [`SpongeState`] from a [`FixedVec`] of [`Element`]s.
     ///
     /// The vector must have exactly `P::T` elements (enforced by the
@@ -536,6 +604,44 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_squeeze_n_matches_sequential_squeezes() -> Result<()> {
+        let params = Pasta::baked();
+
+        let sequential = Cell::new([Fp::ZERO; 2]);
+        let batched = Cell::new([Fp::ZERO; 2]);
+
+        Simulator::simulate(Fp::from(123), |dr, value| {
+            let mut sponge = Sponge::<'_, _, <Pasta as Cycle>::CircuitPoseidon>::new(
+                dr,
+                Pasta::circuit_poseidon(params),
+            );
+            let value = Element::alloc(dr, value)?;
+            sponge.absorb(dr, &value)?;
+            let a = sponge.squeeze(dr)?;
+            let b = sponge.squeeze(dr)?;
+            sequential.set([*a.value().take(), *b.value().take()]);
+            Ok(())
+        })?;
+
+        Simulator::simulate(Fp::from(123), |dr, value| {
+            let mut sponge = Sponge::<'_, _, <Pasta as Cycle>::CircuitPoseidon>::new(
+                dr,
+                Pasta::circuit_poseidon(params),
+            );
+            let value = Element::alloc(dr, value)?;
+            sponge.absorb(dr, &value)?;
+            let [a, b] = sponge.squeeze_n::<2>(dr)?;
+            batched.set([*a.value().take(), *b.value().take()]);
+            Ok(())
+        })?;
+
+        assert_eq!(sequential.get(), batched.get());
+        assert_ne!(sequential.get()[0], sequential.get()[1]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_save_resume_produces_same_output_as_normal_sponge() -> Result<()> {
         let params = Pasta::baked();
@@ -632,4 +738,90 @@ mod tests {
 
         Ok(())
     }
+
+    /// An (insecure) toy permutation `x_i -> x_i + x_{i+1} + 1`.
+    struct Toy;
+
+    impl SpongeHash<Fp> for Toy {
+        const T: usize = 3;
+        const RATE: usize = 2;
+
+        fn permute<'dr, D: Driver<'dr, F = Fp>>(
+            &self,
+            dr: &mut D,
+            mut state: SpongeState<'dr, D, Self>,
+        ) -> Result<SpongeState<'dr, D, Self>> {
+            let old = state.values.to_vec();
+            for (i, x) in state.values.iter_mut().enumerate() {
+                *x = old[i]
+                    .add(dr, &old[(i + 1) % Self::T])
+                    .add(dr, &Element::one());
+            }
+            Ok(state)
+        }
+    }
+
+    #[test]
+    fn test_sponge_with_alternate_permutation() -> Result<()> {
+        let squeezed = Cell::new([Fp::ZERO; 3]);
+
+        Simulator::simulate((Fp::from(1), Fp::from(2)), |dr, v| {
+            let mut sponge = Sponge::<'_, _, Toy>::new(dr, &Toy);
+            let (v1, v2) = v.cast();
+            let v1 = Element::alloc(dr, v1)?;
+            let v2 = Element::alloc(dr, v2)?;
+            sponge.absorb(dr, &v1)?;
+            sponge.absorb(dr, &v2)?;
+            let [a, b, c] = sponge.squeeze_n::<3>(dr)?;
+            squeezed.set([*a.value().take(), *b.value().take(), *c.value().take()]);
+            Ok(())
+        })?;
+
+        // [1, 2, 0] -> [4, 3, 2] yields 4 then 3; the third squeeze permutes
+        // again, [4, 3, 2] -> [8, 6, 7].
+        assert_eq!(squeezed.get(), [4u64, 3, 8].map(Fp::from));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_absorb_point_order() -> Result<()> {
+        use group::prime::PrimeCurveAffine;
+        use ragu_core::{Error, drivers::emulator::Emulator};
+        use ragu_pasta::EpAffine;
+
+        let params = Pasta::baked();
+        let p = EpAffine::generator();
+        let coordinates = p.coordinates().unwrap();
+
+        let (via_point, via_coordinates) = Emulator::emulate_wireless((), |dr, _| {
+            let mut sponge = Sponge::<'_, _, <Pasta as Cycle>::CircuitPoseidon>::new(
+                dr,
+                Pasta::circuit_poseidon(params),
+            );
+            sponge.absorb_point(dr, &Point::constant(dr, p)?)?;
+            let via_point = *sponge.squeeze(dr)?.value().take();
+
+            // x first, then y.
+            let mut sponge = Sponge::<'_, _, <Pasta as Cycle>::CircuitPoseidon>::new(
+                dr,
+                Pasta::circuit_poseidon(params),
+            );
+            sponge.absorb(dr, &Element::constant(dr, *coordinates.x()))?;
+            sponge.absorb(dr, &Element::constant(dr, *coordinates.y()))?;
+            let via_coordinates = *sponge.squeeze(dr)?.value().take();
+
+            // The point at infinity cannot be absorbed.
+            assert!(matches!(
+                Point::constant(dr, EpAffine::identity()),
+                Err(Error::InvalidWitness(_))
+            ));
+
+            Ok((via_point, via_coordinates))
+        })?;
+
+        assert_eq!(via_point, via_coordinates);
+
+        Ok(())
+    }
 }
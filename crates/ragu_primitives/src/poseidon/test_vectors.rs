@@ -0,0 +1,109 @@
+//! Reference vectors for the [`Sponge`].
+//!
+//! [`run`] replays a sequence of [`SpongeOp`]s against a fresh [`Sponge`] and
+//! returns every squeezed challenge, producing a table that external
+//! reimplementations of the transcript can be checked against.
+
+use alloc::vec::Vec;
+
+use ff::Field;
+use ragu_core::{
+    Result,
+    drivers::emulator::{Emulator, Wireless},
+    maybe::{Always, Maybe},
+};
+
+use super::{Sponge, SpongeHash};
+use crate::Element;
+
+/// An operation performed on a [`Sponge`] by [`run`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpongeOp<F> {
+    /// Absorb a single field element.
+    Absorb(F),
+    /// Absorb the coordinates of a point, `x` followed by `y`.
+    AbsorbPoint(F, F),
+    /// Squeeze a challenge.
+    Squeeze,
+}
+
+/// Applies `ops` in order to a fresh [`Sponge`] over `params` and returns the
+/// challenges squeezed by each [`SpongeOp::Squeeze`].
+pub fn run<F: Field, P: SpongeHash<F>>(params: &P, ops: &[SpongeOp<F>]) -> Result<Vec<F>> {
+    let mut dr = Emulator::<Wireless<Always<()>, F>>::execute();
+    let mut sponge = Sponge::new(&mut dr, params);
+    let mut challenges = Vec::new();
+
+    for op in ops {
+        match *op {
+            SpongeOp::Absorb(value) => {
+                let value = Element::alloc(&mut dr, Always::constant(value))?;
+                sponge.absorb(&mut dr, &value)?;
+            }
+            SpongeOp::AbsorbPoint(x, y) => {
+                for coordinate in [x, y] {
+                    let coordinate = Element::alloc(&mut dr, Always::constant(coordinate))?;
+                    sponge.absorb(&mut dr, &coordinate)?;
+                }
+            }
+            SpongeOp::Squeeze => {
+                challenges.push(*sponge.squeeze(&mut dr)?.value().take());
+            }
+        }
+    }
+
+    Ok(challenges)
+}
+
+#[cfg(test)]
+mod tests {
+    use ragu_arithmetic::Cycle;
+    use ragu_pasta::{Fp, Pasta, fp};
+
+    use super::*;
+
+    #[test]
+    fn test_vectors_are_stable() -> Result<()> {
+        let params = Pasta::circuit_poseidon(Pasta::baked());
+        let ops = [
+            SpongeOp::Absorb(Fp::from(1u64)),
+            SpongeOp::Squeeze,
+            SpongeOp::AbsorbPoint(Fp::from(2u64), Fp::from(3u64)),
+            SpongeOp::Absorb(Fp::from(4u64)),
+            SpongeOp::Squeeze,
+            SpongeOp::Squeeze,
+        ];
+
+        let vector = run(params, &ops)?;
+        assert_eq!(
+            vector,
+            [
+                fp!(0x17b5ec89783f955f8dc0b175706e344abbe868f90808937d09bfd505149a6b77),
+                fp!(0x0ecdbea61595d7a0e66a81d0260a55524e488d0c5299becf4f50e61b14884977),
+                fp!(0x106892c6055ffd35817db688c7227d7d193556c224b7ee9b96e30ae556b1295b),
+            ]
+        );
+
+        // A point is absorbed as its two coordinates.
+        let flattened = [
+            SpongeOp::Absorb(Fp::from(1u64)),
+            SpongeOp::Squeeze,
+            SpongeOp::Absorb(Fp::from(2u64)),
+            SpongeOp::Absorb(Fp::from(3u64)),
+            SpongeOp::Absorb(Fp::from(4u64)),
+            SpongeOp::Squeeze,
+            SpongeOp::Squeeze,
+        ];
+        assert_eq!(vector, run(params, &flattened)?);
+
+        // Changing an absorbed value changes every later challenge.
+        let mut tweaked = ops;
+        tweaked[3] = SpongeOp::Absorb(Fp::from(5u64));
+        let other = run(params, &tweaked)?;
+        assert_eq!(vector[0], other[0]);
+        assert_ne!(vector[1], other[1]);
+        assert_ne!(vector[2], other[2]);
+
+        Ok(())
+    }
+}
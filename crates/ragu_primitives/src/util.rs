@@ -10,22 +10,32 @@ use ragu_core::maybe::{Maybe, Perhaps};
 /// Extension trait for `Maybe` that provides helper methods kept internal to
 /// this crate.
 pub(crate) trait InternalMaybe<T: Send>: Maybe<T> {
-    /// Convert a `bool` into a `Field` element.
+    /// Convert a `bool` into a `Field` element without branching on it.
     fn fe<U, F: Field>(&self) -> Perhaps<<Self as Maybe<U>>::Kind, F>
     where
         Self: Maybe<U>,
         U: Borrow<bool> + Send + Sync,
     {
-        Maybe::<U>::as_ref(self).map(|b| if *b.borrow() { F::ONE } else { F::ZERO })
+        Maybe::<U>::as_ref(self)
+            .map(|b| F::conditional_select(&F::ZERO, &F::ONE, (*b.borrow() as u8).into()))
     }
 
     /// Convert a `bool` into a `Coeff`.
+    ///
+    /// By default this produces [`Coeff::One`] or [`Coeff::Zero`], which lets
+    /// later arithmetic on the coefficient take shortcuts that depend on the
+    /// `bool`. With the `ct` feature enabled, a [`Coeff::Arbitrary`] is
+    /// produced in both cases instead.
     fn coeff<U, F: Field>(&self) -> Perhaps<<Self as Maybe<U>>::Kind, Coeff<F>>
     where
         Self: Maybe<U>,
         U: Borrow<bool> + Send + Sync,
     {
-        Maybe::<U>::as_ref(self).map(|b| if *b.borrow() { Coeff::One } else { Coeff::Zero })
+        if cfg!(feature = "ct") {
+            self.fe::<U, F>().map(Coeff::Arbitrary)
+        } else {
+            Maybe::<U>::as_ref(self).map(|b| if *b.borrow() { Coeff::One } else { Coeff::Zero })
+        }
     }
 
     /// Convert an arbitrary `Field` element into a `Coeff`.
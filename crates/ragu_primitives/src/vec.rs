@@ -46,6 +46,15 @@ pub trait Len: Send + Sync + 'static {
     }
 }
 
+/// A [`Len`] whose length is a compile-time constant.
+///
+/// Unlike [`Len::len`], [`LEN`](StaticLen::LEN) can be used in array types
+/// such as `[T; ConstLen::<4>::LEN]` for concrete implementations.
+pub trait StaticLen: Len {
+    /// The length, which must equal [`Len::len()`].
+    const LEN: usize;
+}
+
 /// Represents a compile-time constant length.
 ///
 /// Use this when the length is known at compile time. For lengths determined
@@ -54,10 +63,14 @@ pub struct ConstLen<const N: usize>;
 
 impl<const N: usize> Len for ConstLen<N> {
     fn len() -> usize {
-        N
+        Self::LEN
     }
 }
 
+impl<const N: usize> StaticLen for ConstLen<N> {
+    const LEN: usize = N;
+}
+
 /// A wrapper around a vector that is guaranteed to have a specific length
 /// determined by the [`Len`] marker type `L`. Because its length is fixed it
 /// implements [`Gadget`] when `T: Gadget`.
@@ -155,6 +168,105 @@ impl<T, L: Len> FixedVec<T, L> {
         assert_eq!(self.len(), L::len());
         self.v
     }
+
+    /// Applies `f` to each element, producing a [`FixedVec`] of the same
+    /// length. This function behaves similarly to `[T; N]::map`.
+    pub fn map<U, F>(self, f: F) -> FixedVec<U, L>
+    where
+        F: FnMut(T) -> U,
+    {
+        assert_eq!(self.len(), L::len());
+        FixedVec {
+            v: self.v.into_iter().map(f).collect(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Applies `f` to each element, producing a [`FixedVec`] of the same
+    /// length, or returning the first error produced by the closure.
+    pub fn try_map<U, F>(self, f: F) -> Result<FixedVec<U, L>>
+    where
+        F: FnMut(T) -> Result<U>,
+    {
+        assert_eq!(self.len(), L::len());
+        Ok(FixedVec {
+            v: self.v.into_iter().map(f).collect::<Result<_>>()?,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Pairs each element with the corresponding element of `other`, which
+    /// is guaranteed to have the same length.
+    pub fn zip<U>(self, other: FixedVec<U, L>) -> FixedVec<(T, U), L> {
+        assert_eq!(self.len(), L::len());
+        assert_eq!(other.len(), L::len());
+        FixedVec {
+            v: self.v.into_iter().zip(other.v).collect(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator that allows modifying each element. The length of
+    /// the vector cannot be changed through it.
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.v.iter_mut()
+    }
+
+    /// Applies `f` to each element in place, without reallocating. Unlike
+    /// [`map`](Self::map), the element type is unchanged.
+    pub fn in_place_map<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        debug_assert_eq!(self.len(), L::len());
+        self.v.iter_mut().for_each(f);
+    }
+}
+
+impl<T, const N: usize> FixedVec<T, ConstLen<N>> {
+    /// Splits this vector into its first `K` and remaining `M` elements.
+    ///
+    /// Fails to compile unless `K + M == N`.
+    pub fn split_at<const K: usize, const M: usize>(
+        mut self,
+    ) -> (FixedVec<T, ConstLen<K>>, FixedVec<T, ConstLen<M>>) {
+        const { assert!(K + M == N, "split lengths must sum to the vector length") };
+        assert_eq!(self.len(), N);
+        let tail = self.v.split_off(K);
+        (
+            FixedVec {
+                v: self.v,
+                _marker: PhantomData,
+            },
+            FixedVec {
+                v: tail,
+                _marker: PhantomData,
+            },
+        )
+    }
+
+    /// Appends the `M` elements of `other` to this vector, producing a vector
+    /// of `S` elements.
+    ///
+    /// Fails to compile unless `N + M == S`.
+    pub fn concat<const M: usize, const S: usize>(
+        mut self,
+        other: FixedVec<T, ConstLen<M>>,
+    ) -> FixedVec<T, ConstLen<S>> {
+        const {
+            assert!(
+                N + M == S,
+                "concatenated length must be the sum of the lengths"
+            )
+        };
+        assert_eq!(self.len(), N);
+        assert_eq!(other.len(), M);
+        self.v.extend(other.v);
+        FixedVec {
+            v: self.v,
+            _marker: PhantomData,
+        }
+    }
 }
 
 impl<T: Clone, L: Len> Clone for FixedVec<T, L> {
@@ -203,6 +315,8 @@ impl<T, L: Len> IntoIterator for FixedVec<T, L> {
     }
 }
 
+/// Whole vectors can be compared with [`Gadget::enforce_equal`], which
+/// enforces each pair of corresponding elements to be equal.
 impl<'dr, D: Driver<'dr>, G: Gadget<'dr, D>, L: Len> Gadget<'dr, D> for FixedVec<G, L> {
     type Kind = FixedVec<PhantomData<G::Kind>, L>;
 }
@@ -223,6 +337,77 @@ fn test_vector_length_mismatch() {
     }
 }
 
+#[test]
+fn test_map() {
+    let v = FixedVec::<u32, ConstLen<4>>::from_fn(|i| i as u32);
+    let v: FixedVec<u64, ConstLen<4>> = v.map(|x| u64::from(x) * 3);
+    assert_eq!(&*v, &[0, 3, 6, 9]);
+}
+
+#[test]
+fn test_try_map() {
+    let v = FixedVec::<u32, ConstLen<3>>::from_fn(|i| i as u32 + 1);
+    let v = v.try_map(|x| Ok(x * 2)).unwrap();
+    assert_eq!(&*v, &[2, 4, 6]);
+
+    let mut calls = 0;
+    let result = v.try_map(|x| {
+        calls += 1;
+        if x == 4 {
+            Err(Error::MalformedEncoding("boom".into()))
+        } else {
+            Ok(x)
+        }
+    });
+    assert!(matches!(result, Err(Error::MalformedEncoding(_))));
+    assert_eq!(calls, 2, "try_map should short-circuit on the first error");
+}
+
+#[test]
+fn test_in_place_map() {
+    use ragu_pasta::Fp;
+
+    let mut v = FixedVec::<Fp, ConstLen<4>>::from_fn(|i| Fp::from(i as u64));
+    let expected = v.clone().map(|x| -x);
+    v.in_place_map(|x| *x = -*x);
+    assert_eq!(&*v, &*expected);
+    assert_eq!(v.len(), 4);
+
+    for x in v.iter_mut() {
+        *x += Fp::ONE;
+    }
+    assert_eq!(v[0], Fp::ONE);
+    assert_eq!(v[3], Fp::ONE - Fp::from(3u64));
+}
+
+#[test]
+fn test_zip() {
+    let a = FixedVec::<u32, ConstLen<3>>::from_fn(|i| i as u32);
+    let b = FixedVec::<char, ConstLen<3>>::from_fn(|i| (b'a' + i as u8) as char);
+    let zipped = a.zip(b);
+    assert_eq!(&*zipped, &[(0, 'a'), (1, 'b'), (2, 'c')]);
+
+    let sums = zipped.map(|(n, c)| n + c as u32);
+    assert_eq!(&*sums, &[97, 99, 101]);
+}
+
+#[test]
+fn test_split_at_and_concat() {
+    let v = FixedVec::<u32, ConstLen<8>>::from_fn(|i| i as u32);
+
+    let (head, tail): (FixedVec<_, ConstLen<3>>, FixedVec<_, ConstLen<5>>) = v.clone().split_at();
+    assert_eq!(&*head, &[0, 1, 2]);
+    assert_eq!(&*tail, &[3, 4, 5, 6, 7]);
+
+    let joined: FixedVec<_, ConstLen<8>> = head.concat(tail);
+    assert_eq!(&*joined, &*v);
+
+    // Splitting off nothing leaves the whole vector.
+    let (empty, all): (FixedVec<_, ConstLen<0>>, FixedVec<_, ConstLen<8>>) = joined.split_at();
+    assert!(empty.is_empty());
+    assert_eq!(&*all, &*v);
+}
+
 impl<'dr, D: Driver<'dr>, G: Consistent<'dr, D>, L: Len> Consistent<'dr, D> for FixedVec<G, L> {
     fn enforce_consistent(&self, dr: &mut D) -> Result<()> {
         for item in self.iter() {
@@ -269,3 +454,45 @@ unsafe impl<F: Field, G: GadgetKind<F>, L: Len> GadgetKind<F> for FixedVec<Phant
         Ok(())
     }
 }
+
+#[test]
+fn test_enforce_equal() -> Result<()> {
+    use ragu_core::maybe::Maybe;
+
+    use crate::Element;
+
+    type F = ragu_pasta::Fp;
+    type Simulator = crate::Simulator<F>;
+
+    let check = |a: [F; 3], b: [F; 3]| {
+        Simulator::simulate((a, b), |dr, witness| {
+            let (a, b) = witness.cast();
+            let a = FixedVec::<_, ConstLen<3>>::try_from_fn(|i| {
+                Element::alloc(dr, a.as_ref().map(|a| a[i]))
+            })?;
+            let b = FixedVec::<_, ConstLen<3>>::try_from_fn(|i| {
+                Element::alloc(dr, b.as_ref().map(|b| b[i]))
+            })?;
+            dr.reset();
+
+            a.enforce_equal(dr, &b)
+        })
+    };
+
+    let v = [F::from(1u64), F::from(2u64), F::from(3u64)];
+    let sim = check(v, v)?;
+    assert_eq!(sim.num_constraints(), 3);
+
+    let mut w = v;
+    w[2] += F::from(1u64);
+    assert!(check(v, w).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_static_len() {
+    let lengths: [usize; ConstLen::<4>::LEN] = [ConstLen::<4>::len(); 4];
+    assert_eq!(lengths, [4; 4]);
+    assert_eq!(ConstLen::<0>::LEN, 0);
+}
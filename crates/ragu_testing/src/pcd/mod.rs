@@ -1,5 +1,6 @@
-//! Test fixtures for ragu_pcd benchmarks.
+//! Test fixtures for ragu_pcd tests and benchmarks.
 //!
-//! This module provides reusable Header and Step implementations for benchmarks.
+//! This module provides reusable Header and Step implementations.
 
 pub mod nontrivial;
+pub mod simple;
@@ -0,0 +1,63 @@
+//! Minimal test fixtures carrying a single field element.
+
+use ff::Field;
+use ragu_arithmetic::Cycle;
+use ragu_core::{
+    Result,
+    drivers::{Driver, DriverValue},
+    gadgets::{Bound, Kind},
+};
+use ragu_pcd::{
+    header::{Header, Suffix},
+    step::{Encoded, Index, Step},
+};
+use ragu_primitives::Element;
+
+/// Header carrying a single field element.
+pub struct Value;
+
+impl<F: Field> Header<F> for Value {
+    const SUFFIX: Suffix = Suffix::new(0);
+    type Data = F;
+    type Output = Kind![F; Element<'_, _>];
+
+    fn encode<'dr, D: Driver<'dr, F = F>>(
+        dr: &mut D,
+        witness: DriverValue<D, Self::Data>,
+    ) -> Result<Bound<'dr, D, Self::Output>> {
+        Element::alloc(dr, witness)
+    }
+}
+
+/// Leaf step that outputs its witness as a [`Value`].
+pub struct WitnessValue;
+
+impl<C: Cycle> Step<C> for WitnessValue {
+    const INDEX: Index = Index::new(0);
+    type Witness<'source> = C::CircuitField;
+    type Aux<'source> = ();
+    type Left = ();
+    type Right = ();
+    type Output = Value;
+
+    fn witness<'dr, 'source: 'dr, D: Driver<'dr, F = C::CircuitField>, const HEADER_SIZE: usize>(
+        &self,
+        dr: &mut D,
+        witness: DriverValue<D, Self::Witness<'source>>,
+        left: DriverValue<D, ()>,
+        right: DriverValue<D, ()>,
+    ) -> Result<(
+        (
+            Encoded<'dr, D, Self::Left, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Right, HEADER_SIZE>,
+            Encoded<'dr, D, Self::Output, HEADER_SIZE>,
+        ),
+        DriverValue<D, <Self::Output as Header<C::CircuitField>>::Data>,
+        DriverValue<D, Self::Aux<'source>>,
+    )> {
+        let left = Encoded::new(dr, left)?;
+        let right = Encoded::new(dr, right)?;
+        let output = Encoded::new(dr, witness.clone())?;
+        Ok(((left, right, output), witness, D::unit()))
+    }
+}